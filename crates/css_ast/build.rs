@@ -2,9 +2,10 @@ use csskit_source_finder::find_visitable_nodes;
 use heck::{ToKebabCase, ToSnakeCase};
 use quote::{format_ident, quote};
 use std::{
-	collections::HashSet,
+	cell::RefCell,
+	collections::{HashMap, HashSet},
 	env,
-	fs::write,
+	fs::{read_to_string, write},
 	path::{Path, PathBuf},
 };
 use syn::{Ident, PathArguments, Type, TypePath};
@@ -39,13 +40,65 @@ fn ident_to_snake_case(ident: Ident) -> Ident {
 	format_ident!("{}", ident.to_string().to_snake_case())
 }
 
+// The name of the property a style value (such as `WebkitBoxOrientStyleValue`) is for (`-webkit-box-orient`).
+fn property_name(name: &str) -> String {
+	let name = name.to_kebab_case();
+	if name.starts_with("webkit-") || name.starts_with("moz-") { format!("-{name}") } else { name }
+}
+
+// Types within a value grammar which can contain a `<color>`, besides `<color>` itself.
+const COLOR_TYPES: &[&str] = &[
+	"<color>",
+	"<image>",
+	"<bg-image>",
+	"<bg-layer>",
+	"<final-bg-layer>",
+	"<shadow>",
+	"<spread-shadow>",
+	"<line-color-list>",
+	"<auto-line-color-list>",
+	"<gap-rule-list>",
+	"<gap-auto-rule-list>",
+	"<content-list>",
+	"<cursor-image>",
+];
+
+// Finds the grammar of each style value defined in a file, by the name of the property. Style values which are
+// commented out (as they can't be parsed yet) are included, as their grammar is still known.
+fn find_grammars(source: &str, grammars: &mut HashMap<String, String>) {
+	let mut grammar: Option<String> = None;
+	let mut in_value = false;
+	for line in source.lines().map(str::trim) {
+		let line = line.strip_prefix("//").map_or(line, str::trim);
+		if line.starts_with("#[value(") {
+			in_value = true;
+			grammar = Some(String::new());
+		}
+		if in_value {
+			grammar.as_mut().unwrap().push_str(line);
+			in_value = !line.ends_with(")]");
+			continue;
+		}
+		let Some(rest) = line.strip_prefix("pub struct ").or_else(|| line.strip_prefix("pub enum ")) else {
+			continue;
+		};
+		let name: String = rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+		if let (Some(grammar), Some(name)) = (grammar.take(), name.strip_suffix("StyleValue")) {
+			let (start, end) = (grammar.find('"').map_or(0, |i| i + 1), grammar.rfind('"').unwrap_or(grammar.len()));
+			grammars.insert(property_name(name), grammar[start..end].into());
+		}
+	}
+}
+
 fn main() {
 	println!("cargo::rerun-if-changed=build.rs");
 	use std::time::Instant;
 	let now = Instant::now();
 	let mut matches = HashSet::<Type>::new();
+	let paths = RefCell::new(vec![]);
 	find_visitable_nodes("src/**/*.rs", &mut matches, |path: &PathBuf| {
 		println!("cargo::rerun-if-changed={}", path.display());
+		paths.borrow_mut().push(path.clone());
 	});
 
 	println!("cargo::warning=Constructring css_node_kind.rs");
@@ -55,6 +108,8 @@ fn main() {
 		let source = quote! {
 				#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 				pub enum NodeKind {
+					Declaration,
+					BadDeclaration,
 					#(#variants),*
 				}
 		};
@@ -65,9 +120,9 @@ fn main() {
 	{
 		let methods = matches.iter().filter_map(|ty| {
 			ty.get_ident().map(|ident| {
-				let method_name = format_ident!("visit_{}", ident_to_snake_case(ident));
+				let method_name = format_ident!("visit_{}", ident_to_snake_case(ident.clone()));
 				let life = ty.get_arguments();
-				quote! { #method_name #life (#ty) }
+				quote! { #method_name #life (#ty): #ident }
 			})
 		});
 		let source = quote! {
@@ -91,10 +146,7 @@ fn main() {
 						return None;
 					}
 					let variant_name = format_ident!("{}", name);
-					let mut variant_str = variant_name.to_string().to_kebab_case();
-					if variant_str.starts_with("webkit-") || variant_str.starts_with("moz-") {
-						variant_str = format!("-{variant_str}");
-					}
+					let variant_str = property_name(name);
 					Some(quote! { #variant_name: #ty = #variant_str })
 				})
 			})
@@ -111,6 +163,43 @@ fn main() {
 		write(Path::new(&env::var("OUT_DIR").unwrap()).join("css_apply_properties.rs"), source.to_string()).unwrap();
 	}
 
+	println!("cargo::warning=Constructring css_color_properties.rs");
+	{
+		let mut grammars = HashMap::new();
+		for path in paths.borrow().iter().filter(|path| path.starts_with("src/values")) {
+			find_grammars(&read_to_string(path).unwrap(), &mut grammars);
+		}
+		// A property accepts a color if its grammar has a type which can contain one, or refers to the grammar of
+		// another property which accepts one (such as `<'border-top-color'>`).
+		let mut names: Vec<&String> = grammars.keys().collect();
+		names.sort();
+		let mut accepts: HashSet<&String> = HashSet::new();
+		loop {
+			let len = accepts.len();
+			for name in &names {
+				let grammar = &grammars[*name];
+				if COLOR_TYPES.iter().any(|ty| grammar.contains(ty))
+					|| accepts.iter().any(|other| grammar.contains(&format!("<'{other}'>")))
+				{
+					accepts.insert(name);
+				}
+			}
+			if accepts.len() == len {
+				break;
+			}
+		}
+		let entries = names.iter().map(|name| {
+			let accepts = accepts.contains(*name);
+			quote! { #name => #accepts }
+		});
+		let source = quote! {
+			static COLOR_PROPERTIES: phf::Map<&'static str, bool> = phf::phf_map! {
+				#(#entries,)*
+			};
+		};
+		write(Path::new(&env::var("OUT_DIR").unwrap()).join("css_color_properties.rs"), source.to_string()).unwrap();
+	}
+
 	let elapsed = now.elapsed();
 	println!("cargo::warning=Built in {:.?}", &elapsed);
 }
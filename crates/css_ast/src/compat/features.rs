@@ -0,0 +1,301 @@
+use super::{Browser, BrowserTarget, Version, property_meta};
+
+/// The first version of each [Browser] which supports a feature, or `None` for browsers which don't support it in any
+/// version.
+///
+/// ```
+/// use css_ast::*;
+/// let support = property_support("aspect-ratio").unwrap();
+/// assert_eq!(support.since(Browser::Safari), Some(Version::new(15, 0)));
+/// assert!(support.supports(&"chrome 100".parse().unwrap()));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Support(pub(super) [Option<Version>; 8]);
+
+impl Support {
+	/// Support which no browser has.
+	pub const NONE: Self = Self([None; 8]);
+
+	/// Creates the [Support] for a feature from the first supporting version of each [Browser] which supports it.
+	pub const fn from_versions(versions: &[(Browser, Version)]) -> Self {
+		let mut support = [None; 8];
+		let mut i = 0;
+		while i < versions.len() {
+			support[versions[i].0 as usize] = Some(versions[i].1);
+			i += 1;
+		}
+		Self(support)
+	}
+
+	/// The first version of the [Browser] which supports the feature, if any do.
+	pub fn since(&self, browser: Browser) -> Option<Version> {
+		self.0[browser as usize]
+	}
+
+	/// Returns true if the target's version of its browser supports the feature.
+	pub fn supports(&self, target: &BrowserTarget) -> bool {
+		self.since(target.browser).is_some_and(|since| target.supports(target.browser, since))
+	}
+}
+
+// Parses a version written as a literal, such as `15.4`, in a const context.
+pub(super) const fn version(str: &str) -> Version {
+	let bytes = str.as_bytes();
+	let (mut major, mut minor, mut dot, mut i) = (0, 0, false, 0);
+	while i < bytes.len() {
+		match bytes[i] {
+			b'.' => dot = true,
+			b if dot => minor = minor * 10 + (b - b'0') as u16,
+			b => major = major * 10 + (b - b'0') as u16,
+		}
+		i += 1;
+	}
+	Version::new(major, minor)
+}
+
+// The first supporting version for each browser, in the order of the [Browser] variants (chrome, edge, firefox, safari,
+// opera, ios_saf, and_chr, samsung). `_` marks a browser which has no support.
+macro_rules! support {
+	(@version _) => { None };
+	(@version $version: literal) => { Some(version(stringify!($version))) };
+	($($version: tt),+ $(,)?) => {
+		Support([$(support!(@version $version)),+])
+	};
+}
+pub(super) use support;
+
+// Features which every browser has supported since its first (or first tracked) version.
+const ALL: Support = support!(1, 12, 1, 1, 3.5, 1, 18, 1);
+const FLEXBOX: Support = support!(29, 12, 28, 9, 17, 9, 29, 2);
+const GRID: Support = support!(57, 16, 52, 10.1, 44, 10.3, 57, 6.2);
+const SUBGRID: Support = support!(117, 117, 71, 16, 103, 16, 117, 24);
+const CONTAINER: Support = support!(105, 105, 110, 16, 91, 16, 105, 20);
+const CUSTOM_PROPERTIES: Support = support!(49, 15, 31, 9.1, 36, 9.3, 49, 5);
+const GRADIENTS: Support = support!(26, 12, 16, 7, 12.1, 7, 26, 1.5);
+const MATH_FUNCTIONS: Support = support!(79, 79, 75, 11.1, 66, 11.3, 79, 12);
+const COLOR_FUNCTIONS: Support = support!(111, 111, 113, 15.4, 97, 15.4, 111, 22);
+const IS_WHERE: Support = support!(88, 88, 78, 14, 74, 14, 88, 15);
+const SHADOW_DOM: Support = support!(54, 79, 63, 10, 41, 10, 54, 6);
+const STRETCH: Support = support!(138, 138, _, _, 122, _, 138, _);
+const VIEW_TRANSITIONS: Support = support!(111, 111, 144, 18, 97, 18, 111, 22);
+
+// Values which are newer than their property, written as `property:value`.
+static VALUES: phf::Map<&'static str, Support> = phf::phf_map! {
+	"display:contents" => support!(65, 79, 37, 11.1, 52, 11.3, 65, 9.2),
+	"display:flex" => FLEXBOX,
+	"display:flow-root" => support!(58, 79, 53, 13, 45, 13, 58, 7),
+	"display:grid" => GRID,
+	"display:inline-flex" => FLEXBOX,
+	"display:inline-grid" => GRID,
+	"grid-template-columns:subgrid" => SUBGRID,
+	"grid-template-rows:subgrid" => SUBGRID,
+	"height:fit-content" => support!(46, 79, 94, 11, 33, 11, 46, 5),
+	"height:stretch" => STRETCH,
+	"max-height:stretch" => STRETCH,
+	"max-width:stretch" => STRETCH,
+	"min-height:stretch" => STRETCH,
+	"min-width:stretch" => STRETCH,
+	"overflow:clip" => support!(90, 90, 81, 16, 76, 16, 90, 15),
+	"position:-webkit-sticky" => support!(_, _, _, 6.1, _, 6, _, _),
+	"position:sticky" => support!(56, 16, 32, 13, 42, 13, 56, 6),
+	"text-wrap:balance" => support!(114, 114, 121, 17.5, 100, 17.5, 114, 23),
+	"text-wrap:pretty" => support!(117, 117, _, 26, 103, 26, 117, 24),
+	"width:fit-content" => support!(46, 79, 94, 11, 33, 11, 46, 5),
+	"width:stretch" => STRETCH,
+};
+
+static FUNCTIONS: phf::Map<&'static str, Support> = phf::phf_map! {
+	"attr" => ALL,
+	"calc" => support!(26, 12, 16, 7, 15, 7, 26, 1.5),
+	"clamp" => MATH_FUNCTIONS,
+	"color" => support!(111, 111, 113, 15, 97, 15, 111, 22),
+	"color-mix" => support!(111, 111, 113, 16.2, 97, 16.2, 111, 22),
+	"conic-gradient" => support!(69, 79, 83, 12.1, 56, 12.2, 69, 10.1),
+	"hsl" => ALL,
+	"hsla" => ALL,
+	"lab" => COLOR_FUNCTIONS,
+	"lch" => COLOR_FUNCTIONS,
+	"light-dark" => support!(123, 123, 120, 17.5, 109, 17.5, 123, 27),
+	"linear-gradient" => GRADIENTS,
+	"max" => MATH_FUNCTIONS,
+	"min" => MATH_FUNCTIONS,
+	"oklab" => COLOR_FUNCTIONS,
+	"oklch" => COLOR_FUNCTIONS,
+	"radial-gradient" => GRADIENTS,
+	"repeating-linear-gradient" => GRADIENTS,
+	"repeating-radial-gradient" => GRADIENTS,
+	"rgb" => ALL,
+	"rgba" => ALL,
+	"url" => ALL,
+	"var" => CUSTOM_PROPERTIES,
+};
+
+// Pseudo-classes and pseudo-elements which are newer (or less widely supported) than CSS 2, along with the nesting
+// selector and column combinator. Functional pseudos are written without their parentheses.
+static SELECTORS: phf::Map<&'static str, Support> = phf::phf_map! {
+	"&" => support!(112, 112, 117, 16.5, 98, 16.5, 112, 23),
+	"||" => Support::NONE,
+	":any-link" => support!(65, 79, 50, 9, 52, 9, 65, 9.2),
+	":autofill" => support!(110, 110, 86, 15, 96, 15, 110, 21),
+	":blank" => Support::NONE,
+	":current" => Support::NONE,
+	":defined" => support!(54, 79, 63, 10, 41, 10, 54, 6),
+	":dir" => support!(120, 120, 49, 16.4, 106, 16.4, 120, 25),
+	":focus-visible" => support!(86, 86, 85, 15.4, 72, 15.4, 86, 14),
+	":focus-within" => support!(60, 79, 52, 10.1, 47, 10.3, 60, 8),
+	":fullscreen" => support!(71, 79, 64, 16.4, 58, _, 71, 10.1),
+	":future" => Support::NONE,
+	":has" => support!(105, 105, 121, 15.4, 91, 15.4, 105, 20),
+	":host" => SHADOW_DOM,
+	":host-context" => support!(54, 79, _, _, 41, _, 54, 6),
+	":is" => IS_WHERE,
+	":local-link" => Support::NONE,
+	":modal" => support!(105, 105, 103, 15.6, 91, 15.6, 105, 20),
+	":nth-col" => Support::NONE,
+	":nth-last-col" => Support::NONE,
+	":past" => Support::NONE,
+	":paused" => support!(_, _, _, 15.4, _, 15.4, _, _),
+	":picture-in-picture" => support!(110, 110, _, 13.1, 96, _, 110, 21),
+	":placeholder-shown" => support!(47, 79, 51, 9, 34, 9, 47, 5),
+	":playing" => support!(_, _, _, 15.4, _, 15.4, _, _),
+	":popover-open" => support!(114, 114, 125, 17, 100, 17, 114, 23),
+	":scope" => support!(27, 79, 32, 7, 15, 7, 27, 1.5),
+	":state" => support!(125, 125, 126, 17.4, 111, 17.4, 125, 27),
+	":target-within" => Support::NONE,
+	":where" => IS_WHERE,
+	"::backdrop" => support!(37, 79, 47, 15.4, 24, 15.4, 37, 3),
+	"::cue" => support!(26, 79, 55, 7, 15, 7, 26, 1.5),
+	"::cue-region" => Support::NONE,
+	"::file-selector-button" => support!(89, 89, 82, 14.1, 75, 14.5, 89, 15),
+	"::grammar-error" => support!(121, 121, _, _, 107, _, 121, 25),
+	"::highlight" => support!(105, 105, 140, 17.2, 91, 17.2, 105, 20),
+	"::marker" => support!(86, 86, 68, 11.1, 72, 11.3, 86, 14),
+	"::part" => support!(73, 79, 72, 13.1, 60, 13.4, 73, 11),
+	"::placeholder" => support!(57, 79, 51, 10.1, 44, 10.3, 57, 7),
+	"::selection" => support!(1, 12, 62, 1.1, 9.5, 3, 18, 1),
+	"::slotted" => support!(50, 79, 63, 10, 37, 10, 50, 5),
+	"::spelling-error" => support!(121, 121, _, _, 107, _, 121, 25),
+	"::target-text" => support!(89, 89, 131, 18.2, 75, 18.2, 89, 15),
+	"::view-transition" => VIEW_TRANSITIONS,
+};
+
+// At-rules, written with their `@`. Unprefixed at-rules which aren't in the table are unknown.
+static AT_RULES: phf::Map<&'static str, Support> = phf::phf_map! {
+	"@charset" => ALL,
+	"@container" => CONTAINER,
+	"@counter-style" => support!(91, 91, 33, 17, 77, 17, 91, 16),
+	"@document" => Support::NONE,
+	"@font-face" => ALL,
+	"@font-feature-values" => support!(111, 111, 34, 9.1, 97, 9.3, 111, 22),
+	"@font-palette-values" => support!(101, 101, 107, 15.4, 87, 15.4, 101, 19),
+	"@import" => ALL,
+	"@keyframes" => support!(43, 12, 16, 9, 30, 9, 43, 4),
+	"@layer" => support!(99, 99, 97, 15.4, 85, 15.4, 99, 18),
+	"@media" => ALL,
+	"@namespace" => ALL,
+	"@page" => ALL,
+	"@position-try" => support!(125, 125, _, 26, 111, 26, 125, 27),
+	"@property" => support!(85, 85, 128, 16.4, 71, 16.4, 85, 14),
+	"@scope" => support!(118, 118, 146, 17.4, 104, 17.4, 118, 24),
+	"@starting-style" => support!(117, 117, 129, 17.5, 103, 17.5, 117, 24),
+	"@supports" => support!(28, 12, 22, 9, 12.1, 9, 28, 1.5),
+	"@view-transition" => support!(126, 126, _, 18.2, 112, 18.2, 126, 27),
+};
+
+/// The [Support] of the range syntax for media features, such as `(width >= 40em)`, as opposed to the legacy `min-`
+/// and `max-` prefixed features.
+pub const MEDIA_RANGE_SYNTAX: Support = support!(104, 104, 63, 16.4, 91, 16.4, 104, 20);
+
+/// Looks up the [Support] of a lower case property name, from the [versions][crate::PropertyMeta::versions] of its
+/// [PropertyMeta][crate::PropertyMeta]. Custom properties (such as `--brand`) are supported wherever `var()` is. Returns
+/// `None` for properties which csskit doesn't implement, or whose versions are unknown.
+pub fn property_support(name: &str) -> Option<Support> {
+	if name.starts_with("--") { Some(CUSTOM_PROPERTIES) } else { property_meta(name).and_then(|meta| meta.versions) }
+}
+
+/// Looks up the [Support] of a lower case keyword value of a property, for values which are newer than the property
+/// itself (such as `display: grid`). Returns `None` for values which aren't in the bundled table.
+pub fn value_support(property: &str, value: &str) -> Option<Support> {
+	VALUES.get(format!("{property}:{value}").as_str()).copied()
+}
+
+/// Looks up the [Support] of a lower case function name, such as `oklch`. Returns `None` for functions which aren't in
+/// the bundled table.
+pub fn function_support(name: &str) -> Option<Support> {
+	FUNCTIONS.get(name).copied()
+}
+
+/// Looks up the [Support] of a lower case pseudo-class or pseudo-element, written with its colons and without any
+/// parentheses (such as `:has` or `::backdrop`). Pseudos which aren't in the bundled table, such as `:hover`, are
+/// supported everywhere, while vendor prefixed pseudos are `None` as their support is unknown.
+pub fn selector_support(name: &str) -> Option<Support> {
+	match SELECTORS.get(name) {
+		Some(support) => Some(*support),
+		None if name.trim_start_matches(':').starts_with('-') => None,
+		None => Some(ALL),
+	}
+}
+
+/// Looks up the [Support] of a lower case at-rule, written with its `@` (such as `@container`). Returns `None` for
+/// at-rules which aren't in the bundled table, including vendor prefixed at-rules.
+pub fn at_rule_support(name: &str) -> Option<Support> {
+	AT_RULES.get(name).copied()
+}
+
+/// Returns the names of the functions in a lower case value, such as `calc` and `var` in `calc(1px + var(--gap))`, as
+/// keys for [function_support].
+pub fn value_functions(value: &str) -> impl Iterator<Item = &str> {
+	let mut segments: Vec<&str> = value.split('(').collect();
+	segments.pop();
+	segments.into_iter().filter_map(|segment| {
+		let start = segment.rfind(|c: char| !c.is_ascii_alphanumeric() && c != '-').map_or(0, |i| i + 1);
+		Some(&segment[start..]).filter(|name| !name.is_empty())
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_version() {
+		assert_eq!(version("15.4"), Version::new(15, 4));
+		assert_eq!(version("100"), Version::new(100, 0));
+	}
+
+	#[test]
+	fn test_from_versions() {
+		let support =
+			Support::from_versions(&[(Browser::Safari, Version::new(15, 4)), (Browser::Chrome, Version::new(1, 0))]);
+		assert_eq!(support.since(Browser::Safari), Some(Version::new(15, 4)));
+		assert_eq!(support.since(Browser::Chrome), Some(Version::new(1, 0)));
+		assert_eq!(support.since(Browser::Firefox), None);
+	}
+
+	#[test]
+	fn test_support() {
+		let chrome = |version| BrowserTarget::new(Browser::Chrome, Version::new(version, 0));
+		let has = selector_support(":has").unwrap();
+		assert!(has.supports(&chrome(105)));
+		assert!(!has.supports(&chrome(104)));
+		assert_eq!(has.since(Browser::IosSafari), Some(Version::new(15, 4)));
+		assert_eq!(selector_support(":hover"), Some(ALL));
+		assert_eq!(selector_support("::-webkit-scrollbar"), None);
+		assert_eq!(property_support("--brand"), Some(CUSTOM_PROPERTIES));
+		assert_eq!(
+			value_support("position", "sticky").and_then(|support| support.since(Browser::Safari)),
+			Some(Version::new(13, 0))
+		);
+		assert!(!value_support("text-wrap", "pretty").unwrap().supports(&"firefox 140".parse().unwrap()));
+		assert_eq!(function_support("oklch"), Some(COLOR_FUNCTIONS));
+		assert_eq!(at_rule_support("@container"), Some(CONTAINER));
+		assert_eq!(at_rule_support("@-webkit-keyframes"), None);
+		assert!(!MEDIA_RANGE_SYNTAX.supports(&chrome(100)));
+	}
+
+	#[test]
+	fn test_value_functions() {
+		assert_eq!(value_functions("calc(1px + var(--gap))").collect::<Vec<_>>(), ["calc", "var"]);
+		assert_eq!(value_functions("1px (2px)").count(), 0);
+	}
+}
@@ -0,0 +1,61 @@
+use super::Support;
+use crate::PropertyId;
+
+/// The [Baseline](https://web.dev/baseline) status of a feature: how widely it is available across the core browsers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Baseline {
+	/// Not yet supported in every core browser.
+	Limited,
+	/// Supported in the latest version of every core browser.
+	Newly,
+	/// Supported in every core browser for at least 30 months.
+	Widely,
+}
+
+/// Compatibility metadata for a property, generated from the `#[popularity]`, `#[caniuse]`, `#[baseline]` and
+/// `#[versions]` attributes of its style value. Each field is `None` where the data is unknown.
+///
+/// ```
+/// use css_ast::*;
+/// let meta = property_meta("scroll-behavior").unwrap();
+/// assert_eq!(meta.baseline, Some(Baseline::Widely));
+/// assert_eq!(meta.versions.unwrap().since(Browser::Safari), Some(Version::new(15, 4)));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PropertyMeta {
+	pub name: &'static str,
+	pub baseline: Option<Baseline>,
+	/// The first version of each browser to support the property. Browsers which the data doesn't list are
+	/// [unsupported][Support::since].
+	pub versions: Option<Support>,
+	/// The percentage of page loads which use the property, according to Chrome's usage counters.
+	pub popularity: Option<f32>,
+	/// The [caniuse](https://caniuse.com) page for the property.
+	pub caniuse: Option<&'static str>,
+}
+
+/// Looks up the [PropertyMeta] of a lower case property name. Returns `None` for properties which csskit doesn't
+/// implement.
+pub fn property_meta(name: &str) -> Option<PropertyMeta> {
+	PropertyId::from_name(name).map(|property| property.meta())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Browser, Version};
+
+	#[test]
+	fn test_property_meta() {
+		let meta = property_meta("scroll-behavior").unwrap();
+		assert_eq!(meta.name, "scroll-behavior");
+		assert_eq!(meta.caniuse, Some("https://caniuse.com/css-scroll-behavior"));
+		assert_eq!(meta.popularity, None);
+		let versions = meta.versions.unwrap();
+		assert_eq!(versions.since(Browser::Chrome), Some(Version::new(61, 0)));
+		assert_eq!(versions.since(Browser::Safari), Some(Version::new(15, 4)));
+		assert_eq!(versions.since(Browser::Samsung), None);
+		assert_eq!(property_meta("overflow").unwrap().popularity, Some(87.038));
+		assert!(property_meta("not-a-property").is_none());
+	}
+}
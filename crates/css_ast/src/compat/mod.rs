@@ -0,0 +1,11 @@
+//! Browser targets, and a bundled table of the browser versions which support CSS features, so that stylesheets can be
+//! checked and transformed for the browsers they need to work in without any network access.
+mod features;
+mod meta;
+mod prefixes;
+mod targets;
+
+pub use features::*;
+pub use meta::*;
+pub use prefixes::*;
+pub use targets::*;
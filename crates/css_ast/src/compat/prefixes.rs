@@ -0,0 +1,229 @@
+use super::{
+	BrowserTarget, Support,
+	features::{support, version},
+};
+
+/// A vendor prefixed alternative to a standard feature, such as `-webkit-backdrop-filter` for `backdrop-filter`, with
+/// the first version of each browser which supports it.
+///
+/// ```
+/// use css_ast::*;
+/// let prefixed = property_prefixes("hyphens")[0];
+/// assert_eq!(prefixed.name, "-webkit-hyphens");
+/// let standard = property_support("hyphens").unwrap();
+/// assert!(prefixed.needed_by(standard, &"safari 16".parse().unwrap()));
+/// assert!(!prefixed.needed_by(standard, &"safari 17".parse().unwrap()));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Prefixed {
+	pub name: &'static str,
+	pub support: Support,
+}
+
+impl Prefixed {
+	/// Returns true if the target needs this prefixed alternative: it supports the prefixed feature, but not the
+	/// standard one (whose [Support] is given).
+	pub fn needed_by(&self, standard: Support, target: &BrowserTarget) -> bool {
+		!standard.supports(target) && self.support.supports(target)
+	}
+}
+
+const WEBKIT_ANIMATIONS: Support = support!(4, 12, 49, 4, 15, 3.2, 18, 1);
+const WEBKIT_FLEXBOX: Support = support!(21, 12, 49, 6.1, 15, 7, 21, 1.5);
+const WEBKIT_MASK: Support = support!(4, 79, 53, 3.1, 15, 3.2, 18, 1);
+const MOZ_ONLY: Support = support!(_, _, 1, _, _, _, _, _);
+
+macro_rules! prefixed {
+	($($name: literal => $support: expr),+ $(,)?) => {
+		&[$(Prefixed { name: $name, support: $support }),+]
+	};
+}
+
+// Standard properties, and their prefixed alternatives.
+static PROPERTIES: phf::Map<&'static str, &'static [Prefixed]> = phf::phf_map! {
+	"align-content" => prefixed!("-webkit-align-content" => WEBKIT_FLEXBOX),
+	"align-items" => prefixed!("-webkit-align-items" => WEBKIT_FLEXBOX),
+	"align-self" => prefixed!("-webkit-align-self" => WEBKIT_FLEXBOX),
+	"animation" => prefixed!("-webkit-animation" => WEBKIT_ANIMATIONS),
+	"animation-delay" => prefixed!("-webkit-animation-delay" => WEBKIT_ANIMATIONS),
+	"animation-direction" => prefixed!("-webkit-animation-direction" => WEBKIT_ANIMATIONS),
+	"animation-duration" => prefixed!("-webkit-animation-duration" => WEBKIT_ANIMATIONS),
+	"animation-fill-mode" => prefixed!("-webkit-animation-fill-mode" => WEBKIT_ANIMATIONS),
+	"animation-iteration-count" => prefixed!("-webkit-animation-iteration-count" => WEBKIT_ANIMATIONS),
+	"animation-name" => prefixed!("-webkit-animation-name" => WEBKIT_ANIMATIONS),
+	"animation-play-state" => prefixed!("-webkit-animation-play-state" => WEBKIT_ANIMATIONS),
+	"animation-timing-function" => prefixed!("-webkit-animation-timing-function" => WEBKIT_ANIMATIONS),
+	"appearance" => prefixed!(
+		"-webkit-appearance" => support!(1, 12, 64, 3, 15, 1, 18, 1),
+		"-moz-appearance" => MOZ_ONLY,
+	),
+	"backdrop-filter" => prefixed!("-webkit-backdrop-filter" => support!(_, 17, _, 9, _, 9, _, _)),
+	"clip-path" => prefixed!("-webkit-clip-path" => support!(24, 79, _, 7, 15, 7, 24, 1.5)),
+	"filter" => prefixed!("-webkit-filter" => support!(18, 79, _, 6, 15, 6, 18, 1.5)),
+	"flex" => prefixed!("-webkit-flex" => WEBKIT_FLEXBOX),
+	"flex-basis" => prefixed!("-webkit-flex-basis" => WEBKIT_FLEXBOX),
+	"flex-direction" => prefixed!("-webkit-flex-direction" => WEBKIT_FLEXBOX),
+	"flex-flow" => prefixed!("-webkit-flex-flow" => WEBKIT_FLEXBOX),
+	"flex-grow" => prefixed!("-webkit-flex-grow" => WEBKIT_FLEXBOX),
+	"flex-shrink" => prefixed!("-webkit-flex-shrink" => WEBKIT_FLEXBOX),
+	"flex-wrap" => prefixed!("-webkit-flex-wrap" => WEBKIT_FLEXBOX),
+	"hyphens" => prefixed!(
+		"-webkit-hyphens" => support!(_, _, _, 5.1, _, 4.2, _, _),
+		"-moz-hyphens" => support!(_, _, 6, _, _, _, _, _),
+		"-ms-hyphens" => support!(_, 12, _, _, _, _, _, _),
+	),
+	"justify-content" => prefixed!("-webkit-justify-content" => WEBKIT_FLEXBOX),
+	"mask" => prefixed!("-webkit-mask" => WEBKIT_MASK),
+	"mask-image" => prefixed!("-webkit-mask-image" => WEBKIT_MASK),
+	"order" => prefixed!("-webkit-order" => WEBKIT_FLEXBOX),
+	"transform" => prefixed!("-webkit-transform" => WEBKIT_ANIMATIONS),
+	"transform-origin" => prefixed!("-webkit-transform-origin" => WEBKIT_ANIMATIONS),
+	"transition" => prefixed!("-webkit-transition" => WEBKIT_ANIMATIONS),
+	"transition-delay" => prefixed!("-webkit-transition-delay" => WEBKIT_ANIMATIONS),
+	"transition-duration" => prefixed!("-webkit-transition-duration" => WEBKIT_ANIMATIONS),
+	"transition-property" => prefixed!("-webkit-transition-property" => WEBKIT_ANIMATIONS),
+	"transition-timing-function" => prefixed!("-webkit-transition-timing-function" => WEBKIT_ANIMATIONS),
+	"user-select" => prefixed!(
+		"-webkit-user-select" => support!(6, 12, _, 3.1, 15, 3.2, 18, 1),
+		"-moz-user-select" => support!(_, _, 2, _, _, _, _, _),
+		"-ms-user-select" => support!(_, 12, _, _, _, _, _, _),
+	),
+};
+
+const FILL_AVAILABLE: &[Prefixed] = prefixed!(
+	"-webkit-fill-available" => support!(22, 79, _, 7, 15, 7, 22, 1.5),
+	"-moz-available" => support!(_, _, 3, _, _, _, _, _),
+);
+const FIT_CONTENT: &[Prefixed] = prefixed!(
+	"-webkit-fit-content" => support!(22, 79, _, 6.1, 15, 7, 22, 1.5),
+	"-moz-fit-content" => support!(_, _, 3, _, _, _, _, _),
+);
+
+// Standard values, written as `property:value`, and their prefixed alternatives.
+static VALUES: phf::Map<&'static str, &'static [Prefixed]> = phf::phf_map! {
+	"display:flex" => prefixed!("-webkit-flex" => WEBKIT_FLEXBOX),
+	"display:inline-flex" => prefixed!("-webkit-inline-flex" => WEBKIT_FLEXBOX),
+	"height:fit-content" => FIT_CONTENT,
+	"height:stretch" => FILL_AVAILABLE,
+	"max-height:stretch" => FILL_AVAILABLE,
+	"max-width:stretch" => FILL_AVAILABLE,
+	"min-height:stretch" => FILL_AVAILABLE,
+	"min-width:stretch" => FILL_AVAILABLE,
+	"position:sticky" => prefixed!("-webkit-sticky" => support!(_, _, _, 6.1, _, 6, _, _)),
+	"width:fit-content" => FIT_CONTENT,
+	"width:stretch" => FILL_AVAILABLE,
+};
+
+// Standard pseudo-classes and pseudo-elements, and their prefixed alternatives.
+static SELECTORS: phf::Map<&'static str, &'static [Prefixed]> = phf::phf_map! {
+	":autofill" => prefixed!(":-webkit-autofill" => support!(1, 79, 86, 3, 15, 1, 18, 1)),
+	":fullscreen" => prefixed!(
+		":-webkit-full-screen" => support!(15, 12, _, 6, 15, _, 18, 1.5),
+		":-moz-full-screen" => support!(_, _, 9, _, _, _, _, _),
+	),
+	"::file-selector-button" => prefixed!("::-webkit-file-upload-button" => support!(1, 79, _, 3, 15, 1, 18, 1)),
+	"::placeholder" => prefixed!(
+		"::-webkit-input-placeholder" => support!(4, 12, _, 4, 15, 3.2, 18, 1),
+		"::-moz-placeholder" => support!(_, _, 19, _, _, _, _, _),
+	),
+	"::selection" => prefixed!("::-moz-selection" => support!(_, _, 1, _, _, _, _, _)),
+};
+
+// Standard at-rules, and their prefixed alternatives.
+static AT_RULES: phf::Map<&'static str, &'static [Prefixed]> = phf::phf_map! {
+	"@keyframes" => prefixed!("@-webkit-keyframes" => support!(4, 12, _, 4, 15, 3.2, 18, 1)),
+};
+
+fn standard(map: &phf::Map<&'static str, &'static [Prefixed]>, name: &str) -> Option<(&'static str, Prefixed)> {
+	map.entries().find_map(|(standard, prefixed)| Some((*standard, *prefixed.iter().find(|p| p.name == name)?)))
+}
+
+/// Returns the prefixed alternatives of a lower case property name, such as `-webkit-mask` for `mask`.
+pub fn property_prefixes(name: &str) -> &'static [Prefixed] {
+	PROPERTIES.get(name).copied().unwrap_or_default()
+}
+
+/// Returns the standard property which a lower case prefixed property is an alternative to, along with its [Prefixed]
+/// entry.
+pub fn unprefixed_property(name: &str) -> Option<(&'static str, Prefixed)> {
+	standard(&PROPERTIES, name)
+}
+
+/// Returns the prefixed alternatives of a lower case keyword value of a property, such as `-webkit-sticky` for
+/// `position: sticky`.
+pub fn value_prefixes(property: &str, value: &str) -> &'static [Prefixed] {
+	VALUES.get(format!("{property}:{value}").as_str()).copied().unwrap_or_default()
+}
+
+/// Returns the standard value which a lower case prefixed value of a property is an alternative to, along with its
+/// [Prefixed] entry.
+pub fn unprefixed_value(property: &str, value: &str) -> Option<(&'static str, Prefixed)> {
+	VALUES.entries().find_map(|(standard, prefixed)| {
+		let (standard_property, standard) = standard.split_once(':')?;
+		let prefixed = prefixed.iter().find(|p| p.name == value)?;
+		(standard_property == property).then_some((standard, *prefixed))
+	})
+}
+
+/// Returns the prefixed alternatives of a lower case pseudo-class or pseudo-element (written as for
+/// [selector_support][super::selector_support]), such as `::-moz-selection` for `::selection`.
+pub fn selector_prefixes(name: &str) -> &'static [Prefixed] {
+	SELECTORS.get(name).copied().unwrap_or_default()
+}
+
+/// Returns the standard pseudo-class or pseudo-element which a lower case prefixed one is an alternative to, along
+/// with its [Prefixed] entry.
+pub fn unprefixed_selector(name: &str) -> Option<(&'static str, Prefixed)> {
+	standard(&SELECTORS, name)
+}
+
+/// Returns the prefixed alternatives of a lower case at-rule (written with its `@`), such as `@-webkit-keyframes` for
+/// `@keyframes`.
+pub fn at_rule_prefixes(name: &str) -> &'static [Prefixed] {
+	AT_RULES.get(name).copied().unwrap_or_default()
+}
+
+/// Returns the standard at-rule which a lower case prefixed at-rule is an alternative to, along with its [Prefixed]
+/// entry.
+pub fn unprefixed_at_rule(name: &str) -> Option<(&'static str, Prefixed)> {
+	standard(&AT_RULES, name)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Browser, Version, at_rule_support, property_meta, property_support, selector_support, value_support};
+
+	#[test]
+	fn test_prefixes() {
+		assert_eq!(property_prefixes("user-select").len(), 3);
+		assert!(property_prefixes("color").is_empty());
+		let (standard, prefixed) = unprefixed_property("-moz-user-select").unwrap();
+		assert_eq!(standard, "user-select");
+		assert_eq!(prefixed.support.since(Browser::Firefox), Some(Version::new(2, 0)));
+		assert_eq!(value_prefixes("position", "sticky")[0].name, "-webkit-sticky");
+		assert_eq!(unprefixed_value("width", "-webkit-fill-available").unwrap().0, "stretch");
+		assert_eq!(unprefixed_value("display", "-webkit-fill-available"), None);
+		assert_eq!(unprefixed_selector("::-webkit-input-placeholder").unwrap().0, "::placeholder");
+		assert_eq!(unprefixed_at_rule("@-webkit-keyframes").unwrap().0, "@keyframes");
+	}
+
+	#[test]
+	fn test_standard_support() {
+		// Whether a prefix is needed depends on the support of the standard feature, so each needs to be known. Properties
+		// which csskit doesn't implement yet have no compat data, so their prefixes are left alone.
+		for property in PROPERTIES.keys().filter(|property| property_meta(property).is_some()) {
+			assert!(property_support(property).is_some(), "{property}");
+		}
+		for key in VALUES.keys() {
+			let (property, value) = key.split_once(':').unwrap();
+			assert!(value_support(property, value).is_some(), "{key}");
+		}
+		for selector in SELECTORS.keys() {
+			assert_ne!(selector_support(selector), selector_support(":hover"), "{selector}");
+		}
+		for at_rule in AT_RULES.keys() {
+			assert!(at_rule_support(at_rule).is_some(), "{at_rule}");
+		}
+	}
+}
@@ -0,0 +1,142 @@
+use std::{fmt, str::FromStr};
+use strum::{Display, EnumString, VariantNames};
+use thiserror::Error;
+
+/// A browser which can be targeted, named after its [caniuse](https://caniuse.com) agent.
+#[derive(Display, EnumString, VariantNames, Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum Browser {
+	Chrome,
+	Edge,
+	Firefox,
+	Safari,
+	Opera,
+	#[strum(to_string = "ios_saf", serialize = "ios_safari")]
+	IosSafari,
+	#[strum(to_string = "and_chr", serialize = "android_chrome")]
+	AndroidChrome,
+	Samsung,
+}
+
+/// A browser version, such as `15.4`. Versions without a minor part (such as `100`) have a minor version of `0`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Version {
+	pub major: u16,
+	pub minor: u16,
+}
+
+impl Version {
+	pub const fn new(major: u16, minor: u16) -> Self {
+		Self { major, minor }
+	}
+}
+
+impl FromStr for Version {
+	type Err = TargetError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || TargetError::InvalidVersion(s.into());
+		let (major, minor) = s.split_once('.').unwrap_or((s, "0"));
+		Ok(Self { major: major.parse().map_err(|_| invalid())?, minor: minor.parse().map_err(|_| invalid())? })
+	}
+}
+
+impl fmt::Display for Version {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.minor {
+			0 => write!(f, "{}", self.major),
+			minor => write!(f, "{}.{}", self.major, minor),
+		}
+	}
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TargetError {
+	#[error("unknown browser `{0}`, expected one of {browsers}", browsers = Browser::VARIANTS.join(", "))]
+	UnknownBrowser(String),
+	#[error("invalid version `{0}`, expected a number such as `15` or `15.4`")]
+	InvalidVersion(String),
+	#[error("invalid target `{0}`, expected a browser and minimum version such as `safari 15.4`")]
+	InvalidTarget(String),
+}
+
+/// The oldest version of a [Browser] which stylesheets should work in, written as `<browser> <version>` (for example
+/// `chrome 100`, or `safari >= 15.4`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BrowserTarget {
+	pub browser: Browser,
+	pub version: Version,
+}
+
+impl BrowserTarget {
+	pub const fn new(browser: Browser, version: Version) -> Self {
+		Self { browser, version }
+	}
+
+	/// Returns true if this target's version of the browser is at least the given [Version].
+	pub fn supports(&self, browser: Browser, since: Version) -> bool {
+		self.browser == browser && self.version >= since
+	}
+}
+
+impl FromStr for BrowserTarget {
+	type Err = TargetError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.split_whitespace();
+		let (Some(browser), Some(mut version)) = (parts.next(), parts.next()) else {
+			return Err(TargetError::InvalidTarget(s.into()));
+		};
+		if version == ">=" {
+			version = parts.next().ok_or_else(|| TargetError::InvalidTarget(s.into()))?;
+		}
+		if parts.next().is_some() {
+			return Err(TargetError::InvalidTarget(s.into()));
+		}
+		let browser = Browser::from_str(browser).map_err(|_| TargetError::UnknownBrowser(browser.into()))?;
+		Ok(Self { browser, version: version.parse()? })
+	}
+}
+
+impl TryFrom<String> for BrowserTarget {
+	type Error = TargetError;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		value.parse()
+	}
+}
+
+impl fmt::Display for BrowserTarget {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} {}", self.browser, self.version)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!("chrome 100".parse(), Ok(BrowserTarget::new(Browser::Chrome, Version::new(100, 0))));
+		assert_eq!("Safari >= 15.4".parse(), Ok(BrowserTarget::new(Browser::Safari, Version::new(15, 4))));
+		assert_eq!("ios_saf 16".parse(), Ok(BrowserTarget::new(Browser::IosSafari, Version::new(16, 0))));
+		assert_eq!("netscape 4".parse::<BrowserTarget>(), Err(TargetError::UnknownBrowser("netscape".into())));
+		assert_eq!("chrome".parse::<BrowserTarget>(), Err(TargetError::InvalidTarget("chrome".into())));
+		assert_eq!("chrome latest".parse::<BrowserTarget>(), Err(TargetError::InvalidVersion("latest".into())));
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(BrowserTarget::new(Browser::IosSafari, Version::new(15, 4)).to_string(), "ios_saf 15.4");
+		assert_eq!(BrowserTarget::new(Browser::Firefox, Version::new(115, 0)).to_string(), "firefox 115");
+	}
+
+	#[test]
+	fn test_supports() {
+		let target = BrowserTarget::new(Browser::Safari, Version::new(15, 4));
+		assert!(target.supports(Browser::Safari, Version::new(15, 0)));
+		assert!(!target.supports(Browser::Safari, Version::new(16, 0)));
+		assert!(!target.supports(Browser::Chrome, Version::new(1, 0)));
+	}
+}
@@ -1,4 +1,5 @@
 #![deny(warnings)]
+mod compat;
 mod properties;
 mod rules;
 mod selector;
@@ -11,10 +12,12 @@ mod units;
 mod values;
 mod visit;
 
+pub use compat::*;
 use csskit_derives::Visitable;
 pub use properties::*;
 pub use rules::*;
 pub use selector::*;
+pub use specificity::*;
 pub use stylerule::*;
 pub use stylesheet::*;
 pub use types::*;
@@ -0,0 +1,76 @@
+/// Each physical property, alongside its flow-relative equivalent in a horizontal, left-to-right writing mode.
+pub const PHYSICAL_TO_LOGICAL: &[(&str, &str)] = &[
+	("width", "inline-size"),
+	("height", "block-size"),
+	("min-width", "min-inline-size"),
+	("min-height", "min-block-size"),
+	("max-width", "max-inline-size"),
+	("max-height", "max-block-size"),
+	("top", "inset-block-start"),
+	("bottom", "inset-block-end"),
+	("left", "inset-inline-start"),
+	("right", "inset-inline-end"),
+	("margin-top", "margin-block-start"),
+	("margin-bottom", "margin-block-end"),
+	("margin-left", "margin-inline-start"),
+	("margin-right", "margin-inline-end"),
+	("padding-top", "padding-block-start"),
+	("padding-bottom", "padding-block-end"),
+	("padding-left", "padding-inline-start"),
+	("padding-right", "padding-inline-end"),
+	("scroll-margin-top", "scroll-margin-block-start"),
+	("scroll-margin-bottom", "scroll-margin-block-end"),
+	("scroll-margin-left", "scroll-margin-inline-start"),
+	("scroll-margin-right", "scroll-margin-inline-end"),
+	("scroll-padding-top", "scroll-padding-block-start"),
+	("scroll-padding-bottom", "scroll-padding-block-end"),
+	("scroll-padding-left", "scroll-padding-inline-start"),
+	("scroll-padding-right", "scroll-padding-inline-end"),
+	("border-top", "border-block-start"),
+	("border-bottom", "border-block-end"),
+	("border-left", "border-inline-start"),
+	("border-right", "border-inline-end"),
+	("border-top-width", "border-block-start-width"),
+	("border-bottom-width", "border-block-end-width"),
+	("border-left-width", "border-inline-start-width"),
+	("border-right-width", "border-inline-end-width"),
+	("border-top-style", "border-block-start-style"),
+	("border-bottom-style", "border-block-end-style"),
+	("border-left-style", "border-inline-start-style"),
+	("border-right-style", "border-inline-end-style"),
+	("border-top-color", "border-block-start-color"),
+	("border-bottom-color", "border-block-end-color"),
+	("border-left-color", "border-inline-start-color"),
+	("border-right-color", "border-inline-end-color"),
+	("border-top-left-radius", "border-start-start-radius"),
+	("border-top-right-radius", "border-start-end-radius"),
+	("border-bottom-left-radius", "border-end-start-radius"),
+	("border-bottom-right-radius", "border-end-end-radius"),
+	("overscroll-behavior-x", "overscroll-behavior-inline"),
+	("overscroll-behavior-y", "overscroll-behavior-block"),
+	("contain-intrinsic-width", "contain-intrinsic-inline-size"),
+	("contain-intrinsic-height", "contain-intrinsic-block-size"),
+];
+
+/// Returns the flow-relative equivalent of a physical property name, if it has one.
+pub fn to_logical_property(name: &str) -> Option<&'static str> {
+	PHYSICAL_TO_LOGICAL.iter().find(|(physical, _)| name.eq_ignore_ascii_case(physical)).map(|(_, logical)| *logical)
+}
+
+/// Returns the physical equivalent of a flow-relative property name in a horizontal, left-to-right writing mode, if it
+/// has one.
+pub fn to_physical_property(name: &str) -> Option<&'static str> {
+	PHYSICAL_TO_LOGICAL.iter().find(|(_, logical)| name.eq_ignore_ascii_case(logical)).map(|(physical, _)| *physical)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_to_physical_property() {
+		assert_eq!(to_physical_property("margin-inline-start"), Some("margin-left"));
+		assert_eq!(to_physical_property("Block-Size"), Some("height"));
+		assert_eq!(to_physical_property("margin-left"), None);
+	}
+}
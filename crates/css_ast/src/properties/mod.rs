@@ -1,4 +1,4 @@
-use crate::values;
+use crate::{PropertyMeta, values};
 use css_lexer::{Cursor, KindSet};
 use css_parse::{
 	Build, ComponentValues, DeclarationValue, Parser, Peek, Result as ParserResult, State, T, keyword_set,
@@ -6,8 +6,12 @@ use css_parse::{
 use csskit_derives::{Parse, ToCursors, ToSpan, Visitable};
 use std::{fmt::Debug, hash::Hash};
 
-// The build.rs generates a list of CSS properties from the value mods
+mod logical;
+pub use logical::*;
+
+// The build.rs generates a list of CSS properties from the value mods, along with those whose grammar accepts a color
 include!(concat!(env!("OUT_DIR"), "/css_apply_properties.rs"));
+include!(concat!(env!("OUT_DIR"), "/css_color_properties.rs"));
 
 #[derive(Parse, ToSpan, ToCursors, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
@@ -87,6 +91,41 @@ macro_rules! define_property_id {
 }
 apply_properties!(define_property_id);
 
+/// Checks if the grammar of a lower case property name accepts a `<color>`, either directly (such as `color`) or
+/// within another type (such as the `<shadow>` of `box-shadow`, or a gradient in `background-image`). This includes
+/// properties which aren't parsed yet (such as `background`), as their grammar is still known. Returns `None` for
+/// properties whose grammar isn't known, including custom properties.
+pub fn property_accepts_color(name: &str) -> Option<bool> {
+	COLOR_PROPERTIES.get(name).copied()
+}
+
+impl PropertyId {
+	/// Returns the [PropertyId] for a lower case property name, or `None` if the property is not known.
+	pub fn from_name(name: &str) -> Option<Self> {
+		Self::MAP.get(name).copied()
+	}
+}
+
+macro_rules! define_property_meta {
+	( $( $name: ident: $ty: ident$(<$a: lifetime>)? = $str: tt,)+ ) => {
+		impl PropertyId {
+			/// Returns the compatibility metadata for this property.
+			pub fn meta(&self) -> PropertyMeta {
+				match self {
+					$(Self::$name(_) => PropertyMeta {
+						name: $str,
+						baseline: values::$ty::BASELINE,
+						versions: values::$ty::VERSIONS,
+						popularity: values::$ty::POPULARITY,
+						caniuse: values::$ty::CANIUSE,
+					},)+
+				}
+			}
+		}
+	}
+}
+apply_properties!(define_property_meta);
+
 impl<'a> DeclarationValue<'a> for StyleValue<'a> {
 	type ComputedValue = Computed<'a>;
 
@@ -147,6 +186,20 @@ mod tests {
 		assert_eq!(std::mem::size_of::<StyleValue>(), 328);
 	}
 
+	#[test]
+	fn test_accepts_color() {
+		assert_eq!(property_accepts_color("color"), Some(true));
+		assert_eq!(property_accepts_color("border-color"), Some(true));
+		assert_eq!(property_accepts_color("outline"), Some(true));
+		assert_eq!(property_accepts_color("box-shadow"), Some(true));
+		assert_eq!(property_accepts_color("background"), Some(true));
+		assert_eq!(property_accepts_color("background-image"), Some(true));
+		assert_eq!(property_accepts_color("animation"), Some(false));
+		assert_eq!(property_accepts_color("animation-name"), Some(false));
+		assert_eq!(property_accepts_color("font-family"), Some(false));
+		assert_eq!(property_accepts_color("--brand"), None);
+	}
+
 	#[test]
 	fn test_writes() {
 		assert_parse!(Property, "width:inherit", Property { value: StyleValue::Inherit(_), .. });
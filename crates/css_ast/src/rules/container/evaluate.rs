@@ -0,0 +1,282 @@
+use css_parse::ConditionInParens;
+
+use super::{
+	ContainerCondition, ContainerConditionList, ContainerFeature, ContainerInParens, ContainerQuery, features::*,
+};
+use crate::{
+	rules::media::{all, any, compare},
+	types::Ratio,
+	units::Length,
+};
+
+/// The size of a query container, which container queries are evaluated against.
+///
+/// Sizes are logical, so that the same queries can be evaluated against horizontal and vertical writing modes. A
+/// container with `container-type: inline-size` has no queryable block size, so queries against its block axis (such as
+/// `height` in a horizontal writing mode) evaluate to `None`.
+///
+/// ```
+/// use css_ast::*;
+/// let size = ContainerSize { font_size: 20.0, ..ContainerSize::inline(400.0) };
+/// assert_eq!(size.width(), Some(400.0));
+/// assert_eq!(size.height(), None);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContainerSize {
+	/// The inline size of the container's content box in `px`.
+	pub inline_size: f32,
+	/// The block size of the container's content box in `px`, if the container is a `size` container.
+	pub block_size: Option<f32>,
+	/// Whether the container has a vertical writing mode, making its inline axis vertical.
+	pub vertical: bool,
+	/// The font size of the container in `px`, which `em` lengths are relative to.
+	pub font_size: f32,
+}
+
+impl ContainerSize {
+	/// A `container-type: size` container, with the given width and height in a horizontal writing mode.
+	pub fn new(width: f32, height: f32) -> Self {
+		Self { inline_size: width, block_size: Some(height), vertical: false, font_size: 16.0 }
+	}
+
+	/// A `container-type: inline-size` container, with the given width in a horizontal writing mode.
+	pub fn inline(width: f32) -> Self {
+		Self { block_size: None, ..Self::new(width, 0.0) }
+	}
+
+	/// The physical width of the container, if it can be queried.
+	pub fn width(&self) -> Option<f32> {
+		if self.vertical { self.block_size } else { Some(self.inline_size) }
+	}
+
+	/// The physical height of the container, if it can be queried.
+	pub fn height(&self) -> Option<f32> {
+		if self.vertical { Some(self.inline_size) } else { self.block_size }
+	}
+
+	/// Resolves a [Length] to `px`, where relative lengths resolve against this container as they would in a container
+	/// query. Returns `None` for lengths this container can't resolve, such as viewport units.
+	pub fn resolve_length(&self, length: Length) -> Option<f32> {
+		if let Some(px) = length.to_px() {
+			return Some(px);
+		}
+		let size = match length {
+			Length::Em(_) | Length::Rem(_) => self.font_size,
+			Length::Cqi(_) => self.inline_size / 100.0,
+			Length::Cqb(_) => self.block_size? / 100.0,
+			Length::Cqw(_) => self.width()? / 100.0,
+			Length::Cqh(_) => self.height()? / 100.0,
+			Length::Cqmin(_) => self.inline_size.min(self.block_size?) / 100.0,
+			Length::Cqmax(_) => self.inline_size.max(self.block_size?) / 100.0,
+			_ => return None,
+		};
+		Some(f32::from(length) * size)
+	}
+}
+
+impl<'a> ContainerConditionList<'a> {
+	/// Returns `true` if any of the conditions in this list match the container.
+	pub fn matches(&self, size: &ContainerSize) -> bool {
+		self.evaluate(size) == Some(true)
+	}
+
+	/// Evaluates this list against a single container, ignoring container names. `None` means the result is unknown,
+	/// because a query relies on a size the container can't be queried for, a style or scroll-state query, or
+	/// `<general-enclosed>` syntax.
+	pub fn evaluate(&self, size: &ContainerSize) -> Option<bool> {
+		any((&self.0).into_iter().map(|(condition, _)| condition.evaluate(size)))
+	}
+}
+
+impl<'a> ContainerCondition<'a> {
+	/// Evaluates this condition against the container, ignoring its name. A condition with only a name always matches.
+	/// See [ContainerConditionList::evaluate].
+	pub fn evaluate(&self, size: &ContainerSize) -> Option<bool> {
+		self.condition.as_ref().map_or(Some(true), |query| query.evaluate(size))
+	}
+}
+
+impl<'a> ContainerQuery<'a> {
+	/// Evaluates this query against the container. See [ContainerConditionList::evaluate].
+	pub fn evaluate(&self, size: &ContainerSize) -> Option<bool> {
+		match self {
+			Self::Is(query) => evaluate_in_parens(query, size),
+			Self::Not(_, query) => evaluate_in_parens(query, size).map(|b| !b),
+			Self::And(queries) => all(queries.iter().map(|(query, _)| evaluate_in_parens(query, size))),
+			Self::Or(queries) => any(queries.iter().map(|(query, _)| evaluate_in_parens(query, size))),
+		}
+	}
+
+	/// Returns `true` if this query contains a size feature, which can only be evaluated against a size container.
+	pub fn is_size_query(&self) -> bool {
+		self.features().any(|feature| feature.is_size_feature())
+	}
+
+	/// Returns `true` if this query contains a feature which needs the container's block size, and so can only be
+	/// evaluated against a `container-type: size` container (rather than `inline-size`).
+	pub fn queries_block_axis(&self) -> bool {
+		self.features().any(|feature| feature.queries_block_axis())
+	}
+
+	fn features(&self) -> impl Iterator<Item = &ContainerFeature<'a>> {
+		let queries: Vec<&ContainerInParens<'a>> = match self {
+			Self::Is(query) | Self::Not(_, query) => vec![query],
+			Self::And(queries) | Self::Or(queries) => queries.iter().map(|(query, _)| query).collect(),
+		};
+		queries.into_iter().flat_map(|query| -> Box<dyn Iterator<Item = &ContainerFeature<'a>>> {
+			match query {
+				ConditionInParens::Feature(feature) => Box::new(std::iter::once(feature)),
+				ConditionInParens::Condition(_, condition, _) => Box::new(condition.features()),
+				ConditionInParens::GeneralEnclosed(_) => Box::new(std::iter::empty()),
+			}
+		})
+	}
+}
+
+fn evaluate_in_parens(query: &ContainerInParens, size: &ContainerSize) -> Option<bool> {
+	match query {
+		ConditionInParens::Feature(feature) => feature.evaluate(size),
+		ConditionInParens::Condition(_, condition, _) => condition.evaluate(size),
+		ConditionInParens::GeneralEnclosed(_) => None,
+	}
+}
+
+// Evaluates a `ranged_feature!` against the container's `$actual` size, if it has one. `$resolve` maps a feature value
+// to `px`. Container features have no `min-`/`max-` prefixes, so the legacy syntax is an equality check.
+macro_rules! ranged {
+	($feature: ident::$name: ident, $actual: expr, $resolve: expr) => {{
+		let resolve = $resolve;
+		$actual.and_then(|actual: f32| match $name {
+			$feature::Left(_, _, comparison, value, _) => {
+				resolve(*value).map(|value| compare(actual, *comparison, value))
+			}
+			$feature::Right(_, value, comparison, _, _) => {
+				resolve(*value).map(|value| compare(value, *comparison, actual))
+			}
+			$feature::Range(_, left, left_comparison, _, right_comparison, right, _) => {
+				resolve(*left).zip(resolve(*right)).map(|(left, right)| {
+					compare(left, *left_comparison, actual) && compare(actual, *right_comparison, right)
+				})
+			}
+			$feature::Legacy(_, _, _, value, _) => resolve(*value).map(|value| actual == value),
+		})
+	}};
+}
+
+impl<'a> ContainerFeature<'a> {
+	/// Evaluates this feature against the container. Style and scroll-state features, and sizes the container can't be
+	/// queried for, evaluate to `None`.
+	pub fn evaluate(&self, size: &ContainerSize) -> Option<bool> {
+		let length = |length: Length| size.resolve_length(length);
+		let ratio = |ratio: Ratio| {
+			let denominator = ratio.denominator.map_or(1.0, f32::from);
+			(denominator != 0.0).then(|| f32::from(ratio.numerator) / denominator)
+		};
+		match self {
+			Self::Width(f) => ranged!(WidthContainerFeature::f, size.width(), length),
+			Self::Height(f) => ranged!(HeightContainerFeature::f, size.height(), length),
+			Self::InlineSize(f) => ranged!(InlineSizeContainerFeature::f, Some(size.inline_size), length),
+			Self::BlockSize(f) => ranged!(BlockSizeContainerFeature::f, size.block_size, length),
+			Self::AspectRatio(f) => {
+				ranged!(AspectRatioContainerFeature::f, size.width().zip(size.height()).map(|(w, h)| w / h), ratio)
+			}
+			Self::Orientation(f) => {
+				let (width, height) = size.width().zip(size.height())?;
+				match f {
+					OrientationContainerFeature::Bare(..) => Some(true),
+					OrientationContainerFeature::WithValue(_, _, _, keyword, _) => Some(match keyword {
+						OrientationContainerFeatureKeyword::Portrait(_) => height >= width,
+						OrientationContainerFeatureKeyword::Landscape(_) => height < width,
+					}),
+				}
+			}
+			Self::Style(_) | Self::ScrollState(_) => None,
+		}
+	}
+
+	/// Returns `true` if this is a size feature, rather than a style or scroll-state feature.
+	pub fn is_size_feature(&self) -> bool {
+		!matches!(self, Self::Style(_) | Self::ScrollState(_))
+	}
+
+	/// Returns `true` if this feature needs the container's block size. `width` and `height` are treated as the inline
+	/// and block axes of a horizontal writing mode.
+	pub fn queries_block_axis(&self) -> bool {
+		matches!(self, Self::Height(_) | Self::BlockSize(_) | Self::AspectRatio(_) | Self::Orientation(_))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_parse::Parser;
+
+	fn evaluate(source: &str, size: &ContainerSize) -> Option<bool> {
+		let bump = bumpalo::Bump::default();
+		let mut parser = Parser::new(&bump, source);
+		let result = parser.parse_entirely::<ContainerConditionList>();
+		assert!(result.errors.is_empty(), "{source:?} failed to parse: {:?}", result.errors);
+		result.output.unwrap().evaluate(size)
+	}
+
+	macro_rules! assert_evaluates {
+		($size: expr, $($source: literal => $expected: expr),+ $(,)?) => {
+			let size = $size;
+			$(assert_eq!(evaluate($source, &size), $expected, "{:?}", $source);)+
+		};
+	}
+
+	#[test]
+	fn test_size() {
+		assert_evaluates!(ContainerSize::new(400.0, 300.0),
+			"(width > 300px)" => Some(true),
+			"(width: 400px)" => Some(true),
+			"(inline-size < 25em)" => Some(false),
+			"(200px <= height <= 300px)" => Some(true),
+			"(block-size > 300px)" => Some(false),
+			"(orientation: landscape)" => Some(true),
+			"(aspect-ratio > 1/1)" => Some(true),
+			"(width > 50vw)" => None,
+			"(width > 99cqw)" => Some(true),
+			"card (width > 300px)" => Some(true),
+			"card" => Some(true),
+		);
+	}
+
+	#[test]
+	fn test_inline_size() {
+		assert_evaluates!(ContainerSize::inline(400.0),
+			"(width >= 400px)" => Some(true),
+			"(height > 0px)" => None,
+			"(orientation: portrait)" => None,
+			"(width < 300px) and (height > 0px)" => Some(false),
+		);
+		assert_evaluates!(ContainerSize { vertical: true, ..ContainerSize::inline(400.0) },
+			"(height >= 400px)" => Some(true),
+			"(inline-size >= 400px)" => Some(true),
+			"(width > 0px)" => None,
+		);
+	}
+
+	#[test]
+	fn test_conditions() {
+		assert_evaluates!(ContainerSize::new(400.0, 300.0),
+			"not (width > 500px)" => Some(true),
+			"(width > 500px) or (height > 200px)" => Some(true),
+			"(width > 300px) and ((height > 500px) or (orientation: portrait))" => Some(false),
+			"(width > 300px) and style(--theme: dark)" => None,
+			"(width > 500px) and style(--theme: dark)" => Some(false),
+			"(width > 500px), (height > 200px)" => Some(true),
+		);
+	}
+
+	#[test]
+	fn test_query_axes() {
+		let bump = bumpalo::Bump::default();
+		let query = |source| Parser::new(&bump, source).parse_entirely::<ContainerQuery>().output.unwrap();
+		assert!(query("(width > 1px)").is_size_query());
+		assert!(!query("(width > 1px)").queries_block_axis());
+		assert!(query("(width > 1px) and (not (height > 1px))").queries_block_axis());
+		assert!(!query("style(--a: b)").is_size_query());
+	}
+}
@@ -2,7 +2,7 @@ use crate::{StyleValue, Visit, VisitMut, Visitable as VisitableTrait, VisitableM
 use bumpalo::collections::Vec;
 use css_lexer::Cursor;
 use css_parse::{
-	ConditionKeyword, Declaration, FeatureConditionList, Parse, Parser, Peek, RangedFeatureKeyword,
+	ConditionInParens, ConditionKeyword, Declaration, FeatureConditionList, Parse, Parser, Peek, RangedFeatureKeyword,
 	Result as ParserResult, discrete_feature, keyword_set, ranged_feature,
 };
 use csskit_derives::{ToCursors, ToSpan, Visitable};
@@ -61,28 +61,31 @@ discrete_feature!(
 	pub enum OrientationContainerFeature<"orientation", OrientationContainerFeatureKeyword>
 );
 
+/// A `<style-in-parens>`: a style feature, a nested [StyleQuery], or a `<general-enclosed>`.
+pub type StyleInParens<'a> = ConditionInParens<'a, StyleQuery<'a>, Declaration<'a, StyleValue<'a>>>;
+
 #[derive(ToCursors, ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 #[visit]
 pub enum StyleQuery<'a> {
-	Is(Declaration<'a, StyleValue<'a>>),
-	Not(ConditionKeyword, Declaration<'a, StyleValue<'a>>),
-	And(Vec<'a, (Declaration<'a, StyleValue<'a>>, Option<ConditionKeyword>)>),
-	Or(Vec<'a, (Declaration<'a, StyleValue<'a>>, Option<ConditionKeyword>)>),
+	Is(StyleInParens<'a>),
+	Not(ConditionKeyword, StyleInParens<'a>),
+	And(Vec<'a, (StyleInParens<'a>, Option<ConditionKeyword>)>),
+	Or(Vec<'a, (StyleInParens<'a>, Option<ConditionKeyword>)>),
 }
 
 impl<'a> FeatureConditionList<'a> for StyleQuery<'a> {
 	type FeatureCondition = Declaration<'a, StyleValue<'a>>;
-	fn build_is(feature: Self::FeatureCondition) -> Self {
+	fn build_is(feature: StyleInParens<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn build_not(keyword: ConditionKeyword, feature: Self::FeatureCondition) -> Self {
+	fn build_not(keyword: ConditionKeyword, feature: StyleInParens<'a>) -> Self {
 		Self::Not(keyword, feature)
 	}
-	fn build_and(feature: Vec<'a, (Self::FeatureCondition, Option<ConditionKeyword>)>) -> Self {
+	fn build_and(feature: Vec<'a, (StyleInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::And(feature)
 	}
-	fn build_or(feature: Vec<'a, (Self::FeatureCondition, Option<ConditionKeyword>)>) -> Self {
+	fn build_or(feature: Vec<'a, (StyleInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::Or(feature)
 	}
 }
@@ -133,28 +136,31 @@ impl<'a> VisitableMut for StyleQuery<'a> {
 	}
 }
 
+/// A `<scroll-state-in-parens>`: a [ScrollStateFeature], a nested [ScrollStateQuery], or a `<general-enclosed>`.
+pub type ScrollStateInParens<'a> = ConditionInParens<'a, ScrollStateQuery<'a>, ScrollStateFeature>;
+
 #[derive(ToCursors, ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 #[visit]
 pub enum ScrollStateQuery<'a> {
-	Is(ScrollStateFeature),
-	Not(ConditionKeyword, ScrollStateFeature),
-	And(Vec<'a, (ScrollStateFeature, Option<ConditionKeyword>)>),
-	Or(Vec<'a, (ScrollStateFeature, Option<ConditionKeyword>)>),
+	Is(ScrollStateInParens<'a>),
+	Not(ConditionKeyword, ScrollStateInParens<'a>),
+	And(Vec<'a, (ScrollStateInParens<'a>, Option<ConditionKeyword>)>),
+	Or(Vec<'a, (ScrollStateInParens<'a>, Option<ConditionKeyword>)>),
 }
 
 impl<'a> FeatureConditionList<'a> for ScrollStateQuery<'a> {
 	type FeatureCondition = ScrollStateFeature;
-	fn build_is(feature: ScrollStateFeature) -> Self {
+	fn build_is(feature: ScrollStateInParens<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn build_not(keyword: ConditionKeyword, feature: ScrollStateFeature) -> Self {
+	fn build_not(keyword: ConditionKeyword, feature: ScrollStateInParens<'a>) -> Self {
 		Self::Not(keyword, feature)
 	}
-	fn build_and(feature: Vec<'a, (ScrollStateFeature, Option<ConditionKeyword>)>) -> Self {
+	fn build_and(feature: Vec<'a, (ScrollStateInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::And(feature)
 	}
-	fn build_or(feature: Vec<'a, (ScrollStateFeature, Option<ConditionKeyword>)>) -> Self {
+	fn build_or(feature: Vec<'a, (ScrollStateInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::Or(feature)
 	}
 }
@@ -303,7 +309,7 @@ mod tests {
 		assert_eq!(std::mem::size_of::<AspectRatioContainerFeature>(), 180);
 		assert_eq!(std::mem::size_of::<OrientationContainerFeature>(), 64);
 		assert_eq!(std::mem::size_of::<StyleQuery>(), 416);
-		assert_eq!(std::mem::size_of::<ScrollStateQuery>(), 88);
+		assert_eq!(std::mem::size_of::<ScrollStateQuery>(), 96);
 		assert_eq!(std::mem::size_of::<ScrollStateFeature>(), 68);
 		assert_eq!(std::mem::size_of::<ScrollableScrollStateFeature>(), 64);
 		assert_eq!(std::mem::size_of::<SnappedScrollStateFeature>(), 64);
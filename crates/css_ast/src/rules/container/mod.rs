@@ -1,14 +1,16 @@
 use crate::{Visit, VisitMut, Visitable as VisitableTrait, VisitableMut, stylesheet::Rule};
 use bumpalo::collections::Vec;
-use css_lexer::{Cursor, Kind};
+use css_lexer::{Cursor, Kind, KindSet};
 use css_parse::{
-	AtRule, Build, ConditionKeyword, FeatureConditionList, Parse, Parser, Peek, PreludeList, Result as ParserResult,
-	RuleList, T, atkeyword_set, diagnostics, keyword_set,
+	AtRule, Build, CommaSeparated, ConditionInParens, ConditionKeyword, FeatureConditionList, Parse, Parser, Peek,
+	Result as ParserResult, RuleList, T, atkeyword_set, diagnostics, keyword_set,
 };
 use csskit_derives::{Parse, Peek, ToCursors, ToSpan, Visitable};
 use csskit_proc_macro::visit;
 
+mod evaluate;
 mod features;
+pub use evaluate::*;
 pub use features::*;
 
 atkeyword_set!(pub struct AtContainerKeyword "container");
@@ -17,25 +19,17 @@ atkeyword_set!(pub struct AtContainerKeyword "container");
 #[derive(Parse, Peek, ToCursors, ToSpan, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
-pub struct ContainerRule<'a>(AtRule<'a, AtContainerKeyword, ContainerConditionList<'a>, ContainerRulesBlock<'a>>);
+pub struct ContainerRule<'a>(pub AtRule<'a, AtContainerKeyword, ContainerConditionList<'a>, ContainerRulesBlock<'a>>);
 
 #[derive(Parse, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct ContainerRulesBlock<'a>(RuleList<'a, Rule<'a>>);
 
-#[derive(ToCursors, ToSpan, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+// https://drafts.csswg.org/css-contain-3/#container-rule
+// @container <container-condition>#
+#[derive(Parse, ToCursors, ToSpan, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct ContainerConditionList<'a>(pub Vec<'a, ContainerCondition<'a>>);
-
-impl<'a> PreludeList<'a> for ContainerConditionList<'a> {
-	type PreludeItem = ContainerCondition<'a>;
-}
-
-impl<'a> Parse<'a> for ContainerConditionList<'a> {
-	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		Ok(Self(Self::parse_prelude_list(p)?))
-	}
-}
+pub struct ContainerConditionList<'a>(pub CommaSeparated<'a, ContainerCondition<'a>>);
 
 #[derive(ToCursors, ToSpan, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
@@ -45,6 +39,10 @@ pub struct ContainerCondition<'a> {
 	pub condition: Option<ContainerQuery<'a>>,
 }
 
+impl<'a> Peek<'a> for ContainerCondition<'a> {
+	const PEEK_KINDSET: KindSet = KindSet::new(&[Kind::Ident, Kind::Function, Kind::LeftParen]);
+}
+
 impl<'a> Parse<'a> for ContainerCondition<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
 		let mut name = None;
@@ -63,19 +61,22 @@ impl<'a> Parse<'a> for ContainerCondition<'a> {
 	}
 }
 
+/// A `<query-in-parens>`: a [ContainerFeature], a nested [ContainerQuery], or a `<general-enclosed>`.
+pub type ContainerInParens<'a> = ConditionInParens<'a, ContainerQuery<'a>, ContainerFeature<'a>>;
+
 #[derive(ToCursors, ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
 pub enum ContainerQuery<'a> {
-	Is(ContainerFeature<'a>),
-	Not(ConditionKeyword, ContainerFeature<'a>),
-	And(Vec<'a, (ContainerFeature<'a>, Option<ConditionKeyword>)>),
-	Or(Vec<'a, (ContainerFeature<'a>, Option<ConditionKeyword>)>),
+	Is(ContainerInParens<'a>),
+	Not(ConditionKeyword, ContainerInParens<'a>),
+	And(Vec<'a, (ContainerInParens<'a>, Option<ConditionKeyword>)>),
+	Or(Vec<'a, (ContainerInParens<'a>, Option<ConditionKeyword>)>),
 }
 
 impl<'a> Peek<'a> for ContainerQuery<'a> {
 	fn peek(p: &Parser<'a>, c: Cursor) -> bool {
-		<T![Function]>::peek(p, c) || <T![Ident]>::peek(p, c)
+		<T![Function]>::peek(p, c) || <T!['(']>::peek(p, c) || <T![Ident]>::peek(p, c)
 	}
 }
 
@@ -87,16 +88,16 @@ impl<'a> Parse<'a> for ContainerQuery<'a> {
 
 impl<'a> FeatureConditionList<'a> for ContainerQuery<'a> {
 	type FeatureCondition = ContainerFeature<'a>;
-	fn build_is(feature: ContainerFeature<'a>) -> Self {
+	fn build_is(feature: ContainerInParens<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn build_not(keyword: ConditionKeyword, feature: ContainerFeature<'a>) -> Self {
+	fn build_not(keyword: ConditionKeyword, feature: ContainerInParens<'a>) -> Self {
 		Self::Not(keyword, feature)
 	}
-	fn build_and(feature: Vec<'a, (ContainerFeature<'a>, Option<ConditionKeyword>)>) -> Self {
+	fn build_and(feature: Vec<'a, (ContainerInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::And(feature)
 	}
-	fn build_or(feature: Vec<'a, (ContainerFeature<'a>, Option<ConditionKeyword>)>) -> Self {
+	fn build_or(feature: Vec<'a, (ContainerInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::Or(feature)
 	}
 }
@@ -168,8 +169,10 @@ apply_container_features!(container_feature_keyword);
 
 impl<'a> Parse<'a> for ContainerFeature<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		// TODO: style() & scroll-state() queries. Erroring allows them to be parsed as `<general-enclosed>` until then.
 		if p.peek::<T![Function]>() {
-			todo!();
+			let c = p.peek_n(1);
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
 		}
 		let mut c = p.peek_n(2);
 		macro_rules! match_feature {
@@ -193,9 +196,13 @@ impl<'a> Parse<'a> for ContainerFeature<'a> {
 			Ok(apply_container_features!(match_feature))
 		} else {
 			// Styles like (1em < width < 1em) or (1em <= width <= 1em)
-			c = p.peek_n(3);
+			c = p.peek_n(4);
+			if c != Kind::Ident {
+				c = p.peek_n(5)
+			}
 			if c != Kind::Ident {
-				c = p.peek_n(4)
+				c = p.parse::<T![Any]>()?.into();
+				Err(diagnostics::Unexpected(c.into(), c.into()))?
 			}
 			Ok(apply_container_features!(match_feature))
 		}
@@ -236,9 +243,16 @@ mod tests {
 		assert_parse!(ContainerCondition, "(width:2px)");
 		assert_parse!(ContainerCondition, "(inline-size>30em)");
 		assert_parse!(ContainerCondition, "(1em<width<1em)");
+		assert_parse!(ContainerCondition, "(1em<=width<=1em)");
 		assert_parse!(ContainerRule, "@container foo{}");
 		assert_parse!(ContainerRule, "@container foo (width:2px){}");
 		assert_parse!(ContainerRule, "@container foo (10em<width<10em){}");
 		assert_parse!(ContainerRule, "@container foo (width:2px){body{color:black}}");
+		assert_parse!(ContainerQuery, "not (width:2px)", ContainerQuery::Not(_, _));
+		assert_parse!(ContainerQuery, "(width>2px)and ((height>2px)or (orientation:portrait))", ContainerQuery::And(_));
+		assert_parse!(ContainerQuery, "style(--foo:bar)", ContainerQuery::Is(ContainerInParens::GeneralEnclosed(_)));
+		assert_parse!(ContainerRule, "@container card not ((width>2px)or (height>2px)){}");
+		assert_parse!(ContainerRule, "@container sidebar (width>400px),card (height>2px){}");
+		assert_parse!(ContainerConditionList, "(width>400px),card (height>2px),(orientation:portrait)");
 	}
 }
@@ -1,19 +1,70 @@
-use crate::Todo;
+use css_lexer::ToSpan;
+use css_parse::{
+	AtRule, ComponentValues, DeclarationList, Parse, Parser, Result as ParserResult, T, atkeyword_set, diagnostics,
+};
+use csskit_derives::{IntoCursor, Parse, Peek, ToCursors, ToSpan, Visitable};
+
+atkeyword_set!(pub struct AtCounterStyleKeyword "counter-style");
 
 // https://drafts.csswg.org/css-counter-styles-3/#the-counter-style-rule
-pub type CounterStyleRule = Todo;
+#[derive(Parse, Peek, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct CounterStyleRule<'a>(pub AtRule<'a, AtCounterStyleKeyword, CounterStyleName, CounterStyleRuleBlock<'a>>);
+
+// https://drafts.csswg.org/css-counter-styles-3/#typedef-counter-style-name
+#[derive(Peek, ToCursors, IntoCursor, Visitable, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit(self)]
+pub struct CounterStyleName(T![Ident]);
+
+impl CounterStyleName {
+	const INVALID: phf::Map<&'static str, bool> = phf::phf_map! {
+		"none" => true,
+		"initial" => true,
+		"inherit" => true,
+		"unset" => true,
+		"revert" => true,
+		"revert-layer" => true,
+		"default" => true,
+	};
+}
+
+impl<'a> Parse<'a> for CounterStyleName {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let ident = p.parse::<T![Ident]>()?;
+		let str = p.parse_str_lower(ident.into());
+		if *Self::INVALID.get(str).unwrap_or(&false) {
+			Err(diagnostics::UnexpectedIdent(str.into(), ident.to_span()))?
+		}
+		Ok(Self(ident))
+	}
+}
+
+// The descriptors of @counter-style are not yet typed, so their values are kept as-is.
+#[derive(Parse, Peek, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+pub struct CounterStyleRuleBlock<'a>(pub DeclarationList<'a, ComponentValues<'a>>);
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<CounterStyleRule>(), 0);
+		assert_eq!(std::mem::size_of::<CounterStyleName>(), 12);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(CounterStyleRule, "@counter-style thumbs {}");
+		assert_parse!(CounterStyleRule, "@counter-style thumbs{system:cyclic;symbols:\"👍\";suffix:\" \"}");
+		assert_parse!(CounterStyleRule, "@counter-style foo{}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(CounterStyleRule, "@counter-style none{}");
+		assert_parse_error!(CounterStyleRule, "@counter-style{}");
 	}
 }
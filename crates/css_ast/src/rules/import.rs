@@ -1,19 +1,83 @@
-use crate::Todo;
+use css_lexer::{Kind, KindSet};
+use css_parse::{AtRule, ComponentValues, NoBlockAllowed, Parse, Parser, Result as ParserResult, T, atkeyword_set};
+use csskit_derives::{Parse, Peek, ToCursors, ToSpan, Visitable};
+
+use crate::Url;
+
+atkeyword_set!(pub struct AtImportKeyword "import");
 
 // https://drafts.csswg.org/css-cascade-5/#at-ruledef-import
-pub type ImportRule = Todo;
+#[derive(Parse, Peek, ToCursors, ToSpan, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit]
+pub struct ImportRule<'a>(pub AtRule<'a, AtImportKeyword, ImportPrelude<'a>, NoBlockAllowed>);
+
+/// The prelude of an [ImportRule]: the stylesheet to import, followed by any `layer()`, `supports()` & media query
+/// conditions, which are kept as component values.
+///
+/// ```text
+/// @import [ <url> | <string> ] [ layer | layer(<layer-name>) ]? <import-conditions> ;
+/// ```
+#[derive(ToCursors, ToSpan, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
+#[visit(self)]
+pub struct ImportPrelude<'a> {
+	pub target: ImportTarget,
+	pub conditions: Option<ComponentValues<'a>>,
+}
+
+impl<'a> Parse<'a> for ImportPrelude<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		let target = p.parse::<ImportTarget>()?;
+		if p.at_end() || p.peek::<T![;]>() {
+			return Ok(Self { target, conditions: None });
+		}
+		let stop = p.set_stop(KindSet::new(&[Kind::Semicolon]));
+		let conditions = p.parse::<ComponentValues>();
+		p.set_stop(stop);
+		Ok(Self { target, conditions: Some(conditions?) })
+	}
+}
+
+#[derive(Peek, ToCursors, ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
+pub enum ImportTarget {
+	Url(Url),
+	String(T![String]),
+}
+
+impl<'a> Parse<'a> for ImportTarget {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<Url>() { Ok(Self::Url(p.parse::<Url>()?)) } else { Ok(Self::String(p.parse::<T![String]>()?)) }
+	}
+}
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
-		assert_eq!(std::mem::size_of::<ImportRule>(), 0);
+		assert_eq!(std::mem::size_of::<ImportRule>(), 120);
 	}
 
 	#[test]
 	fn test_writes() {
-		//assert_parse!(ImportRule, "@import \"foo.css\"");
+		assert_parse!(ImportRule, "@import \"foo.css\";", "@import\"foo.css\";");
+		assert_parse!(ImportRule, "@import url(foo.css);");
+		assert_parse!(ImportRule, "@import url(\"foo.css\") layer(base) screen;");
+		assert_parse!(
+			ImportRule,
+			"@import 'foo.css' supports(display:grid) screen and (width>1px);",
+			"@import'foo.css' supports(display:grid) screen and (width>1px);"
+		);
+		assert_parse!(crate::StyleSheet, "@import url(foo.css) print;a{}");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ImportRule, "@import;");
+		assert_parse_error!(ImportRule, "@import foo;");
 	}
 }
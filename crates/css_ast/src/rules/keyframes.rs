@@ -6,13 +6,13 @@ use css_parse::{
 };
 use csskit_derives::{IntoCursor, Parse, Peek, ToCursors, ToSpan, Visitable};
 
-atkeyword_set!(struct AtKeyframesKeyword "keyframes");
+atkeyword_set!(pub struct AtKeyframesKeyword "keyframes");
 
 // https://drafts.csswg.org/css-animations/#at-ruledef-keyframes
 #[derive(Peek, Parse, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
-pub struct KeyframesRule<'a>(AtRule<'a, AtKeyframesKeyword, KeyframesName, KeyframesRuleBlock<'a>>);
+pub struct KeyframesRule<'a>(pub AtRule<'a, AtKeyframesKeyword, KeyframesName, KeyframesRuleBlock<'a>>);
 
 #[derive(Peek, ToCursors, IntoCursor, Visitable, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
@@ -0,0 +1,552 @@
+use css_lexer::{Cursor, Kind};
+use css_parse::{Comparison, ConditionInParens};
+
+use super::{
+	MediaCondition, MediaFeature, MediaInParens, MediaPreCondition, MediaQuery, MediaQueryList, MediaType, features::*,
+};
+use crate::{types::Ratio, units::Length};
+
+/// The media type of a [MediaEnvironment].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MediaEnvironmentType {
+	#[default]
+	Screen,
+	Print,
+}
+
+/// The value of the `hover` and `any-hover` media features.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HoverCapability {
+	None,
+	#[default]
+	Hover,
+}
+
+/// The value of the `pointer` and `any-pointer` media features.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PointerAccuracy {
+	None,
+	Coarse,
+	#[default]
+	Fine,
+}
+
+/// The value of the `prefers-color-scheme` media feature.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PreferredColorScheme {
+	#[default]
+	Light,
+	Dark,
+}
+
+/// A description of the device and user preferences that media queries are evaluated against.
+///
+/// The [Default] environment is a 1280x720 desktop screen, with a fine pointer that can hover, and no preferences. Use
+/// struct update syntax to describe other environments:
+///
+/// ```
+/// use css_ast::*;
+/// let env = MediaEnvironment {
+///     prefers_color_scheme: PreferredColorScheme::Dark,
+///     ..MediaEnvironment::mobile(375.0, 667.0)
+/// };
+/// assert_eq!(env.width, 375.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MediaEnvironment {
+	pub media_type: MediaEnvironmentType,
+	/// The viewport width in `px`.
+	pub width: f32,
+	/// The viewport height in `px`.
+	pub height: f32,
+	/// The device width in `px`, used by the deprecated `device-width` feature.
+	pub device_width: f32,
+	/// The device height in `px`, used by the deprecated `device-height` feature.
+	pub device_height: f32,
+	/// The pixel density in `dppx`.
+	pub resolution: f32,
+	/// The initial font size in `px`, which `em` and `rem` lengths are relative to.
+	pub font_size: f32,
+	/// The number of bits per color component, or `0` for a monochrome device.
+	pub color: i32,
+	/// The number of bits per pixel of a monochrome device, or `0` for a color device.
+	pub monochrome: i32,
+	pub grid: bool,
+	pub hover: HoverCapability,
+	pub any_hover: HoverCapability,
+	pub pointer: PointerAccuracy,
+	pub any_pointer: PointerAccuracy,
+	pub prefers_color_scheme: PreferredColorScheme,
+	pub prefers_reduced_motion: bool,
+}
+
+impl Default for MediaEnvironment {
+	fn default() -> Self {
+		Self {
+			media_type: MediaEnvironmentType::Screen,
+			width: 1280.0,
+			height: 720.0,
+			device_width: 1280.0,
+			device_height: 720.0,
+			resolution: 1.0,
+			font_size: 16.0,
+			color: 8,
+			monochrome: 0,
+			grid: false,
+			hover: HoverCapability::Hover,
+			any_hover: HoverCapability::Hover,
+			pointer: PointerAccuracy::Fine,
+			any_pointer: PointerAccuracy::Fine,
+			prefers_color_scheme: PreferredColorScheme::Light,
+			prefers_reduced_motion: false,
+		}
+	}
+}
+
+impl MediaEnvironment {
+	/// A desktop screen with the given viewport size.
+	pub fn screen(width: f32, height: f32) -> Self {
+		Self { width, height, device_width: width, device_height: height, ..Self::default() }
+	}
+
+	/// A touch screen with the given viewport size, a coarse pointer which cannot hover, and a `2dppx` resolution.
+	pub fn mobile(width: f32, height: f32) -> Self {
+		Self {
+			resolution: 2.0,
+			hover: HoverCapability::None,
+			any_hover: HoverCapability::None,
+			pointer: PointerAccuracy::Coarse,
+			any_pointer: PointerAccuracy::Coarse,
+			..Self::screen(width, height)
+		}
+	}
+
+	/// A printed A4 page.
+	pub fn print() -> Self {
+		Self {
+			media_type: MediaEnvironmentType::Print,
+			hover: HoverCapability::None,
+			any_hover: HoverCapability::None,
+			pointer: PointerAccuracy::None,
+			any_pointer: PointerAccuracy::None,
+			..Self::screen(794.0, 1123.0)
+		}
+	}
+
+	/// Resolves a [Length] to `px`, where relative lengths resolve against this environment as they would in a media
+	/// query. Returns `None` for lengths this environment can't resolve (such as `ex` or `ch`, which depend on font
+	/// metrics).
+	pub fn resolve_length(&self, length: Length) -> Option<f32> {
+		if let Some(px) = length.to_px() {
+			return Some(px);
+		}
+		let size = match length {
+			Length::Em(_) | Length::Rem(_) => self.font_size,
+			Length::Vw(_) | Length::Svw(_) | Length::Lvw(_) | Length::Dvw(_) => self.width / 100.0,
+			Length::Vi(_) | Length::Svi(_) | Length::Lvi(_) | Length::Dvi(_) => self.width / 100.0,
+			Length::Vh(_) | Length::Svh(_) | Length::Lvh(_) | Length::Dvh(_) => self.height / 100.0,
+			Length::Vb(_) | Length::Svb(_) | Length::Lvb(_) | Length::Dvb(_) => self.height / 100.0,
+			Length::Vmin(_) | Length::Svmin(_) | Length::Lvmin(_) | Length::Dvmin(_) => {
+				self.width.min(self.height) / 100.0
+			}
+			Length::Vmax(_) | Length::Svmax(_) | Length::Lvmax(_) | Length::Dvmax(_) => {
+				self.width.max(self.height) / 100.0
+			}
+			// Container units fall back to the small viewport units when there is no container.
+			Length::Cqw(_) | Length::Cqi(_) => self.width / 100.0,
+			Length::Cqh(_) | Length::Cqb(_) => self.height / 100.0,
+			Length::Cqmin(_) => self.width.min(self.height) / 100.0,
+			Length::Cqmax(_) => self.width.max(self.height) / 100.0,
+			_ => return None,
+		};
+		Some(f32::from(length) * size)
+	}
+}
+
+impl<'a> MediaQueryList<'a> {
+	/// Returns `true` if any of the queries in this list match the environment. An empty list always matches.
+	pub fn matches(&self, env: &MediaEnvironment) -> bool {
+		self.evaluate(env) == Some(true)
+	}
+
+	/// Evaluates this list against the environment. `None` means the result is unknown, because a query relies on a
+	/// feature the environment doesn't describe, or on `<general-enclosed>` syntax. When pruning rules, only a result
+	/// of `Some(false)` means the rule can never apply.
+	pub fn evaluate(&self, env: &MediaEnvironment) -> Option<bool> {
+		if self.0.is_empty() {
+			return Some(true);
+		}
+		any((&self.0).into_iter().map(|(query, _)| query.evaluate(env)))
+	}
+}
+
+impl<'a> MediaQuery<'a> {
+	/// Returns `true` if this query matches the environment. See [MediaQueryList::matches].
+	pub fn matches(&self, env: &MediaEnvironment) -> bool {
+		self.evaluate(env) == Some(true)
+	}
+
+	/// Evaluates this query against the environment. See [MediaQueryList::evaluate].
+	pub fn evaluate(&self, env: &MediaEnvironment) -> Option<bool> {
+		let media_type = match self.media_type {
+			None | Some(MediaType::All(_)) => true,
+			Some(MediaType::Screen(_)) => env.media_type == MediaEnvironmentType::Screen,
+			Some(MediaType::Print(_)) => env.media_type == MediaEnvironmentType::Print,
+			Some(MediaType::Custom(_)) => false,
+		};
+		let result = match &self.condition {
+			Some(condition) => all([Some(media_type), condition.evaluate(env)].into_iter()),
+			None => Some(media_type),
+		};
+		match self.precondition {
+			Some(MediaPreCondition::Not(_)) => result.map(|b| !b),
+			_ => result,
+		}
+	}
+}
+
+impl<'a> MediaCondition<'a> {
+	/// Evaluates this condition against the environment. See [MediaQueryList::evaluate].
+	pub fn evaluate(&self, env: &MediaEnvironment) -> Option<bool> {
+		match self {
+			Self::Is(media) => evaluate_in_parens(media, env),
+			Self::Not(_, media) => evaluate_in_parens(media, env).map(|b| !b),
+			Self::And(medias) => all(medias.iter().map(|(media, _)| evaluate_in_parens(media, env))),
+			Self::Or(medias) => any(medias.iter().map(|(media, _)| evaluate_in_parens(media, env))),
+		}
+	}
+}
+
+fn evaluate_in_parens(media: &MediaInParens, env: &MediaEnvironment) -> Option<bool> {
+	match media {
+		ConditionInParens::Feature(feature) => feature.evaluate(env),
+		ConditionInParens::Condition(_, condition, _) => condition.evaluate(env),
+		ConditionInParens::GeneralEnclosed(_) => None,
+	}
+}
+
+// Kleene logic: `false` wins over unknown, which wins over `true`.
+pub(crate) fn all(results: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+	let mut result = Some(true);
+	for r in results {
+		match r {
+			Some(false) => return Some(false),
+			None => result = None,
+			Some(true) => {}
+		}
+	}
+	result
+}
+
+// Kleene logic: `true` wins over unknown, which wins over `false`.
+pub(crate) fn any(results: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+	let mut result = Some(false);
+	for r in results {
+		match r {
+			Some(true) => return Some(true),
+			None => result = None,
+			Some(false) => {}
+		}
+	}
+	result
+}
+
+pub(crate) fn compare(left: f32, comparison: Comparison, right: f32) -> bool {
+	match comparison {
+		Comparison::LessThan(_) => left < right,
+		Comparison::GreaterThan(_) => left > right,
+		Comparison::GreaterThanEqual(_) => left >= right,
+		Comparison::LessThanEqual(_) => left <= right,
+		Comparison::Equal(_) => left == right,
+	}
+}
+
+// Evaluates a `ranged_feature!` against the environment's `$actual` value. `$resolve` maps a feature value to the same
+// unit as `$actual`, and `$min`/`$max` are the legacy `min-`/`max-` keywords.
+macro_rules! ranged {
+	($feature: ident::$name: ident, $actual: expr, $resolve: expr, $min: path, $max: path) => {{
+		let actual: f32 = $actual;
+		let resolve = $resolve;
+		match $name {
+			$feature::Left(_, _, comparison, value, _) => {
+				resolve(*value).map(|value| compare(actual, *comparison, value))
+			}
+			$feature::Right(_, value, comparison, _, _) => {
+				resolve(*value).map(|value| compare(value, *comparison, actual))
+			}
+			$feature::Range(_, left, left_comparison, _, right_comparison, right, _) => {
+				resolve(*left).zip(resolve(*right)).map(|(left, right)| {
+					compare(left, *left_comparison, actual) && compare(actual, *right_comparison, right)
+				})
+			}
+			$feature::Legacy(_, keyword, _, value, _) => resolve(*value).map(|value| match keyword {
+				$min(_) => actual >= value,
+				$max(_) => actual <= value,
+				_ => actual == value,
+			}),
+		}
+	}};
+}
+
+// Evaluates a `discrete_feature!` against the environment's `$actual` value. In a boolean context (`(hover)`) the
+// feature matches unless the environment's value is `$none`.
+macro_rules! discrete {
+	($feature: ident::$name: ident, $actual: expr, $none: expr, { $($keyword: path => $value: expr),+ $(,)? }) => {{
+		let actual = $actual;
+		match $name {
+			$feature::Bare(..) => Some(actual != $none),
+			$feature::WithValue(_, _, _, keyword, _) => Some(match keyword {
+				$($keyword(_) => actual == $value,)+
+			}),
+		}
+	}};
+}
+
+impl MediaFeature {
+	/// Evaluates this feature against the environment. Features which the [MediaEnvironment] doesn't describe evaluate
+	/// to `None`.
+	pub fn evaluate(&self, env: &MediaEnvironment) -> Option<bool> {
+		let length = |length: Length| env.resolve_length(length);
+		let ratio = |ratio: Ratio| {
+			let denominator = ratio.denominator.map_or(1.0, f32::from);
+			(denominator != 0.0).then(|| f32::from(ratio.numerator) / denominator)
+		};
+		match self {
+			Self::Width(f) => ranged!(
+				WidthMediaFeature::f,
+				env.width,
+				length,
+				WidthMediaFeatureKeyword::MinWidth,
+				WidthMediaFeatureKeyword::MaxWidth
+			),
+			Self::Height(f) => ranged!(
+				HeightMediaFeature::f,
+				env.height,
+				length,
+				HeightMediaFeatureKeyword::MinHeight,
+				HeightMediaFeatureKeyword::MaxHeight
+			),
+			Self::DeviceWidth(f) => ranged!(
+				DeviceWidthMediaFeature::f,
+				env.device_width,
+				length,
+				DeviceWidthMediaFeatureKeyword::MinDeviceWidth,
+				DeviceWidthMediaFeatureKeyword::MaxDeviceWidth
+			),
+			Self::DeviceHeight(f) => ranged!(
+				DeviceHeightMediaFeature::f,
+				env.device_height,
+				length,
+				DeviceHeightMediaFeatureKeyword::MinDeviceHeight,
+				DeviceHeightMediaFeatureKeyword::MaxDeviceHeight
+			),
+			Self::Resolution(f) => ranged!(
+				ResolutionMediaFeature::f,
+				env.resolution,
+				|resolution: crate::units::Resolution| Some(resolution.to_dppx()),
+				ResolutionMediaFeatureKeyword::MinResolution,
+				ResolutionMediaFeatureKeyword::MaxResolution
+			),
+			Self::AspectRatio(f) => ranged!(
+				AspectRatioMediaFeature::f,
+				env.width / env.height,
+				ratio,
+				AspectRatioMediaFeatureKeyword::MinAspectRatio,
+				AspectRatioMediaFeatureKeyword::MaxAspectRatio
+			),
+			Self::DeviceAspectRatio(f) => ranged!(
+				DeviceAspectRatioMediaFeature::f,
+				env.device_width / env.device_height,
+				ratio,
+				DeviceAspectRatioMediaFeatureKeyword::MinDeviceAspectRatio,
+				DeviceAspectRatioMediaFeatureKeyword::MaxDeviceAspectRatio
+			),
+			Self::Color(f) => ranged!(
+				ColorMediaFeature::f,
+				env.color as f32,
+				|n: crate::units::CSSInt| Some(f32::from(n)),
+				ColorMediaFeatureKeyword::MinColor,
+				ColorMediaFeatureKeyword::MaxColor
+			),
+			Self::Monochrome(f) => ranged!(
+				MonochromeMediaFeature::f,
+				env.monochrome as f32,
+				|n: crate::units::CSSInt| Some(f32::from(n)),
+				MonochromeMediaFeatureKeyword::MinMonochrome,
+				MonochromeMediaFeatureKeyword::MaxMonochrome
+			),
+			Self::Orientation(f) => {
+				let portrait = env.height >= env.width;
+				match f {
+					OrientationMediaFeature::Bare(..) => Some(true),
+					OrientationMediaFeature::WithValue(_, _, _, keyword, _) => Some(match keyword {
+						OrientationMediaFeatureKeyword::Portrait(_) => portrait,
+						OrientationMediaFeatureKeyword::Landscape(_) => !portrait,
+					}),
+				}
+			}
+			Self::Grid(f) => match f {
+				GridMediaFeature::Bare(..) => Some(env.grid),
+				GridMediaFeature::WithValue(_, _, _, value, _) => {
+					let c: Cursor = (*value).into();
+					(c == Kind::Number).then(|| (c.token().value() != 0.0) == env.grid)
+				}
+			},
+			Self::Hover(f) => discrete!(HoverMediaFeature::f, env.hover, HoverCapability::None, {
+				HoverMediaFeatureKeyword::None => HoverCapability::None,
+				HoverMediaFeatureKeyword::Hover => HoverCapability::Hover,
+			}),
+			Self::AnyHover(f) => discrete!(AnyHoverMediaFeature::f, env.any_hover, HoverCapability::None, {
+				AnyHoverMediaFeatureKeyword::None => HoverCapability::None,
+				AnyHoverMediaFeatureKeyword::Hover => HoverCapability::Hover,
+			}),
+			Self::Pointer(f) => discrete!(PointerMediaFeature::f, env.pointer, PointerAccuracy::None, {
+				PointerMediaFeatureKeyword::None => PointerAccuracy::None,
+				PointerMediaFeatureKeyword::Coarse => PointerAccuracy::Coarse,
+				PointerMediaFeatureKeyword::Fine => PointerAccuracy::Fine,
+			}),
+			Self::AnyPointer(f) => discrete!(AnyPointerMediaFeature::f, env.any_pointer, PointerAccuracy::None, {
+				AnyPointerMediaFeatureKeyword::None => PointerAccuracy::None,
+				AnyPointerMediaFeatureKeyword::Coarse => PointerAccuracy::Coarse,
+				AnyPointerMediaFeatureKeyword::Fine => PointerAccuracy::Fine,
+			}),
+			Self::PrefersColorScheme(f) => match f {
+				// `prefers-color-scheme` has no "none" value, so it is always true in a boolean context.
+				PrefersColorSchemeMediaFeature::Bare(..) => Some(true),
+				PrefersColorSchemeMediaFeature::WithValue(_, _, _, keyword, _) => Some(match keyword {
+					PrefersColorSchemeMediaFeatureKeyword::Light(_) => {
+						env.prefers_color_scheme == PreferredColorScheme::Light
+					}
+					PrefersColorSchemeMediaFeatureKeyword::Dark(_) => {
+						env.prefers_color_scheme == PreferredColorScheme::Dark
+					}
+				}),
+			},
+			Self::PrefersReducedMotion(f) => {
+				discrete!(PrefersReducedMotionMediaFeature::f, env.prefers_reduced_motion, false, {
+					PrefersReducedMotionMediaFeatureKeyword::NoPreference => false,
+					PrefersReducedMotionMediaFeatureKeyword::Reduce => true,
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_parse::Parser;
+
+	fn evaluate(source: &str, env: &MediaEnvironment) -> Option<bool> {
+		let bump = bumpalo::Bump::default();
+		let mut parser = Parser::new(&bump, source);
+		let result = parser.parse_entirely::<MediaQueryList>();
+		assert!(result.errors.is_empty(), "{source:?} failed to parse: {:?}", result.errors);
+		result.output.unwrap().evaluate(env)
+	}
+
+	macro_rules! assert_evaluates {
+		($env: expr, $($source: literal => $expected: expr),+ $(,)?) => {
+			let env = $env;
+			$(assert_eq!(evaluate($source, &env), $expected, "{:?}", $source);)+
+		};
+	}
+
+	#[test]
+	fn test_media_types() {
+		assert_evaluates!(MediaEnvironment::default(),
+			"all" => Some(true),
+			"screen" => Some(true),
+			"print" => Some(false),
+			"only screen" => Some(true),
+			"not print" => Some(true),
+			"not screen" => Some(false),
+			"embossed" => Some(false),
+			"print, screen" => Some(true),
+		);
+		assert_evaluates!(MediaEnvironment::print(),
+			"print" => Some(true),
+			"screen" => Some(false),
+			"not screen and (hover)" => Some(true),
+		);
+	}
+
+	#[test]
+	fn test_ranged() {
+		assert_evaluates!(MediaEnvironment::mobile(375.0, 667.0),
+			"(min-width: 375px)" => Some(true),
+			"(min-width: 376px)" => Some(false),
+			"(max-width: 767.98px)" => Some(true),
+			"(width: 375px)" => Some(true),
+			"(width > 375px)" => Some(false),
+			"(width >= 375px)" => Some(true),
+			"(375px < width)" => Some(false),
+			"(400px > width)" => Some(true),
+			"(320px <= width < 768px)" => Some(true),
+			"(768px <= width <= 1024px)" => Some(false),
+			"(min-width: 20em)" => Some(true),
+			"(max-width: 50vh)" => Some(false),
+			"(min-width: 10ch)" => None,
+			"(max-height: 600px)" => Some(false),
+			"(orientation: portrait)" => Some(true),
+			"(orientation: landscape)" => Some(false),
+			"(min-resolution: 2dppx)" => Some(true),
+			"(min-resolution: 192dpi)" => Some(true),
+			"(resolution > 2x)" => Some(false),
+			"(max-aspect-ratio: 1/1)" => Some(true),
+			"(aspect-ratio > 16/9)" => Some(false),
+			"(min-color: 8)" => Some(true),
+			"(monochrome)" => None,
+		);
+	}
+
+	#[test]
+	fn test_discrete() {
+		let dark = MediaEnvironment {
+			prefers_color_scheme: PreferredColorScheme::Dark,
+			..MediaEnvironment::mobile(375.0, 667.0)
+		};
+		assert_evaluates!(dark,
+			"(prefers-color-scheme: dark)" => Some(true),
+			"(prefers-color-scheme: light)" => Some(false),
+			"(prefers-color-scheme)" => Some(true),
+			"(hover)" => Some(false),
+			"(hover: none)" => Some(true),
+			"(pointer: coarse)" => Some(true),
+			"(any-pointer: fine)" => Some(false),
+			"(pointer)" => Some(true),
+			"(grid)" => Some(false),
+			"(grid: 0)" => Some(true),
+			"(prefers-reduced-motion: reduce)" => Some(false),
+			"(prefers-reduced-motion: no-preference)" => Some(true),
+			"(display-mode: standalone)" => None,
+		);
+	}
+
+	#[test]
+	fn test_conditions() {
+		assert_evaluates!(MediaEnvironment::default(),
+			"screen and (min-width: 768px) and (hover)" => Some(true),
+			"screen and (max-width: 767px), print" => Some(false),
+			"(max-width: 767px) or (hover)" => Some(true),
+			"not (hover)" => Some(false),
+			"not ((max-width: 767px) or (pointer: coarse))" => Some(true),
+			"(min-width: 40em) and ((hover) or (pointer: coarse))" => Some(true),
+			"not all and (monochrome)" => None,
+			"(hover) and future-syntax(1 2 3)" => None,
+			"(max-width: 767px) and future-syntax(1 2 3)" => Some(false),
+			"(hover) or (unknown-feature: 2)" => Some(true),
+			"not (unknown-feature: 2)" => None,
+		);
+	}
+
+	#[test]
+	fn test_matches() {
+		let bump = bumpalo::Bump::default();
+		let mut parser = Parser::new(&bump, "(hover) and (unknown)");
+		let list = parser.parse_entirely::<MediaQueryList>().output.unwrap();
+		assert!(!list.matches(&MediaEnvironment::default()));
+		assert!(!list.0[0].0.matches(&MediaEnvironment::default()));
+	}
+}
@@ -1,3 +1,43 @@
-use crate::Todo;
+use crate::types::Ratio;
+use css_parse::{RangedFeatureKeyword, keyword_set, ranged_feature};
 
-pub type AspectRatioMediaFeature = Todo;
+keyword_set!(pub enum AspectRatioMediaFeatureKeyword {
+	AspectRatio: "aspect-ratio",
+	MaxAspectRatio: "max-aspect-ratio",
+	MinAspectRatio: "min-aspect-ratio",
+});
+
+impl RangedFeatureKeyword for AspectRatioMediaFeatureKeyword {
+	fn is_legacy(&self) -> bool {
+		matches!(self, Self::MaxAspectRatio(_) | Self::MinAspectRatio(_))
+	}
+}
+
+ranged_feature!(pub enum AspectRatioMediaFeature<AspectRatioMediaFeatureKeyword, Ratio>);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<AspectRatioMediaFeature>(), 184);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(AspectRatioMediaFeature, "(aspect-ratio:16/9)");
+		assert_parse!(AspectRatioMediaFeature, "(min-aspect-ratio:4/3)");
+		assert_parse!(AspectRatioMediaFeature, "(max-aspect-ratio:1)");
+		assert_parse!(AspectRatioMediaFeature, "(aspect-ratio>=16/9)");
+		assert_parse!(AspectRatioMediaFeature, "(1/1<aspect-ratio<16/9)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(AspectRatioMediaFeature, "(aspect-ratio:)");
+		assert_parse_error!(AspectRatioMediaFeature, "(aspect-ratio:16px)");
+		assert_parse_error!(AspectRatioMediaFeature, "(min-aspect-ratio>1/1)");
+	}
+}
@@ -1,3 +1,43 @@
-use crate::Todo;
+use crate::types::Ratio;
+use css_parse::{RangedFeatureKeyword, keyword_set, ranged_feature};
 
-pub type DeviceAspectRatioMediaFeature = Todo;
+keyword_set!(pub enum DeviceAspectRatioMediaFeatureKeyword {
+	DeviceAspectRatio: "device-aspect-ratio",
+	MaxDeviceAspectRatio: "max-device-aspect-ratio",
+	MinDeviceAspectRatio: "min-device-aspect-ratio",
+});
+
+impl RangedFeatureKeyword for DeviceAspectRatioMediaFeatureKeyword {
+	fn is_legacy(&self) -> bool {
+		matches!(self, Self::MaxDeviceAspectRatio(_) | Self::MinDeviceAspectRatio(_))
+	}
+}
+
+ranged_feature!(pub enum DeviceAspectRatioMediaFeature<DeviceAspectRatioMediaFeatureKeyword, Ratio>);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<DeviceAspectRatioMediaFeature>(), 184);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(DeviceAspectRatioMediaFeature, "(device-aspect-ratio:16/9)");
+		assert_parse!(DeviceAspectRatioMediaFeature, "(min-device-aspect-ratio:4/3)");
+		assert_parse!(DeviceAspectRatioMediaFeature, "(max-device-aspect-ratio:1)");
+		assert_parse!(DeviceAspectRatioMediaFeature, "(device-aspect-ratio>=16/9)");
+		assert_parse!(DeviceAspectRatioMediaFeature, "(1/1<device-aspect-ratio<16/9)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(DeviceAspectRatioMediaFeature, "(device-aspect-ratio:)");
+		assert_parse_error!(DeviceAspectRatioMediaFeature, "(device-aspect-ratio:16px)");
+		assert_parse_error!(DeviceAspectRatioMediaFeature, "(min-device-aspect-ratio>1/1)");
+	}
+}
@@ -1,3 +1,43 @@
-use crate::Todo;
+use crate::units::Resolution;
+use css_parse::{RangedFeatureKeyword, keyword_set, ranged_feature};
 
-pub type ResolutionMediaFeature = Todo;
+keyword_set!(pub enum ResolutionMediaFeatureKeyword {
+	Resolution: "resolution",
+	MaxResolution: "max-resolution",
+	MinResolution: "min-resolution",
+});
+
+impl RangedFeatureKeyword for ResolutionMediaFeatureKeyword {
+	fn is_legacy(&self) -> bool {
+		matches!(self, Self::MaxResolution(_) | Self::MinResolution(_))
+	}
+}
+
+ranged_feature!(pub enum ResolutionMediaFeature<ResolutionMediaFeatureKeyword, Resolution>);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_parse::{assert_parse, assert_parse_error};
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<ResolutionMediaFeature>(), 128);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(ResolutionMediaFeature, "(resolution:2dppx)");
+		assert_parse!(ResolutionMediaFeature, "(min-resolution:192dpi)");
+		assert_parse!(ResolutionMediaFeature, "(max-resolution:2x)");
+		assert_parse!(ResolutionMediaFeature, "(resolution>=2x)");
+		assert_parse!(ResolutionMediaFeature, "(1dppx<resolution<=3dppx)");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(ResolutionMediaFeature, "(resolution:)");
+		assert_parse_error!(ResolutionMediaFeature, "(resolution:2px)");
+		assert_parse_error!(ResolutionMediaFeature, "(min-resolution>2x)");
+	}
+}
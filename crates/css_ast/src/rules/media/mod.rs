@@ -1,41 +1,35 @@
 use bumpalo::collections::Vec;
 use css_lexer::{Cursor, Kind, KindSet};
 use css_parse::{
-	AtRule, Block, Build, ConditionKeyword, FeatureConditionList, Parse, Parser, Peek, PreludeList,
-	Result as ParserResult, T, atkeyword_set, diagnostics, keyword_set,
+	AtRule, Block, Build, CommaSeparated, ConditionInParens, ConditionKeyword, FeatureConditionList, Parse, Parser,
+	Peek, Result as ParserResult, T, atkeyword_set, diagnostics, keyword_set,
 };
 use csskit_derives::{IntoCursor, Parse, Peek, ToCursors, ToSpan, Visitable};
 
 use crate::{StyleValue, stylesheet::Rule};
 
+mod evaluate;
 mod features;
-use features::*;
+pub use evaluate::*;
+pub use features::*;
 
-atkeyword_set!(struct AtMediaKeyword "media");
+atkeyword_set!(pub struct AtMediaKeyword "media");
 
 // https://drafts.csswg.org/mediaqueries-4/
 #[derive(Peek, Parse, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
-#[visit(self)]
-pub struct MediaRule<'a>(AtRule<'a, AtMediaKeyword, MediaQueryList<'a>, MediaRuleBlock<'a>>);
+#[visit]
+pub struct MediaRule<'a>(pub AtRule<'a, AtMediaKeyword, MediaQueryList<'a>, MediaRuleBlock<'a>>);
 
-#[derive(Peek, Parse, ToSpan, ToCursors, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Peek, Parse, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct MediaRuleBlock<'a>(Block<'a, StyleValue<'a>, Rule<'a>>);
+pub struct MediaRuleBlock<'a>(pub Block<'a, StyleValue<'a>, Rule<'a>>);
 
-#[derive(Peek, ToSpan, ToCursors, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+// https://drafts.csswg.org/mediaqueries-4/#typedef-media-query-list
+#[derive(Peek, Parse, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct MediaQueryList<'a>(pub Vec<'a, MediaQuery<'a>>);
-
-impl<'a> PreludeList<'a> for MediaQueryList<'a> {
-	type PreludeItem = MediaQuery<'a>;
-}
-
-impl<'a> Parse<'a> for MediaQueryList<'a> {
-	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		Ok(Self(Self::parse_prelude_list(p)?))
-	}
-}
+#[visit(skip)]
+pub struct MediaQueryList<'a>(pub CommaSeparated<'a, MediaQuery<'a>>);
 
 keyword_set!(pub enum MediaPreCondition { Not: "not", Only: "only" });
 
@@ -86,10 +80,10 @@ impl<'a> Build<'a> for MediaType {
 #[derive(ToCursors, ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 pub struct MediaQuery<'a> {
-	precondition: Option<MediaPreCondition>,
-	media_type: Option<MediaType>,
-	and: Option<T![Ident]>,
-	condition: Option<MediaCondition<'a>>,
+	pub precondition: Option<MediaPreCondition>,
+	pub media_type: Option<MediaType>,
+	pub and: Option<T![Ident]>,
+	pub condition: Option<MediaCondition<'a>>,
 }
 
 impl<'a> Peek<'a> for MediaQuery<'a> {
@@ -102,7 +96,11 @@ impl<'a> Parse<'a> for MediaQuery<'a> {
 		let mut media_type = None;
 		let mut and = None;
 		let mut condition = None;
-		if p.peek::<T!['(']>() {
+		// `not (` begins a `<media-condition>`, rather than a `not <media-type>` query.
+		let c = p.peek_n(1);
+		if p.peek::<T!['(']>()
+			|| (c == Kind::Ident && p.eq_ignore_ascii_case(c, "not") && p.peek_n(2) == Kind::LeftParen)
+		{
 			condition = Some(p.parse::<MediaCondition<'a>>()?);
 			return Ok(Self { precondition, media_type, and, condition });
 		}
@@ -133,27 +131,30 @@ impl<'a> Parse<'a> for MediaQuery<'a> {
 	}
 }
 
+/// A `<media-in-parens>`: a [MediaFeature], a nested [MediaCondition], or a `<general-enclosed>`.
+pub type MediaInParens<'a> = ConditionInParens<'a, MediaCondition<'a>, MediaFeature>;
+
 #[derive(ToCursors, ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 pub enum MediaCondition<'a> {
-	Is(MediaFeature),
-	Not(ConditionKeyword, MediaFeature),
-	And(Vec<'a, (MediaFeature, Option<ConditionKeyword>)>),
-	Or(Vec<'a, (MediaFeature, Option<ConditionKeyword>)>),
+	Is(MediaInParens<'a>),
+	Not(ConditionKeyword, MediaInParens<'a>),
+	And(Vec<'a, (MediaInParens<'a>, Option<ConditionKeyword>)>),
+	Or(Vec<'a, (MediaInParens<'a>, Option<ConditionKeyword>)>),
 }
 
 impl<'a> FeatureConditionList<'a> for MediaCondition<'a> {
 	type FeatureCondition = MediaFeature;
-	fn build_is(feature: MediaFeature) -> Self {
+	fn build_is(feature: MediaInParens<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn build_not(keyword: ConditionKeyword, feature: MediaFeature) -> Self {
+	fn build_not(keyword: ConditionKeyword, feature: MediaInParens<'a>) -> Self {
 		Self::Not(keyword, feature)
 	}
-	fn build_and(feature: Vec<'a, (MediaFeature, Option<ConditionKeyword>)>) -> Self {
+	fn build_and(feature: Vec<'a, (MediaInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::And(feature)
 	}
-	fn build_or(feature: Vec<'a, (MediaFeature, Option<ConditionKeyword>)>) -> Self {
+	fn build_or(feature: Vec<'a, (MediaInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::Or(feature)
 	}
 }
@@ -290,14 +291,14 @@ use apply_medias;
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use css_parse::assert_parse;
+	use css_parse::{assert_parse, assert_parse_error};
 
 	#[test]
 	fn size_test() {
 		assert_eq!(std::mem::size_of::<MediaRule>(), 160);
 		assert_eq!(std::mem::size_of::<MediaQueryList>(), 32);
-		assert_eq!(std::mem::size_of::<MediaQuery>(), 200);
-		assert_eq!(std::mem::size_of::<MediaCondition>(), 152);
+		assert_eq!(std::mem::size_of::<MediaQuery>(), 256);
+		assert_eq!(std::mem::size_of::<MediaCondition>(), 208);
 	}
 
 	#[test]
@@ -335,7 +336,7 @@ mod tests {
 				precondition: None,
 				media_type: Some(MediaType::Screen(_)),
 				and: Some(_),
-				condition: Some(MediaCondition::Is(MediaFeature::Grid(_))),
+				condition: Some(MediaCondition::Is(MediaInParens::Feature(MediaFeature::Grid(_)))),
 			}
 		);
 		assert_parse!(
@@ -355,15 +356,31 @@ mod tests {
 				precondition: None,
 				media_type: Some(MediaType::Screen(_)),
 				and: Some(_),
-				condition: Some(MediaCondition::Is(MediaFeature::Orientation(_))),
+				condition: Some(MediaCondition::Is(MediaInParens::Feature(MediaFeature::Orientation(_)))),
 			}
 		);
 		assert_parse!(MediaQuery, "(hover)and (pointer)");
 		assert_parse!(MediaQuery, "(hover)or (pointer)");
-		// assert_parse!(MediaQuery, "not ((width: 2px) or (width: 3px))");
-		// assert_parse!(MediaQuery, "not ((hover) or (pointer))");
+		assert_parse!(MediaQuery, "(hover)and (pointer)and (color)");
+		assert_parse!(MediaQuery, "not (hover)", MediaQuery { condition: Some(MediaCondition::Not(_, _)), .. });
+		assert_parse!(MediaQuery, "not ((width:2px)or (width:3px))");
+		assert_parse!(MediaQuery, "not ((hover)or (pointer))");
+		assert_parse!(
+			MediaQuery,
+			"(min-width:40em)and ((hover)or (pointer:fine))",
+			MediaQuery { condition: Some(MediaCondition::And(_)), .. }
+		);
+		assert_parse!(MediaQuery, "screen and (not (hover))");
+		assert_parse!(MediaQuery, "(((hover)))");
+		assert_parse!(MediaQuery, "(hover)or (unknown-feature:2)");
+		assert_parse!(MediaQuery, "(hover)and future-syntax(1 2 3)");
+		assert_parse!(MediaCondition, "(unknown)", MediaCondition::Is(MediaInParens::GeneralEnclosed(_)));
+		assert_parse!(MediaCondition, "((hover)and (color))", MediaCondition::Is(MediaInParens::Condition(..)));
 		assert_parse!(MediaRule, "@media print{}");
-		// assert_parse!(MediaRule, "@media print,(prefers-reduced-motion: reduce){}");
+		assert_parse!(MediaRule, "@media print,(prefers-reduced-motion:reduce){}");
+		assert_parse!(MediaQueryList, "screen and (min-width:768px),print");
+		assert_parse!(MediaRule, "@media not all and (monochrome){}");
+		assert_parse!(MediaRule, "@media(min-width:40em)and ((hover)or (pointer:fine)){a{color:red}}");
 		assert_parse!(MediaRule, "@media(min-width:1200px){}");
 		assert_parse!(MediaRule, "@media(min-width:1200px){body{color:red;}}");
 		assert_parse!(MediaRule, "@media(min-width:1200px){@page{}}");
@@ -382,12 +399,16 @@ mod tests {
 		// assert_parse!(MediaRule, "@media (min-width: 0\\0) {\n\n}");
 	}
 
-	// #[test]
-	// fn test_errors() {
-	// 	assert_parse_error!(MediaQuery, "(hover) and or (pointer)");
-	// 	assert_parse_error!(MediaQuery, "(pointer) or and (pointer)");
-	// 	assert_parse_error!(MediaQuery, "(pointer) not and (pointer)");
-	// 	assert_parse_error!(MediaQuery, "only and (pointer)");
-	// 	assert_parse_error!(MediaQuery, "not and (pointer)");
-	// }
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(MediaQuery, "(hover) and or (pointer)");
+		assert_parse_error!(MediaQuery, "(pointer) or and (pointer)");
+		assert_parse_error!(MediaQuery, "(pointer) not and (pointer)");
+		assert_parse_error!(MediaQuery, "(hover) and (pointer) or (color)");
+		assert_parse_error!(MediaQuery, "only and (pointer)");
+		// Queries must be separated by commas
+		assert_parse_error!(MediaQueryList, "screen print");
+		assert_parse_error!(MediaQueryList, "screen,");
+		assert_parse_error!(MediaQuery, "not and (pointer)");
+	}
 }
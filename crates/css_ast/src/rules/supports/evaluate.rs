@@ -0,0 +1,236 @@
+use bumpalo::Bump;
+use css_lexer::{Span, ToSpan};
+use css_parse::{ConditionInParens, Declaration, DeclarationValue, Parser, text};
+
+use super::{SupportsCondition, SupportsFeature, SupportsInParens, SupportsRule};
+use crate::{
+	BrowserTarget, Combinator, CompoundSelector, FunctionalPseudoClass, FunctionalPseudoElement, SelectorComponent,
+	SelectorList, StyleValue, Support, function_support, property_support,
+	rules::media::{all, any},
+	selector_support, value_functions, value_support,
+};
+
+impl<'a> SupportsRule<'a> {
+	/// Evaluates the rule's condition against the browser targets. See [SupportsCondition::evaluate].
+	pub fn evaluate(&self, source: &str, targets: &[BrowserTarget]) -> Option<bool> {
+		self.0.prelude.evaluate(source, targets)
+	}
+}
+
+impl<'a> SupportsCondition<'a> {
+	/// Evaluates this condition against every browser target, using the bundled compat table (see [property_support]
+	/// and [selector_support]). `Some(true)` means the condition is true in every target, and `Some(false)` that it is
+	/// false in every target. `None` means the result differs between targets, or is unknown for at least one of them
+	/// (for example because a feature isn't in the table), or that there are no targets.
+	///
+	/// ```
+	/// use css_ast::*;
+	/// use css_parse::Parser;
+	/// let source = "(scroll-behavior: smooth) and (not selector(:has(a)))";
+	/// let bump = bumpalo::Bump::default();
+	/// let condition = Parser::new(&bump, source).parse_entirely::<SupportsCondition>().output.unwrap();
+	/// let targets = ["chrome 90".parse().unwrap(), "firefox 100".parse().unwrap()];
+	/// assert_eq!(condition.evaluate(source, &targets), Some(true));
+	/// assert_eq!(condition.evaluate(source, &["chrome 110".parse().unwrap()]), Some(false));
+	/// ```
+	pub fn evaluate(&self, source: &str, targets: &[BrowserTarget]) -> Option<bool> {
+		let mut results = targets.iter().map(|target| self.evaluate_target(source, target));
+		let first = results.next()??;
+		results.all(|result| result == Some(first)).then_some(first)
+	}
+
+	/// Evaluates this condition against a single browser target. `None` means the result is unknown, because the
+	/// condition uses a feature which isn't in the bundled compat table, a value which can't be checked against its
+	/// property's grammar, `font-tech()`, `font-format()`, or `<general-enclosed>` syntax.
+	pub fn evaluate_target(&self, source: &str, target: &BrowserTarget) -> Option<bool> {
+		match self {
+			Self::Is(feature) => evaluate_in_parens(feature, source, target),
+			Self::Not(_, feature) => evaluate_in_parens(feature, source, target).map(|b| !b),
+			Self::And(features) => all(features.iter().map(|(feature, _)| evaluate_in_parens(feature, source, target))),
+			Self::Or(features) => any(features.iter().map(|(feature, _)| evaluate_in_parens(feature, source, target))),
+		}
+	}
+}
+
+fn evaluate_in_parens(feature: &SupportsInParens, source: &str, target: &BrowserTarget) -> Option<bool> {
+	match feature {
+		ConditionInParens::Feature(feature) => feature.evaluate_target(source, target),
+		ConditionInParens::Condition(_, condition, _) => condition.evaluate_target(source, target),
+		ConditionInParens::GeneralEnclosed(_) => None,
+	}
+}
+
+impl<'a> SupportsFeature<'a> {
+	/// Evaluates this feature against a single browser target. See [SupportsCondition::evaluate_target].
+	///
+	/// A declaration is supported if its property, any value which is newer than the property (such as
+	/// `display: grid`), and every function in its value (such as `oklch()`) are. Where the property is supported, the
+	/// result is unknown if the value doesn't parse for it, as that may be an invalid value or one whose grammar isn't
+	/// implemented. A selector is supported if every pseudo-class, pseudo-element and combinator in it is.
+	pub fn evaluate_target(&self, source: &str, target: &BrowserTarget) -> Option<bool> {
+		let supports = |support: Support| support.supports(target);
+		match self {
+			Self::FontTech(..) | Self::FontFormat(..) => None,
+			Self::Property(_, declaration, _) => {
+				let name = text(source, declaration.name.to_span()).to_ascii_lowercase();
+				let value = text(source, declaration.value.to_span()).to_ascii_lowercase();
+				let value = value.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+				let property = property_support(&name)?;
+				if !supports(property) {
+					return Some(false);
+				}
+				let value_support = value_support(&name, &value);
+				// A value which isn't valid for its property is unsupported, but one whose grammar isn't implemented
+				// can't be told apart from it, so the result is only known for values which parse (or are listed in the
+				// compat table). The closing parenthesis doesn't end a declaration, so the value is parsed on its own.
+				let bump = Bump::default();
+				let declaration = format!("{name}:{value}");
+				let parsed = Parser::new(&bump, &declaration).parse_entirely::<Declaration<StyleValue>>();
+				let valid = parsed.errors.is_empty() && parsed.output.is_some_and(|d| !d.value.is_unknown());
+				if !valid && value_support.is_none() {
+					return None;
+				}
+				let mut features: Vec<Support> = value_support.into_iter().collect();
+				// Custom properties accept any value, so the functions in it needn't be supported.
+				if !name.starts_with("--") {
+					for function in value_functions(&value) {
+						features.push(function_support(function)?);
+					}
+				}
+				Some(features.into_iter().all(supports))
+			}
+			Self::Selector(_, _, selector, _, _) => {
+				let mut features = vec![];
+				selector_features(source, selector, &mut features);
+				all(features.into_iter().map(|feature| selector_support(&feature).map(supports)))
+			}
+		}
+	}
+}
+
+// Collects the lower case names of the pseudo-classes, pseudo-elements and non-descendant combinators in a selector,
+// including those nested within functional pseudos, as keys for [selector_support].
+fn selector_features(source: &str, selector: &SelectorList, features: &mut Vec<String>) {
+	for (compound, _) in &selector.0 {
+		compound_features(source, compound, features);
+	}
+}
+
+fn compound_features(source: &str, compound: &CompoundSelector, features: &mut Vec<String>) {
+	let name = |span: Span| {
+		let text = text(source, span);
+		text[..text.find('(').unwrap_or(text.len())].to_ascii_lowercase()
+	};
+	for component in &compound.0 {
+		match component {
+			SelectorComponent::Combinator(Combinator::Nesting(_)) => features.push("&".into()),
+			SelectorComponent::Combinator(Combinator::Column(_)) => features.push("||".into()),
+			SelectorComponent::PseudoClass(pseudo) => features.push(name(pseudo.to_span())),
+			SelectorComponent::PseudoElement(pseudo) => features.push(name(pseudo.to_span())),
+			SelectorComponent::FunctionalPseudoClass(pseudo) => {
+				features.push(name(pseudo.to_span()));
+				match pseudo {
+					FunctionalPseudoClass::Has(pseudo) => selector_features(source, &pseudo.value, features),
+					FunctionalPseudoClass::Host(pseudo) => selector_features(source, &pseudo.value, features),
+					FunctionalPseudoClass::HostContext(pseudo) => selector_features(source, &pseudo.value, features),
+					FunctionalPseudoClass::Is(pseudo) => selector_features(source, &pseudo.value, features),
+					FunctionalPseudoClass::Not(pseudo) => selector_features(source, &pseudo.value, features),
+					FunctionalPseudoClass::Where(pseudo) => selector_features(source, &pseudo.value, features),
+					_ => {}
+				}
+			}
+			SelectorComponent::FunctionalPseudoElement(pseudo) => {
+				features.push(name(pseudo.to_span()));
+				if let FunctionalPseudoElement::Slotted(pseudo) = pseudo {
+					compound_features(source, &pseudo.value, features);
+				}
+			}
+			_ => {}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn evaluate(source: &str, targets: &[&str]) -> Option<bool> {
+		let bump = bumpalo::Bump::default();
+		let mut parser = Parser::new(&bump, source);
+		let result = parser.parse_entirely::<SupportsCondition>();
+		assert!(result.errors.is_empty(), "{source:?} failed to parse: {:?}", result.errors);
+		let targets: Vec<BrowserTarget> = targets.iter().map(|target| target.parse().unwrap()).collect();
+		result.output.unwrap().evaluate(source, &targets)
+	}
+
+	macro_rules! assert_evaluates {
+		($targets: expr, $($source: literal => $expected: expr),+ $(,)?) => {
+			$(assert_eq!(evaluate($source, &$targets), $expected, "{:?}", $source);)+
+		};
+	}
+
+	#[test]
+	fn test_properties() {
+		assert_evaluates!(["chrome 100", "firefox 115", "safari 15.4"],
+			"(aspect-ratio: 1)" => Some(true),
+			"(gap: 1rem)" => Some(true),
+			"(position: sticky)" => Some(true),
+			"(grid-template-columns: subgrid)" => None,
+			"(container-name: card)" => None,
+			"(color: oklch(50% 0.1 30))" => None,
+			"(--brand: oklch(50% 0.1 30))" => Some(true),
+			"(width: calc(100% - var(--gap)))" => Some(true),
+			"(color: red)" => Some(true),
+			// `display` isn't implemented, so it has no compat data.
+			"(display: grid)" => None,
+			"(display:block)" => None,
+			"(display: nonsense)" => None,
+			"not (display: nonsense)" => None,
+			"(width: 10furlongs)" => None,
+		);
+		assert_evaluates!(["chrome 90", "safari 14"],
+			"(container-name: card)" => Some(false),
+			"(text-wrap: balance)" => Some(false),
+			"(color: oklch(50% 0.1 30))" => Some(false),
+		);
+	}
+
+	#[test]
+	fn test_unknown() {
+		assert_evaluates!(["chrome 120"],
+			"(-webkit-box-reflect: below)" => None,
+			"(foo bar)" => None,
+			"font-tech(color-COLRv1)" => None,
+		);
+		assert_evaluates!([], "(display: grid)" => None);
+	}
+
+	#[test]
+	fn test_selectors() {
+		assert_evaluates!(["chrome 110", "safari 16"],
+			"selector(:has(> img))" => Some(true),
+			"selector(a:hover > b)" => Some(true),
+			"selector(:is(a, :focus-visible))" => Some(true),
+			"selector(::backdrop)" => Some(true),
+			"selector(:nth-col(2))" => Some(false),
+		);
+		assert_evaluates!(["chrome 104", "safari 16"], "selector(:has(a))" => None);
+		assert_evaluates!(["chrome 120"], "selector(::-webkit-scrollbar)" => None);
+	}
+
+	#[test]
+	fn test_conditions() {
+		assert_evaluates!(["chrome 90", "firefox 100"],
+			"not (aspect-ratio: 1)" => Some(false),
+			"(aspect-ratio: 1) and (not selector(:has(a)))" => Some(true),
+			"(position: -webkit-sticky) or (position: sticky)" => Some(true),
+			"(aspect-ratio: 1) and (foo bar)" => None,
+			"(text-wrap: balance) and (foo bar)" => Some(false),
+		);
+		// Each target is evaluated on its own, so a condition which is true for different reasons in each is still true.
+		assert_evaluates!(["chrome 90", "safari 6.1"],
+			"(position: -webkit-sticky) or (position: sticky)" => Some(true),
+			"(position: sticky)" => None,
+		);
+	}
+}
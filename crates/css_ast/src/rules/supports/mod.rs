@@ -4,12 +4,14 @@ use crate::{
 use bumpalo::collections::Vec;
 use css_lexer::Cursor;
 use css_parse::{
-	AtRule, Build, ComponentValues, ConditionKeyword, Declaration, FeatureConditionList, Parse, Parser,
-	Result as ParserResult, RuleList, T, atkeyword_set, diagnostics, function_set,
+	AtRule, Build, ComponentValues, ConditionInParens, ConditionKeyword, Declaration, FeatureConditionList, Parse,
+	Parser, Result as ParserResult, RuleList, T, atkeyword_set, diagnostics, function_set,
 };
 use csskit_derives::{Parse, Peek, ToCursors, ToSpan, Visitable};
 
-atkeyword_set!(struct AtSupportsKeyword "supports");
+mod evaluate;
+
+atkeyword_set!(pub struct AtSupportsKeyword "supports");
 
 ///
 /// ```md
@@ -45,42 +47,42 @@ atkeyword_set!(struct AtSupportsKeyword "supports");
 #[derive(Parse, Peek, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
-pub struct SupportsRule<'a>(AtRule<'a, AtSupportsKeyword, SupportsCondition<'a>, SupportsRuleBlock<'a>>);
+pub struct SupportsRule<'a>(pub AtRule<'a, AtSupportsKeyword, SupportsCondition<'a>, SupportsRuleBlock<'a>>);
 
 #[derive(Parse, Peek, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
-pub struct SupportsRuleBlock<'a>(RuleList<'a, Rule<'a>>);
+pub struct SupportsRuleBlock<'a>(pub RuleList<'a, Rule<'a>>);
+
+/// A `<supports-in-parens>`: a [SupportsFeature], a nested [SupportsCondition], or a `<general-enclosed>`.
+pub type SupportsInParens<'a> = ConditionInParens<'a, SupportsCondition<'a>, SupportsFeature<'a>>;
 
 #[derive(ToSpan, ToCursors, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
 pub enum SupportsCondition<'a> {
-	Is(SupportsFeature<'a>),
-	Not(ConditionKeyword, SupportsFeature<'a>),
-	And(Vec<'a, (SupportsFeature<'a>, Option<ConditionKeyword>)>),
-	Or(Vec<'a, (SupportsFeature<'a>, Option<ConditionKeyword>)>),
+	Is(SupportsInParens<'a>),
+	Not(ConditionKeyword, SupportsInParens<'a>),
+	And(Vec<'a, (SupportsInParens<'a>, Option<ConditionKeyword>)>),
+	Or(Vec<'a, (SupportsInParens<'a>, Option<ConditionKeyword>)>),
 }
 
 impl<'a> FeatureConditionList<'a> for SupportsCondition<'a> {
 	type FeatureCondition = SupportsFeature<'a>;
-	fn build_is(feature: SupportsFeature<'a>) -> Self {
+	fn build_is(feature: SupportsInParens<'a>) -> Self {
 		Self::Is(feature)
 	}
-	fn build_not(keyword: ConditionKeyword, feature: SupportsFeature<'a>) -> Self {
+	fn build_not(keyword: ConditionKeyword, feature: SupportsInParens<'a>) -> Self {
 		Self::Not(keyword, feature)
 	}
-	fn build_and(feature: Vec<'a, (SupportsFeature<'a>, Option<ConditionKeyword>)>) -> Self {
+	fn build_and(feature: Vec<'a, (SupportsInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::And(feature)
 	}
-	fn build_or(feature: Vec<'a, (SupportsFeature<'a>, Option<ConditionKeyword>)>) -> Self {
+	fn build_or(feature: Vec<'a, (SupportsInParens<'a>, Option<ConditionKeyword>)>) -> Self {
 		Self::Or(feature)
 	}
 }
 
 impl<'a> Parse<'a> for SupportsCondition<'a> {
 	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
-		if p.peek::<T![Function]>() || p.peek::<T!['(']>() {
-			return Ok(Self::Is(p.parse::<SupportsFeature>()?));
-		}
 		Self::parse_condition(p)
 	}
 }
@@ -156,11 +158,9 @@ impl<'a> Parse<'a> for SupportsFeature<'a> {
 					let open_close = if open.is_some() { Some(p.parse::<T![')']>()?) } else { None };
 					Ok(Self::Selector(open, function, selector, close, open_close))
 				}
-				SupportsFeatureKeyword::FontTech(_) => {
-					todo!();
-				}
-				SupportsFeatureKeyword::FontFormat(_) => {
-					todo!();
+				// These are not yet supported, erroring allows them to be parsed as `<general-enclosed>`.
+				SupportsFeatureKeyword::FontTech(_) | SupportsFeatureKeyword::FontFormat(_) => {
+					Err(diagnostics::Unexpected(c.into(), c.into()))?
 				}
 			}
 		} else if let Some(open) = open {
@@ -212,9 +212,15 @@ mod tests {
 	fn test_writes() {
 		assert_parse!(SupportsRule, "@supports(color:black){}");
 		assert_parse!(SupportsRule, "@supports(width:1px){body{width:1px}}");
-		// assert_parse!(SupportsRule, "@supports not (width:1--foo){}");
-		// assert_parse!(SupportsRule, "@supports(width: 1--foo) or (width: 1foo) {\n\n}");
-		// assert_parse!(SupportsRule, "@supports(width: 1--foo) and (width: 1foo) {\n\n}");
+		assert_parse!(SupportsRule, "@supports not (width:1px){}");
+		assert_parse!(SupportsRule, "@supports(width:1px)or (width:2px){}");
+		assert_parse!(SupportsRule, "@supports(width:1px)and (width:2px){}");
+		assert_parse!(SupportsRule, "@supports not ((text-align-last:justify)or (-moz-text-align-last:justify)){}");
+		assert_parse!(SupportsRule, "@supports((position:-webkit-sticky)or (position:sticky)){}");
+		assert_parse!(SupportsRule, "@supports(display:grid)and (not (display:inline-grid)){}");
+		assert_parse!(SupportsRule, "@supports font-tech(color-COLRv1){}");
+		assert_parse!(SupportsCondition, "selector(h2>p)", SupportsCondition::Is(SupportsInParens::Feature(_)));
+		assert_parse!(SupportsCondition, "(foo bar)", SupportsCondition::Is(SupportsInParens::GeneralEnclosed(_)));
 		// assert_parse!(SupportsRule, "@supports(width: 100vw) {\n\tbody {\n\t\twidth: 100vw;\n\t}\n}");
 		// assert_parse!(SupportsRule, "@supports not ((text-align-last: justify) or (-moz-text-align-last: justify)) {\n\n}");
 		// assert_parse!(SupportsRule, "@supports((position:-webkit-sticky)or (position:sticky)) {}");
@@ -4,6 +4,7 @@ use css_parse::{Build, Parse, Parser, Result as ParserResult, T, function_set, k
 use csskit_derives::{Parse, Peek, ToCursors, ToSpan, Visitable};
 
 use super::{ForgivingSelector, Nth, RelativeSelector, SelectorList};
+use crate::specificity::{Specificity, ToSpecificity};
 
 macro_rules! apply_functional_pseudo_class {
 	($macro: ident) => {
@@ -78,6 +79,21 @@ impl<'a> Parse<'a> for FunctionalPseudoClass<'a> {
 	}
 }
 
+// https://drafts.csswg.org/selectors/#specificity-rules
+impl<'a> ToSpecificity for FunctionalPseudoClass<'a> {
+	fn specificity(&self) -> Specificity {
+		match self {
+			Self::Is(IsPseudoFunction { value, .. })
+			| Self::Not(NotPseudoFunction { value, .. })
+			| Self::Has(HasPseudoFunction { value, .. }) => value.specificity(),
+			Self::Host(HostPseudoFunction { value, .. })
+			| Self::HostContext(HostContextPseudoFunction { value, .. }) => Specificity(0, 1, 0) + value.specificity(),
+			Self::Where(_) => Specificity(0, 0, 0),
+			_ => Specificity(0, 1, 0),
+		}
+	}
+}
+
 #[derive(ToSpan, ToCursors, Visitable, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit(self)]
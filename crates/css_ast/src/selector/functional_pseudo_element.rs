@@ -3,6 +3,7 @@ use css_parse::{Parse, Parser, Result as ParserResult, T, function_set};
 use csskit_derives::{ToCursors, ToSpan, Visitable};
 
 use super::CompoundSelector;
+use crate::specificity::{Specificity, ToSpecificity};
 
 #[derive(ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", rename_all = "kebab-case"))]
@@ -16,6 +17,15 @@ pub enum FunctionalPseudoElement<'a> {
 	Slotted(SlottedPseudoElement<'a>),
 }
 
+impl<'a> ToSpecificity for FunctionalPseudoElement<'a> {
+	fn specificity(&self) -> Specificity {
+		match self {
+			Self::Slotted(SlottedPseudoElement { value, .. }) => Specificity(0, 0, 1) + value.specificity(),
+			Self::Highlight(_) | Self::Part(_) => Specificity(0, 0, 1),
+		}
+	}
+}
+
 function_set!(
 	enum FunctionKeywords {
 		Highlight: "highlight",
@@ -6,6 +6,8 @@ use css_parse::{
 };
 use csskit_derives::{IntoCursor, Parse, Peek, ToCursors, ToSpan, Visitable};
 
+use crate::specificity::{Specificity, ToSpecificity};
+
 mod attribute;
 mod class;
 mod combinator;
@@ -49,11 +51,24 @@ pub use webkit::*;
 #[visit]
 pub struct SelectorList<'a>(pub CommaSeparated<'a, CompoundSelector<'a>>);
 
+// The specificity of a list is that of its most specific selector, as is used by `:is()`, `:not()` & `:has()`.
+impl<'a> ToSpecificity for SelectorList<'a> {
+	fn specificity(&self) -> Specificity {
+		(&self.0).into_iter().map(|(selector, _)| selector.specificity()).max().unwrap_or(Specificity(0, 0, 0))
+	}
+}
+
 #[derive(Peek, ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde())]
 #[visit]
 pub struct CompoundSelector<'a>(pub Vec<'a, SelectorComponent<'a>>);
 
+impl<'a> ToSpecificity for CompoundSelector<'a> {
+	fn specificity(&self) -> Specificity {
+		self.0.iter().map(ToSpecificity::specificity).sum()
+	}
+}
+
 impl<'a> CompoundSelectorTrait<'a> for CompoundSelector<'a> {
 	type SelectorComponent = SelectorComponent<'a>;
 }
@@ -121,6 +136,25 @@ impl<'a> Parse<'a> for SelectorComponent<'a> {
 	}
 }
 
+// https://drafts.csswg.org/selectors/#specificity-rules
+// The nesting selector (`&`) takes the specificity of its parent rule, which isn't known here, so it counts as zero.
+impl<'a> ToSpecificity for SelectorComponent<'a> {
+	fn specificity(&self) -> Specificity {
+		match self {
+			Self::Id(_) => Specificity(1, 0, 0),
+			Self::Class(_) | Self::Attribute(_) | Self::PseudoClass(_) => Specificity(0, 1, 0),
+			Self::Tag(_) | Self::PseudoElement(_) | Self::LegacyPseudoElement(_) => Specificity(0, 0, 1),
+			Self::Namespace(namespace) => match namespace.tag {
+				NamespaceTag::Tag(_) => Specificity(0, 0, 1),
+				NamespaceTag::Wildcard(_) => Specificity(0, 0, 0),
+			},
+			Self::FunctionalPseudoClass(pseudo) => pseudo.specificity(),
+			Self::FunctionalPseudoElement(pseudo) => pseudo.specificity(),
+			Self::Wildcard(_) | Self::Combinator(_) => Specificity(0, 0, 0),
+		}
+	}
+}
+
 impl<'a> SelectorComponentTrait<'a> for SelectorComponent<'a> {
 	type Wildcard = Wildcard;
 	type Id = Id;
@@ -242,4 +276,20 @@ mod tests {
 		);
 		assert_parse!(SelectorList, "button:-moz-focusring");
 	}
+
+	#[test]
+	fn test_specificity() {
+		let specificity = |source: &str| {
+			let bump = bumpalo::Bump::default();
+			let selectors = Parser::new(&bump, source).parse_entirely::<SelectorList>().output.unwrap();
+			(&selectors.0).into_iter().map(|(selector, _)| selector.specificity()).collect::<std::vec::Vec<_>>()
+		};
+		assert_eq!(specificity("*"), vec![Specificity(0, 0, 0)]);
+		assert_eq!(specificity("#a.b.c > d::before"), vec![Specificity(1, 2, 2)]);
+		assert_eq!(specificity("a, .b, #c"), vec![Specificity(0, 0, 1), Specificity(0, 1, 0), Specificity(1, 0, 0)]);
+		assert_eq!(specificity("[href]:hover:nth-child(2n+1)"), vec![Specificity(0, 3, 0)]);
+		assert_eq!(specificity(":is(a, #b) :where(#c) :not(.d)"), vec![Specificity(1, 1, 0)]);
+		assert_eq!(specificity("svg|rect *|*"), vec![Specificity(0, 0, 1)]);
+		assert_eq!(specificity("::slotted(.a)"), vec![Specificity(0, 1, 1)]);
+	}
 }
@@ -3,7 +3,7 @@ use css_parse::{
 	Build, Parse, Parser, Result as ParserResult, T, diagnostics, function_set, pseudo_class, pseudo_element,
 	syntax::CommaSeparated,
 };
-use csskit_derives::{ToCursors, Visitable};
+use csskit_derives::{ToCursors, ToSpan, Visitable};
 
 use super::functional_pseudo_class::DirValue;
 
@@ -89,7 +89,7 @@ pseudo_element!(
 	}
 );
 
-#[derive(ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
 #[visit(self)]
 pub enum MozFunctionalPseudoElement<'a> {
@@ -174,7 +174,7 @@ pseudo_class!(
 	}
 );
 
-#[derive(ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
 #[visit]
 pub enum MozFunctionalPseudoClass {
@@ -196,7 +196,7 @@ impl<'a> Parse<'a> for MozFunctionalPseudoClass {
 	}
 }
 
-#[derive(ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(ToSpan, ToCursors, Visitable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "kebab-case"))]
 #[visit(self)]
 pub struct MozLocaleDirFunctionalPseudoClass {
@@ -1,3 +1,4 @@
+use core::fmt;
 use core::iter::Sum;
 use core::ops;
 
@@ -10,16 +11,14 @@ pub struct Specificity(pub u8, pub u8, pub u8);
 
 impl ops::AddAssign for Specificity {
 	fn add_assign(&mut self, other: Self) {
-		self.0 |= other.0;
-		self.1 |= other.1;
-		self.2 |= other.2;
+		*self = *self + other;
 	}
 }
 
 impl ops::Add for Specificity {
 	type Output = Self;
 	fn add(self, other: Self) -> Self {
-		Self(self.0 | other.0, self.1 | other.1, self.2 | other.2)
+		Self(self.0.saturating_add(other.0), self.1.saturating_add(other.1), self.2.saturating_add(other.2))
 	}
 }
 
@@ -32,3 +31,9 @@ impl Sum for Specificity {
 		out
 	}
 }
+
+impl fmt::Display for Specificity {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "({}, {}, {})", self.0, self.1, self.2)
+	}
+}
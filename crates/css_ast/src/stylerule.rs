@@ -1,7 +1,8 @@
 use crate::{StyleValue, selector::SelectorList};
 use css_lexer::Cursor;
 use css_parse::{
-	Parse, Parser, QualifiedRule, Result as ParserResult, RuleVariants, atkeyword_set, syntax::BadDeclaration,
+	Build, Parse, Parser, Peek, QualifiedRule, Result as ParserResult, RuleVariants, atkeyword_set, diagnostics,
+	syntax::BadDeclaration,
 };
 use csskit_derives::{Parse, Peek, ToCursors, ToSpan, Visitable};
 use csskit_proc_macro::visit;
@@ -68,8 +69,11 @@ macro_rules! define_atkeyword_set {
 apply_rules!(define_atkeyword_set);
 
 impl<'a> RuleVariants<'a> for NestedGroupRule<'a> {
-	fn parse_at_rule(p: &mut Parser<'a>, _name: Cursor) -> ParserResult<Self> {
-		let kw = p.parse::<AtRuleKeywords>()?;
+	fn parse_at_rule(p: &mut Parser<'a>, c: Cursor) -> ParserResult<Self> {
+		if !AtRuleKeywords::peek(p, c) {
+			Err(diagnostics::Unexpected(c.into(), c.into()))?;
+		}
+		let kw = AtRuleKeywords::build(p, c);
 		macro_rules! parse_rule {
 			( $(
 				$name: ident($ty: ident$(<$a: lifetime>)?): $str: pat,
@@ -131,5 +135,6 @@ mod tests {
 		assert_parse!(StyleRule, ":root{a;b{}}");
 		// Bad Declarations should be parsable.
 		assert_parse!(StyleRule, ":root{$(var)-size: 100%;}");
+		assert_parse!(StyleRule, "a{@media screen{color:red;b{}}}");
 	}
 }
@@ -36,11 +36,11 @@ macro_rules! apply_rules {
 			Charset(CharsetRule): "charset",
 			ColorProfile(ColorProfileRule): "color-profile",
 			Container(ContainerRule<'a>): "container",
-			CounterStyle(CounterStyleRule): "counter-style",
+			CounterStyle(CounterStyleRule<'a>): "counter-style",
 			FontFace(FontFaceRule<'a>): "font-face",
 			FontFeatureValues(FontFeatureValuesRule): "font-feature-values",
 			FontPaletteValues(FontPaletteValuesRule): "font-palette-values",
-			Import(ImportRule): "import",
+			Import(ImportRule<'a>): "import",
 			Keyframes(KeyframesRule<'a>): "keyframes",
 			Layer(LayerRule<'a>): "layer",
 			Media(MediaRule<'a>): "media",
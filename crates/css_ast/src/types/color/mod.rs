@@ -1,5 +1,6 @@
 mod color_function;
 mod named;
+mod srgba;
 mod system;
 
 use css_lexer::Cursor;
@@ -8,6 +9,7 @@ use csskit_derives::{ToCursors, ToSpan};
 
 pub use color_function::*;
 pub use named::*;
+pub use srgba::*;
 pub use system::*;
 
 #[derive(ToSpan, ToCursors, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -151,6 +151,164 @@ keyword_set!(pub enum NamedColor {
 	Yellowgreen: "yellowgreen",
 });
 
+impl NamedColor {
+	/// Returns the red, green & blue channels of this named color.
+	///
+	/// https://drafts.csswg.org/css-color/#named-colors
+	pub fn to_rgb(&self) -> (u8, u8, u8) {
+		match self {
+			Self::Aliceblue(_) => (0xf0, 0xf8, 0xff),
+			Self::Antiquewhite(_) => (0xfa, 0xeb, 0xd7),
+			Self::Aqua(_) => (0x00, 0xff, 0xff),
+			Self::Aquamarine(_) => (0x7f, 0xff, 0xd4),
+			Self::Azure(_) => (0xf0, 0xff, 0xff),
+			Self::Beige(_) => (0xf5, 0xf5, 0xdc),
+			Self::Bisque(_) => (0xff, 0xe4, 0xc4),
+			Self::Black(_) => (0x00, 0x00, 0x00),
+			Self::Blanchedalmond(_) => (0xff, 0xeb, 0xcd),
+			Self::Blue(_) => (0x00, 0x00, 0xff),
+			Self::Blueviolet(_) => (0x8a, 0x2b, 0xe2),
+			Self::Brown(_) => (0xa5, 0x2a, 0x2a),
+			Self::Burlywood(_) => (0xde, 0xb8, 0x87),
+			Self::Cadetblue(_) => (0x5f, 0x9e, 0xa0),
+			Self::Chartreuse(_) => (0x7f, 0xff, 0x00),
+			Self::Chocolate(_) => (0xd2, 0x69, 0x1e),
+			Self::Coral(_) => (0xff, 0x7f, 0x50),
+			Self::Cornflowerblue(_) => (0x64, 0x95, 0xed),
+			Self::Cornsilk(_) => (0xff, 0xf8, 0xdc),
+			Self::Crimson(_) => (0xdc, 0x14, 0x3c),
+			Self::Cyan(_) => (0x00, 0xff, 0xff),
+			Self::Darkblue(_) => (0x00, 0x00, 0x8b),
+			Self::Darkcyan(_) => (0x00, 0x8b, 0x8b),
+			Self::Darkgoldenrod(_) => (0xb8, 0x86, 0x0b),
+			Self::Darkgray(_) => (0xa9, 0xa9, 0xa9),
+			Self::Darkgreen(_) => (0x00, 0x64, 0x00),
+			Self::Darkgrey(_) => (0xa9, 0xa9, 0xa9),
+			Self::Darkkhaki(_) => (0xbd, 0xb7, 0x6b),
+			Self::Darkmagenta(_) => (0x8b, 0x00, 0x8b),
+			Self::Darkolivegreen(_) => (0x55, 0x6b, 0x2f),
+			Self::Darkorange(_) => (0xff, 0x8c, 0x00),
+			Self::Darkorchid(_) => (0x99, 0x32, 0xcc),
+			Self::Darkred(_) => (0x8b, 0x00, 0x00),
+			Self::Darksalmon(_) => (0xe9, 0x96, 0x7a),
+			Self::Darkseagreen(_) => (0x8f, 0xbc, 0x8f),
+			Self::Darkslateblue(_) => (0x48, 0x3d, 0x8b),
+			Self::Darkslategray(_) => (0x2f, 0x4f, 0x4f),
+			Self::Darkslategrey(_) => (0x2f, 0x4f, 0x4f),
+			Self::Darkturquoise(_) => (0x00, 0xce, 0xd1),
+			Self::Darkviolet(_) => (0x94, 0x00, 0xd3),
+			Self::Deeppink(_) => (0xff, 0x14, 0x93),
+			Self::Deepskyblue(_) => (0x00, 0xbf, 0xff),
+			Self::Dimgray(_) => (0x69, 0x69, 0x69),
+			Self::Dimgrey(_) => (0x69, 0x69, 0x69),
+			Self::Dodgerblue(_) => (0x1e, 0x90, 0xff),
+			Self::Firebrick(_) => (0xb2, 0x22, 0x22),
+			Self::Floralwhite(_) => (0xff, 0xfa, 0xf0),
+			Self::Forestgreen(_) => (0x22, 0x8b, 0x22),
+			Self::Fuchsia(_) => (0xff, 0x00, 0xff),
+			Self::Gainsboro(_) => (0xdc, 0xdc, 0xdc),
+			Self::Ghostwhite(_) => (0xf8, 0xf8, 0xff),
+			Self::Gold(_) => (0xff, 0xd7, 0x00),
+			Self::Goldenrod(_) => (0xda, 0xa5, 0x20),
+			Self::Gray(_) => (0x80, 0x80, 0x80),
+			Self::Green(_) => (0x00, 0x80, 0x00),
+			Self::Greenyellow(_) => (0xad, 0xff, 0x2f),
+			Self::Grey(_) => (0x80, 0x80, 0x80),
+			Self::Honeydew(_) => (0xf0, 0xff, 0xf0),
+			Self::Hotpink(_) => (0xff, 0x69, 0xb4),
+			Self::Indianred(_) => (0xcd, 0x5c, 0x5c),
+			Self::Indigo(_) => (0x4b, 0x00, 0x82),
+			Self::Ivory(_) => (0xff, 0xff, 0xf0),
+			Self::Khaki(_) => (0xf0, 0xe6, 0x8c),
+			Self::Lavender(_) => (0xe6, 0xe6, 0xfa),
+			Self::Lavenderblush(_) => (0xff, 0xf0, 0xf5),
+			Self::Lawngreen(_) => (0x7c, 0xfc, 0x00),
+			Self::Lemonchiffon(_) => (0xff, 0xfa, 0xcd),
+			Self::Lightblue(_) => (0xad, 0xd8, 0xe6),
+			Self::Lightcoral(_) => (0xf0, 0x80, 0x80),
+			Self::Lightcyan(_) => (0xe0, 0xff, 0xff),
+			Self::Lightgoldenrodyellow(_) => (0xfa, 0xfa, 0xd2),
+			Self::Lightgray(_) => (0xd3, 0xd3, 0xd3),
+			Self::Lightgreen(_) => (0x90, 0xee, 0x90),
+			Self::Lightgrey(_) => (0xd3, 0xd3, 0xd3),
+			Self::Lightpink(_) => (0xff, 0xb6, 0xc1),
+			Self::Lightsalmon(_) => (0xff, 0xa0, 0x7a),
+			Self::Lightseagreen(_) => (0x20, 0xb2, 0xaa),
+			Self::Lightskyblue(_) => (0x87, 0xce, 0xfa),
+			Self::Lightslategray(_) => (0x77, 0x88, 0x99),
+			Self::Lightslategrey(_) => (0x77, 0x88, 0x99),
+			Self::Lightsteelblue(_) => (0xb0, 0xc4, 0xde),
+			Self::Lightyellow(_) => (0xff, 0xff, 0xe0),
+			Self::Lime(_) => (0x00, 0xff, 0x00),
+			Self::Limegreen(_) => (0x32, 0xcd, 0x32),
+			Self::Linen(_) => (0xfa, 0xf0, 0xe6),
+			Self::Magenta(_) => (0xff, 0x00, 0xff),
+			Self::Maroon(_) => (0x80, 0x00, 0x00),
+			Self::Mediumaquamarine(_) => (0x66, 0xcd, 0xaa),
+			Self::Mediumblue(_) => (0x00, 0x00, 0xcd),
+			Self::Mediumorchid(_) => (0xba, 0x55, 0xd3),
+			Self::Mediumpurple(_) => (0x93, 0x70, 0xdb),
+			Self::Mediumseagreen(_) => (0x3c, 0xb3, 0x71),
+			Self::Mediumslateblue(_) => (0x7b, 0x68, 0xee),
+			Self::Mediumspringgreen(_) => (0x00, 0xfa, 0x9a),
+			Self::Mediumturquoise(_) => (0x48, 0xd1, 0xcc),
+			Self::Mediumvioletred(_) => (0xc7, 0x15, 0x85),
+			Self::Midnightblue(_) => (0x19, 0x19, 0x70),
+			Self::Mintcream(_) => (0xf5, 0xff, 0xfa),
+			Self::Mistyrose(_) => (0xff, 0xe4, 0xe1),
+			Self::Moccasin(_) => (0xff, 0xe4, 0xb5),
+			Self::Navajowhite(_) => (0xff, 0xde, 0xad),
+			Self::Navy(_) => (0x00, 0x00, 0x80),
+			Self::Oldlace(_) => (0xfd, 0xf5, 0xe6),
+			Self::Olive(_) => (0x80, 0x80, 0x00),
+			Self::Olivedrab(_) => (0x6b, 0x8e, 0x23),
+			Self::Orange(_) => (0xff, 0xa5, 0x00),
+			Self::Orangered(_) => (0xff, 0x45, 0x00),
+			Self::Orchid(_) => (0xda, 0x70, 0xd6),
+			Self::Palegoldenrod(_) => (0xee, 0xe8, 0xaa),
+			Self::Palegreen(_) => (0x98, 0xfb, 0x98),
+			Self::Paleturquoise(_) => (0xaf, 0xee, 0xee),
+			Self::Palevioletred(_) => (0xdb, 0x70, 0x93),
+			Self::Papayawhip(_) => (0xff, 0xef, 0xd5),
+			Self::Peachpuff(_) => (0xff, 0xda, 0xb9),
+			Self::Peru(_) => (0xcd, 0x85, 0x3f),
+			Self::Pink(_) => (0xff, 0xc0, 0xcb),
+			Self::Plum(_) => (0xdd, 0xa0, 0xdd),
+			Self::Powderblue(_) => (0xb0, 0xe0, 0xe6),
+			Self::Purple(_) => (0x80, 0x00, 0x80),
+			Self::Rebeccapurple(_) => (0x66, 0x33, 0x99),
+			Self::Red(_) => (0xff, 0x00, 0x00),
+			Self::Rosybrown(_) => (0xbc, 0x8f, 0x8f),
+			Self::Royalblue(_) => (0x41, 0x69, 0xe1),
+			Self::Saddlebrown(_) => (0x8b, 0x45, 0x13),
+			Self::Salmon(_) => (0xfa, 0x80, 0x72),
+			Self::Sandybrown(_) => (0xf4, 0xa4, 0x60),
+			Self::Seagreen(_) => (0x2e, 0x8b, 0x57),
+			Self::Seashell(_) => (0xff, 0xf5, 0xee),
+			Self::Sienna(_) => (0xa0, 0x52, 0x2d),
+			Self::Silver(_) => (0xc0, 0xc0, 0xc0),
+			Self::Skyblue(_) => (0x87, 0xce, 0xeb),
+			Self::Slateblue(_) => (0x6a, 0x5a, 0xcd),
+			Self::Slategray(_) => (0x70, 0x80, 0x90),
+			Self::Slategrey(_) => (0x70, 0x80, 0x90),
+			Self::Snow(_) => (0xff, 0xfa, 0xfa),
+			Self::Springgreen(_) => (0x00, 0xff, 0x7f),
+			Self::Steelblue(_) => (0x46, 0x82, 0xb4),
+			Self::Tan(_) => (0xd2, 0xb4, 0x8c),
+			Self::Teal(_) => (0x00, 0x80, 0x80),
+			Self::Thistle(_) => (0xd8, 0xbf, 0xd8),
+			Self::Tomato(_) => (0xff, 0x63, 0x47),
+			Self::Turquoise(_) => (0x40, 0xe0, 0xd0),
+			Self::Violet(_) => (0xee, 0x82, 0xee),
+			Self::Wheat(_) => (0xf5, 0xde, 0xb3),
+			Self::White(_) => (0xff, 0xff, 0xff),
+			Self::Whitesmoke(_) => (0xf5, 0xf5, 0xf5),
+			Self::Yellow(_) => (0xff, 0xff, 0x00),
+			Self::Yellowgreen(_) => (0x9a, 0xcd, 0x32),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -0,0 +1,420 @@
+use super::{Channel, Color, ColorFunction, ColorSpace, Hue};
+
+type Matrix = [[f32; 3]; 3];
+
+// https://drafts.csswg.org/css-color/#color-conversion-code
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+	[0.412_390_8, 0.357_584_33, 0.180_480_8],
+	[0.212_639, 0.715_168_7, 0.072_192_32],
+	[0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+	[3.240_97, -1.537_383_2, -0.498_610_76],
+	[-0.969_243_65, 1.875_967_5, 0.041_555_06],
+	[0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+const LINEAR_P3_TO_XYZ: Matrix = [
+	[0.486_570_95, 0.265_667_7, 0.198_217_29],
+	[0.228_974_56, 0.691_738_5, 0.079_286_91],
+	[0.0, 0.045_113_38, 1.043_944_4],
+];
+const LINEAR_A98_TO_XYZ: Matrix = [
+	[0.576_669_04, 0.185_558_24, 0.188_228_65],
+	[0.297_344_97, 0.627_363_55, 0.075_291_46],
+	[0.027_031_36, 0.070_688_85, 0.991_337_5],
+];
+const LINEAR_PROPHOTO_TO_XYZ_D50: Matrix =
+	[[0.797_766_6, 0.135_181_3, 0.031_347_73], [0.288_074_83, 0.711_835_2, 0.000_089_936_94], [0.0, 0.0, 0.825_104_6]];
+const LINEAR_REC2020_TO_XYZ: Matrix = [
+	[0.636_958, 0.144_616_9, 0.168_880_98],
+	[0.262_700_2, 0.677_998_1, 0.059_301_716],
+	[0.0, 0.028_072_692, 1.060_985_1],
+];
+const D50_TO_D65: Matrix = [
+	[0.955_473_4, -0.023_098_455, 0.063_259_244],
+	[-0.028_369_71, 1.009_995_4, 0.021_041_441],
+	[0.012_314_015, -0.020_507_65, 1.330_365_9],
+];
+const D65_TO_D50: Matrix = [
+	[1.047_929_8, 0.022_946_87, -0.050_192_266],
+	[0.029_627_81, 0.990_434_4, -0.017_073_8],
+	[-0.009_243_041, 0.015_055_191, 0.751_874_3],
+];
+const D50_WHITE: [f32; 3] = [0.964_295_7, 1.0, 0.825_104_6];
+const LAB_KAPPA: f32 = 24389.0 / 27.0;
+const LAB_EPSILON: f32 = 216.0 / 24389.0;
+
+// https://bottosson.github.io/posts/oklab/
+const LINEAR_SRGB_TO_LMS: Matrix = [
+	[0.412_221_46, 0.536_332_55, 0.051_445_995],
+	[0.211_903_5, 0.680_699_5, 0.107_396_96],
+	[0.088_302_46, 0.281_718_85, 0.629_978_7],
+];
+const LMS_TO_OKLAB: Matrix = [
+	[0.210_454_26, 0.793_617_8, -0.004_072_047],
+	[1.977_998_5, -2.428_592_2, 0.450_593_7],
+	[0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+const OKLAB_TO_LMS: Matrix =
+	[[1.0, 0.396_337_78, 0.215_803_76], [1.0, -0.105_561_346, -0.063_854_17], [1.0, -0.089_484_18, -1.291_485_5]];
+const LMS_TO_LINEAR_SRGB: Matrix = [
+	[4.076_741_7, -3.307_711_6, 0.230_969_94],
+	[-1.268_438, 2.609_757_4, -0.341_319_38],
+	[-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+fn multiply(m: &Matrix, [a, b, c]: [f32; 3]) -> [f32; 3] {
+	m.map(|row| row[0] * a + row[1] * b + row[2] * c)
+}
+
+// Applies a transfer function to each channel, preserving the sign so that out of gamut values are kept.
+fn each(values: [f32; 3], f: impl Fn(f32) -> f32) -> [f32; 3] {
+	values.map(|v| f(v.abs()).copysign(v))
+}
+
+fn srgb_to_linear(values: [f32; 3]) -> [f32; 3] {
+	each(values, |v| if v <= 0.04045 { v / 12.92 } else { ((v + 0.055) / 1.055).powf(2.4) })
+}
+
+fn linear_to_srgb(values: [f32; 3]) -> [f32; 3] {
+	each(values, |v| if v <= 0.003_130_8 { v * 12.92 } else { 1.055 * v.powf(1.0 / 2.4) - 0.055 })
+}
+
+fn lab_to_xyz_d50([l, a, b]: [f32; 3]) -> [f32; 3] {
+	let f1 = (l + 16.0) / 116.0;
+	let f0 = a / 500.0 + f1;
+	let f2 = f1 - b / 200.0;
+	let x = if f0.powi(3) > LAB_EPSILON { f0.powi(3) } else { (116.0 * f0 - 16.0) / LAB_KAPPA };
+	let y = if l > LAB_KAPPA * LAB_EPSILON { f1.powi(3) } else { l / LAB_KAPPA };
+	let z = if f2.powi(3) > LAB_EPSILON { f2.powi(3) } else { (116.0 * f2 - 16.0) / LAB_KAPPA };
+	[x * D50_WHITE[0], y * D50_WHITE[1], z * D50_WHITE[2]]
+}
+
+fn xyz_d50_to_lab(xyz: [f32; 3]) -> [f32; 3] {
+	let [f0, f1, f2] = [0, 1, 2].map(|i| {
+		let v = xyz[i] / D50_WHITE[i];
+		if v > LAB_EPSILON { v.cbrt() } else { (LAB_KAPPA * v + 16.0) / 116.0 }
+	});
+	[116.0 * f1 - 16.0, 500.0 * (f0 - f1), 200.0 * (f1 - f2)]
+}
+
+fn polar_to_rectangular([l, c, h]: [f32; 3]) -> [f32; 3] {
+	let h = h.to_radians();
+	[l, c * h.cos(), c * h.sin()]
+}
+
+fn rectangular_to_polar([l, a, b]: [f32; 3]) -> [f32; 3] {
+	let h = b.atan2(a).to_degrees();
+	[l, (a * a + b * b).sqrt(), if h < 0.0 { h + 360.0 } else { h }]
+}
+
+fn oklab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
+	multiply(&LMS_TO_LINEAR_SRGB, multiply(&OKLAB_TO_LMS, lab).map(|v| v.powi(3)))
+}
+
+fn linear_srgb_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+	multiply(&LMS_TO_OKLAB, multiply(&LINEAR_SRGB_TO_LMS, rgb).map(f32::cbrt))
+}
+
+fn hsl_to_srgb(h: f32, s: f32, l: f32) -> [f32; 3] {
+	let h = h.rem_euclid(360.0);
+	let a = s * l.min(1.0 - l);
+	[0.0, 8.0, 4.0].map(|n| {
+		let k = (n + h / 30.0) % 12.0;
+		l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+	})
+}
+
+fn hwb_to_srgb(h: f32, w: f32, b: f32) -> [f32; 3] {
+	if w + b >= 1.0 {
+		let gray = w / (w + b);
+		return [gray; 3];
+	}
+	hsl_to_srgb(h, 1.0, 0.5).map(|v| v * (1.0 - w - b) + w)
+}
+
+/// A color which has been resolved into the sRGB color space, with red, green, blue & alpha channels each in the range
+/// `0.0..=1.0`.
+///
+/// Colors from wider gamuts (such as `display-p3` or `oklch()`) are clipped into sRGB, so converting to & from an
+/// [Srgba] may be lossy.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Srgba {
+	pub red: f32,
+	pub green: f32,
+	pub blue: f32,
+	pub alpha: f32,
+}
+
+impl Srgba {
+	pub fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+		Self {
+			red: red.clamp(0.0, 1.0),
+			green: green.clamp(0.0, 1.0),
+			blue: blue.clamp(0.0, 1.0),
+			alpha: alpha.clamp(0.0, 1.0),
+		}
+	}
+
+	fn from_channels([red, green, blue]: [f32; 3], alpha: f32) -> Self {
+		Self::new(red, green, blue, alpha)
+	}
+
+	fn from_linear(rgb: [f32; 3], alpha: f32) -> Self {
+		Self::from_channels(linear_to_srgb(rgb), alpha)
+	}
+
+	fn from_xyz_d65(xyz: [f32; 3], alpha: f32) -> Self {
+		Self::from_linear(multiply(&XYZ_TO_LINEAR_SRGB, xyz), alpha)
+	}
+
+	fn from_xyz_d50(xyz: [f32; 3], alpha: f32) -> Self {
+		Self::from_xyz_d65(multiply(&D50_TO_D65, xyz), alpha)
+	}
+
+	/// Parses the digits of a hex color (without the leading `#`), which may be 3, 4, 6 or 8 hex digits long.
+	pub fn from_hex(hex: &str) -> Option<Self> {
+		let digits = hex.chars().map(|c| c.to_digit(16).map(|d| d as f32)).collect::<Option<Vec<_>>>()?;
+		let channels: Vec<f32> = match digits.len() {
+			3 | 4 => digits.iter().map(|d| d * 17.0 / 255.0).collect(),
+			6 | 8 => digits.chunks(2).map(|pair| (pair[0] * 16.0 + pair[1]) / 255.0).collect(),
+			_ => return None,
+		};
+		Some(Self::new(channels[0], channels[1], channels[2], *channels.get(3).unwrap_or(&1.0)))
+	}
+
+	fn channels(&self) -> [f32; 3] {
+		[self.red, self.green, self.blue]
+	}
+
+	/// Returns the red, green & blue channels as integers in the range `0..=255`.
+	pub fn to_rgb8(&self) -> [u8; 3] {
+		self.channels().map(|v| (v * 255.0).round() as u8)
+	}
+
+	/// Returns the shortest hex notation of this color, for example `#36f` or `#33669980` (with a leading `#`).
+	pub fn to_hex(&self) -> String {
+		let [r, g, b] = self.to_rgb8();
+		let a = (self.alpha * 255.0).round() as u8;
+		let bytes = if a == 255 { vec![r, g, b] } else { vec![r, g, b, a] };
+		if bytes.iter().all(|b| b % 17 == 0) {
+			bytes.iter().fold("#".to_owned(), |str, b| format!("{str}{:x}", b / 17))
+		} else {
+			bytes.iter().fold("#".to_owned(), |str, b| format!("{str}{b:02x}"))
+		}
+	}
+
+	/// Returns the hue (in degrees), saturation & lightness (each `0.0..=1.0`) of this color.
+	pub fn to_hsl(&self) -> [f32; 3] {
+		let [r, g, b] = self.channels();
+		let max = r.max(g).max(b);
+		let min = r.min(g).min(b);
+		let l = (max + min) / 2.0;
+		let d = max - min;
+		if d == 0.0 {
+			return [0.0, 0.0, l];
+		}
+		let s = if l == 0.0 || l == 1.0 { 0.0 } else { (max - l) / l.min(1.0 - l) };
+		let h = if max == r {
+			(g - b) / d + if g < b { 6.0 } else { 0.0 }
+		} else if max == g {
+			(b - r) / d + 2.0
+		} else {
+			(r - g) / d + 4.0
+		};
+		[h * 60.0, s, l]
+	}
+
+	/// Returns the hue (in degrees), whiteness & blackness (each `0.0..=1.0`) of this color.
+	pub fn to_hwb(&self) -> [f32; 3] {
+		let [r, g, b] = self.channels();
+		let [h, _, _] = self.to_hsl();
+		[h, r.min(g).min(b), 1.0 - r.max(g).max(b)]
+	}
+
+	/// Returns the CIE lightness (`0.0..=100.0`), a & b axes of this color.
+	pub fn to_lab(&self) -> [f32; 3] {
+		let xyz = multiply(&LINEAR_SRGB_TO_XYZ, srgb_to_linear(self.channels()));
+		xyz_d50_to_lab(multiply(&D65_TO_D50, xyz))
+	}
+
+	/// Returns the CIE lightness (`0.0..=100.0`), chroma & hue (in degrees) of this color.
+	pub fn to_lch(&self) -> [f32; 3] {
+		rectangular_to_polar(self.to_lab())
+	}
+
+	/// Returns the Oklab lightness (`0.0..=1.0`), a & b axes of this color.
+	pub fn to_oklab(&self) -> [f32; 3] {
+		linear_srgb_to_oklab(srgb_to_linear(self.channels()))
+	}
+
+	/// Returns the Oklab lightness (`0.0..=1.0`), chroma & hue (in degrees) of this color.
+	pub fn to_oklch(&self) -> [f32; 3] {
+		rectangular_to_polar(self.to_oklab())
+	}
+}
+
+impl Channel {
+	/// Resolves this channel to a number, where a percentage is relative to the given `reference` (the value of
+	/// `100%`), and `none` is zero.
+	pub fn resolve(&self, reference: f32) -> f32 {
+		match self {
+			Self::None(_) => 0.0,
+			Self::Number(n) => n.value(),
+			Self::Percent(p) => p.value() / 100.0 * reference,
+		}
+	}
+}
+
+impl Hue {
+	/// Resolves this hue to a number of degrees, where `none` is zero.
+	pub fn degrees(&self) -> f32 {
+		match self {
+			Self::None(_) => 0.0,
+			Self::Number(n) => n.value(),
+			Self::Angle(a) => a.as_degrees(),
+		}
+	}
+}
+
+fn alpha(alpha: &Option<Channel>) -> f32 {
+	alpha.as_ref().map_or(1.0, |a| a.resolve(1.0))
+}
+
+impl ColorFunction {
+	/// Resolves this color function into the sRGB color space.
+	pub fn to_srgba(&self) -> Srgba {
+		match self {
+			Self::Rgb(_, r, _, g, _, b, _, _, a, _) | Self::Rgba(_, r, _, g, _, b, _, _, a, _) => {
+				Srgba::from_channels([r, g, b].map(|c| c.resolve(255.0) / 255.0), alpha(a))
+			}
+			Self::Hsl(_, h, _, s, _, l, _, _, a, _) | Self::Hsla(_, h, _, s, _, l, _, _, a, _) => Srgba::from_channels(
+				hsl_to_srgb(h.degrees(), s.resolve(100.0) / 100.0, l.resolve(100.0) / 100.0),
+				alpha(a),
+			),
+			Self::Hwb(_, h, w, b, _, a, _) => Srgba::from_channels(
+				hwb_to_srgb(h.degrees(), w.resolve(100.0) / 100.0, b.resolve(100.0) / 100.0),
+				alpha(a),
+			),
+			Self::Lab(_, l, a, b, _, alpha_channel, _) => {
+				let lab = [l.resolve(100.0), a.resolve(125.0), b.resolve(125.0)];
+				Srgba::from_xyz_d50(lab_to_xyz_d50(lab), alpha(alpha_channel))
+			}
+			Self::Lch(_, l, c, h, _, a, _) => {
+				let lab = polar_to_rectangular([l.resolve(100.0), c.resolve(150.0), h.degrees()]);
+				Srgba::from_xyz_d50(lab_to_xyz_d50(lab), alpha(a))
+			}
+			Self::Oklab(_, l, a, b, _, alpha_channel, _) => {
+				let lab = [l.resolve(1.0), a.resolve(0.4), b.resolve(0.4)];
+				Srgba::from_linear(oklab_to_linear_srgb(lab), alpha(alpha_channel))
+			}
+			Self::Oklch(_, l, c, h, _, a, _) => {
+				let lab = polar_to_rectangular([l.resolve(1.0), c.resolve(0.4), h.degrees()]);
+				Srgba::from_linear(oklab_to_linear_srgb(lab), alpha(a))
+			}
+			Self::Color(_, space, r, g, b, _, a, _) => {
+				let values = [r, g, b].map(|c| c.resolve(1.0));
+				let a = alpha(a);
+				match space {
+					ColorSpace::Srgb(_) => Srgba::from_channels(values, a),
+					ColorSpace::SrgbLinear(_) => Srgba::from_linear(values, a),
+					ColorSpace::DisplayP3(_) => {
+						Srgba::from_xyz_d65(multiply(&LINEAR_P3_TO_XYZ, srgb_to_linear(values)), a)
+					}
+					ColorSpace::A98Rgb(_) => {
+						Srgba::from_xyz_d65(multiply(&LINEAR_A98_TO_XYZ, each(values, |v| v.powf(563.0 / 256.0))), a)
+					}
+					ColorSpace::ProphotoRgb(_) => {
+						let linear = each(values, |v| if v <= 16.0 / 512.0 { v / 16.0 } else { v.powf(1.8) });
+						Srgba::from_xyz_d50(multiply(&LINEAR_PROPHOTO_TO_XYZ_D50, linear), a)
+					}
+					ColorSpace::Rec2020(_) => {
+						const ALPHA: f32 = 1.099_296_8;
+						const BETA: f32 = 0.018_053_97;
+						let linear = each(values, |v| {
+							if v < BETA * 4.5 { v / 4.5 } else { ((v + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45) }
+						});
+						Srgba::from_xyz_d65(multiply(&LINEAR_REC2020_TO_XYZ, linear), a)
+					}
+					ColorSpace::Xyz(_) | ColorSpace::XyzD65(_) => Srgba::from_xyz_d65(values, a),
+					ColorSpace::XyzD50(_) => Srgba::from_xyz_d50(values, a),
+				}
+			}
+		}
+	}
+}
+
+impl Color {
+	/// Resolves this color into the sRGB color space. The `source` text is required to read the digits of hex colors.
+	///
+	/// Colors which depend on the context they are used in, such as `currentcolor` or system colors, cannot be
+	/// resolved and so return [None].
+	pub fn to_srgba(&self, source: &str) -> Option<Srgba> {
+		match self {
+			Self::Currentcolor(_) | Self::System(_) => None,
+			Self::Transparent(_) => Some(Srgba::new(0.0, 0.0, 0.0, 0.0)),
+			Self::Hex(hash) => {
+				let c: css_lexer::Cursor = (*hash).into();
+				Srgba::from_hex(c.str_slice(source).trim_start_matches('#'))
+			}
+			Self::Named(named) => {
+				let (r, g, b) = named.to_rgb();
+				Some(Srgba::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0))
+			}
+			Self::Function(function) => Some(function.to_srgba()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_parse::Parser;
+
+	fn resolve(source: &str) -> [u8; 4] {
+		let bump = Bump::default();
+		let color = Parser::new(&bump, source).parse_entirely::<Color>().output.unwrap();
+		let srgba = color.to_srgba(source).unwrap();
+		let [r, g, b] = srgba.to_rgb8();
+		[r, g, b, (srgba.alpha * 255.0).round() as u8]
+	}
+
+	#[test]
+	fn test_resolve() {
+		assert_eq!(resolve("#3366ff"), [0x33, 0x66, 0xff, 255]);
+		assert_eq!(resolve("#36f8"), [0x33, 0x66, 0xff, 0x88]);
+		assert_eq!(resolve("rebeccapurple"), [0x66, 0x33, 0x99, 255]);
+		assert_eq!(resolve("transparent"), [0, 0, 0, 0]);
+		assert_eq!(resolve("rgb(51 102 255/50%)"), [0x33, 0x66, 0xff, 128]);
+		assert_eq!(resolve("rgba(20%,40%,100%,0.5)"), [0x33, 0x66, 0xff, 128]);
+		assert_eq!(resolve("hsl(120deg 100% 25%)"), [0, 128, 0, 255]);
+		assert_eq!(resolve("hsl(0.5turn,100%,50%)"), [0, 255, 255, 255]);
+		assert_eq!(resolve("hwb(0 20% 20%)"), [204, 51, 51, 255]);
+		assert_eq!(resolve("lab(54.29% 80.82 69.91)"), [255, 0, 0, 255]);
+		assert_eq!(resolve("lch(54.29 106.84 40.85)"), [255, 0, 0, 255]);
+		assert_eq!(resolve("oklab(0.628 0.2249 0.1258)"), [255, 0, 0, 255]);
+		assert_eq!(resolve("oklch(62.8% 0.2577 29.23)"), [255, 0, 0, 255]);
+		assert_eq!(resolve("color(srgb 1 0.5 0)"), [255, 128, 0, 255]);
+		assert_eq!(resolve("color(display-p3 1 0 0)"), [255, 0, 0, 255]);
+		assert_eq!(resolve("color(xyz-d65 0.9505 1 1.089)"), [255, 255, 255, 255]);
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let color = Srgba::from_hex("3366ff").unwrap();
+		assert_eq!(color.to_hex(), "#36f");
+		assert_eq!(Srgba::from_hex("33669980").unwrap().to_hex(), "#33669980");
+		let [h, s, l] = color.to_hsl();
+		assert_eq!([h.round(), (s * 100.0).round(), (l * 100.0).round()], [225.0, 100.0, 60.0]);
+		let [l, c, h] = color.to_oklch();
+		let bump = Bump::default();
+		let source = format!("oklch({l} {c} {h})");
+		let oklch = Parser::new(&bump, &source).parse_entirely::<Color>().output.unwrap();
+		assert_eq!(oklch.to_srgba(&source).unwrap().to_hex(), "#36f");
+		let [l, a, b] = color.to_lab();
+		let source = format!("lab({l} {a} {b})");
+		let lab = Parser::new(&bump, &source).parse_entirely::<Color>().output.unwrap();
+		assert_eq!(lab.to_srgba(&source).unwrap().to_hex(), "#36f");
+	}
+}
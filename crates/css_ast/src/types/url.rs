@@ -1,3 +1,4 @@
+use bumpalo::Bump;
 use css_lexer::Cursor;
 use css_parse::{Build, Parse, Parser, Peek, Result as ParserResult, T, function_set};
 use csskit_derives::{ToCursors, ToSpan};
@@ -42,7 +43,7 @@ impl<'a> Parse<'a> for Url {
 			UrlFunctionKeywords::Url(_) => {
 				let string = p.parse::<T![String]>()?;
 				let close = p.parse::<T![')']>()?;
-				Ok(Self::SrcFunction(function, string, close))
+				Ok(Self::UrlFunction(function, string, close))
 			}
 			UrlFunctionKeywords::Src(_) => {
 				let string = p.parse::<T![String]>()?;
@@ -53,6 +54,17 @@ impl<'a> Parse<'a> for Url {
 	}
 }
 
+impl Url {
+	/// Returns the URL this refers to, without the surrounding `url()` or quotes, and with any escapes decoded.
+	pub fn href<'a>(&self, source: &'a str, bump: &'a Bump) -> &'a str {
+		let c: Cursor = match self {
+			Self::Url(url) => (*url).into(),
+			Self::UrlFunction(_, string, _) | Self::SrcFunction(_, string, _) => (*string).into(),
+		};
+		c.parse_str(source, bump).trim()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -69,4 +81,17 @@ mod tests {
 		assert_parse!(Url, "url(\"foo\")");
 		assert_parse!(Url, "url(foo)");
 	}
+
+	#[test]
+	fn test_href() {
+		let href = |source: &str| {
+			let bump = Bump::default();
+			let url = Parser::new(&bump, source).parse_entirely::<Url>().output.unwrap();
+			url.href(source, &bump).to_owned()
+		};
+		assert_eq!(href("url(foo.png)"), "foo.png");
+		assert_eq!(href("url( 'a b.png' )"), "a b.png");
+		assert_eq!(href("src(\"../fonts/a.woff2\")"), "../fonts/a.woff2");
+		assert_eq!(href("url(im\\61ge.png)"), "image.png");
+	}
 }
@@ -2,9 +2,9 @@ use css_lexer::{Cursor, DimensionUnit};
 use css_parse::{Build, Parser, T};
 use csskit_derives::{IntoCursor, Peek, ToCursors};
 
-// const DEG_GRAD: f32 = 0.9;
-// const DEG_RAD: f32 = 57.295_78;
-// const DEG_TURN: f32 = 360.0;
+const DEG_GRAD: f32 = 0.9;
+const DEG_RAD: f32 = 57.295_78;
+const DEG_TURN: f32 = 360.0;
 
 // https://drafts.csswg.org/css-values/#angles
 #[derive(Peek, ToCursors, IntoCursor, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -16,6 +16,18 @@ pub enum Angle {
 	Deg(T![Dimension::Deg]),
 }
 
+impl Angle {
+	/// Returns the value of this angle, converted into degrees.
+	pub fn as_degrees(&self) -> f32 {
+		match self {
+			Self::Grad(f) => f.value() * DEG_GRAD,
+			Self::Rad(f) => f.value() * DEG_RAD,
+			Self::Turn(f) => f.value() * DEG_TURN,
+			Self::Deg(f) => f.value(),
+		}
+	}
+}
+
 impl From<Angle> for f32 {
 	fn from(val: Angle) -> Self {
 		match val {
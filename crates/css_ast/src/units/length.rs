@@ -4,12 +4,12 @@ use csskit_derives::{IntoCursor, ToCursors};
 
 use super::Flex;
 
-// const PX_CM: f32 = PX_IN / 2.54;
-// const PX_MM: f32 = PX_IN / 25.4;
-// const PX_Q: f32 = PX_MM / 4.0;
-// const PX_IN: f32 = 96.0;
-// const PX_PC: f32 = PX_IN / 6.0;
-// const PX_PT: f32 = PX_IN / 72.0;
+const PX_CM: f32 = PX_IN / 2.54;
+const PX_MM: f32 = PX_IN / 25.4;
+const PX_Q: f32 = PX_MM / 4.0;
+const PX_IN: f32 = 96.0;
+const PX_PC: f32 = PX_IN / 6.0;
+const PX_PT: f32 = PX_IN / 72.0;
 
 macro_rules! apply_lengths {
 	($ident: ident) => {
@@ -100,6 +100,26 @@ impl From<Length> for f32 {
 	}
 }
 
+impl Length {
+	/// The value of this length in `px`, if it is an [absolute length][1] (or zero). Font relative, viewport relative
+	/// and container relative lengths return `None`, as they depend on their context.
+	///
+	/// [1]: https://drafts.csswg.org/css-values/#absolute-lengths
+	pub fn to_px(&self) -> Option<f32> {
+		match *self {
+			Self::Zero(_) => Some(0.0),
+			Self::Px(n) => Some(n.into()),
+			Self::Cm(n) => Some(f32::from(n) * PX_CM),
+			Self::Mm(n) => Some(f32::from(n) * PX_MM),
+			Self::Q(n) => Some(f32::from(n) * PX_Q),
+			Self::In(n) => Some(f32::from(n) * PX_IN),
+			Self::Pc(n) => Some(f32::from(n) * PX_PC),
+			Self::Pt(n) => Some(f32::from(n) * PX_PT),
+			_ => None,
+		}
+	}
+}
+
 impl<'a> Peek<'a> for Length {
 	fn peek(p: &Parser<'a>, c: Cursor) -> bool {
 		macro_rules! is_checks {
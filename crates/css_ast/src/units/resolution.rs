@@ -2,8 +2,8 @@ use css_lexer::{Cursor, DimensionUnit};
 use css_parse::{Build, Parser, T};
 use csskit_derives::{IntoCursor, Peek, ToCursors};
 
-// const DPPX_IN: f32 = 96.0;
-// const DPPX_CM: f32 = DPPX_IN / 2.54;
+const DPPX_IN: f32 = 96.0;
+const DPPX_CM: f32 = DPPX_IN / 2.54;
 
 // https://drafts.csswg.org/css-values/#resolution
 #[derive(Peek, ToCursors, IntoCursor, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -15,6 +15,18 @@ pub enum Resolution {
 	X(T![Dimension::X]),
 }
 
+impl Resolution {
+	/// The value of this resolution in `dppx` (dots per `px` unit), where `1dppx` is `96dpi`.
+	pub fn to_dppx(&self) -> f32 {
+		match *self {
+			Self::Dpi(r) => f32::from(r) / DPPX_IN,
+			Self::Dpcm(r) => f32::from(r) / DPPX_CM,
+			Self::Dppx(r) => r.into(),
+			Self::X(r) => r.into(),
+		}
+	}
+}
+
 impl From<Resolution> for f32 {
 	fn from(res: Resolution) -> Self {
 		match res {
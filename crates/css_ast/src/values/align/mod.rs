@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub enum AlignContentStyleValue {}
 
 // /// Represents the style value for `justify-content` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#justify-content).
@@ -48,7 +48,7 @@ pub enum AlignContentStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 // pub enum JustifyContentStyleValue {}
 
 // /// Represents the style value for `place-content` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#place-content).
@@ -71,7 +71,7 @@ pub enum AlignContentStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:59,chrome_android:59,edge:79,firefox:45,opera:46,safari:11,safari_ios:11,samsunginternet_android:7)]
 // pub struct PlaceContentStyleValue;
 
 // /// Represents the style value for `justify-self` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#justify-self).
@@ -117,7 +117,7 @@ pub enum AlignContentStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub enum AlignSelfStyleValue {}
 
 // /// Represents the style value for `place-self` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#place-self).
@@ -140,7 +140,7 @@ pub enum AlignSelfStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:59,chrome_android:59,edge:79,firefox:45,opera:46,safari:11,safari_ios:11,samsunginternet_android:7)]
 // pub struct PlaceSelfStyleValue;
 
 // /// Represents the style value for `justify-items` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#justify-items).
@@ -188,7 +188,7 @@ pub enum AlignSelfStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 // pub enum AlignItemsStyleValue {}
 
 // /// Represents the style value for `place-items` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#place-items).
@@ -211,7 +211,7 @@ pub enum AlignSelfStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:59,chrome_android:59,edge:79,firefox:45,opera:46,safari:11,safari_ios:11,samsunginternet_android:7)]
 // pub struct PlaceItemsStyleValue;
 
 /// Represents the style value for `row-gap` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#row-gap).
@@ -234,7 +234,7 @@ pub enum AlignSelfStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:66,chrome_android:66,edge:16,firefox:61,opera:53,safari:12,safari_ios:12,samsunginternet_android:9.2)]
 pub enum RowGapStyleValue {}
 
 /// Represents the style value for `column-gap` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#column-gap).
@@ -257,7 +257,7 @@ pub enum RowGapStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:50,chrome_android:50,edge:12,firefox:52,opera:37,safari:10,safari_ios:10,samsunginternet_android:5)]
 pub enum ColumnGapStyleValue {}
 
 /// Represents the style value for `gap` as defined in [css-align-3](https://drafts.csswg.org/css-align-3/#gap).
@@ -280,5 +280,5 @@ pub enum ColumnGapStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:66,chrome_android:66,edge:16,firefox:61,opera:53,safari:12,safari_ios:12,samsunginternet_android:9.2)]
 pub struct GapStyleValue;
@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:125,chrome_android:125,edge:125,opera:111,safari:26,safari_ios:26,samsunginternet_android:27)]
 pub enum AnchorNameStyleValue<'a> {}
 
 /// Represents the style value for `anchor-scope` as defined in [css-anchor-position-1](https://drafts.csswg.org/css-anchor-position-1/#anchor-scope).
@@ -71,7 +71,7 @@ pub enum AnchorScopeStyleValue<'a> {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:125,chrome_android:125,edge:125,opera:111,safari:26,safari_ios:26,samsunginternet_android:27)]
 pub enum PositionAnchorStyleValue {}
 
 /// Represents the style value for `position-area` as defined in [css-anchor-position-1](https://drafts.csswg.org/css-anchor-position-1/#position-area).
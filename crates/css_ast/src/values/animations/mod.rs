@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationNameStyleValue<'a>;
 
 /// Represents the style value for `animation-duration` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-duration).
@@ -48,7 +48,7 @@ pub struct AnimationNameStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationDurationStyleValue<'a>;
 
 /// Represents the style value for `animation-timing-function` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-timing-function).
@@ -71,7 +71,7 @@ pub struct AnimationDurationStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationTimingFunctionStyleValue<'a>;
 
 /// Represents the style value for `animation-iteration-count` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-iteration-count).
@@ -94,7 +94,7 @@ pub struct AnimationTimingFunctionStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationIterationCountStyleValue<'a>;
 
 /// Represents the style value for `animation-direction` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-direction).
@@ -117,7 +117,7 @@ pub struct AnimationIterationCountStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationDirectionStyleValue<'a>;
 
 /// Represents the style value for `animation-play-state` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-play-state).
@@ -140,7 +140,7 @@ pub struct AnimationDirectionStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationPlayStateStyleValue<'a>;
 
 /// Represents the style value for `animation-delay` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-delay).
@@ -163,7 +163,7 @@ pub struct AnimationPlayStateStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationDelayStyleValue<'a>;
 
 /// Represents the style value for `animation-fill-mode` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-fill-mode).
@@ -186,7 +186,7 @@ pub struct AnimationDelayStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 pub struct AnimationFillModeStyleValue<'a>;
 
 // /// Represents the style value for `animation` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation).
@@ -209,7 +209,7 @@ pub struct AnimationFillModeStyleValue<'a>;
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:43,chrome_android:43,edge:12,firefox:16,opera:30,safari:9,safari_ios:9,samsunginternet_android:4)]
 // pub struct AnimationStyleValue<'a>;
 
 /// Represents the style value for `animation-composition` as defined in [css-animations-2](https://drafts.csswg.org/css-animations-2/#animation-composition).
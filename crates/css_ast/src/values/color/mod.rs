@@ -47,7 +47,7 @@ pub struct ColorStyleValue;
 #[percentages("map to the range [0,1]")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(90.213)]
+#[popularity(Unknown)]
 #[caniuse("https://caniuse.com/css-opacity")]
 #[baseline(widely)]
 #[versions(chrome:1,chrome_android:18,edge:12,firefox:1,firefox_android:4,safari:2,safari_ios:1)]
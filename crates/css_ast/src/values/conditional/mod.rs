@@ -25,7 +25,7 @@ use impls::*;
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:105,chrome_android:105,edge:105,firefox:110,opera:91,safari:16,safari_ios:16,samsunginternet_android:20)]
 // pub enum ContainerTypeStyleValue {}
 
 /// Represents the style value for `container-name` as defined in [css-conditional-5](https://drafts.csswg.org/css-conditional-5/#container-name).
@@ -48,7 +48,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:105,chrome_android:105,edge:105,firefox:110,opera:91,safari:16,safari_ios:16,samsunginternet_android:20)]
 pub enum ContainerNameStyleValue<'a> {}
 
 // /// Represents the style value for `container` as defined in [css-conditional-5](https://drafts.csswg.org/css-conditional-5/#container).
@@ -71,5 +71,5 @@ pub enum ContainerNameStyleValue<'a> {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:105,chrome_android:105,edge:105,firefox:110,opera:91,safari:16,safari_ios:16,samsunginternet_android:20)]
 // pub struct ContainerStyleValue;
@@ -47,8 +47,8 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see § 4.1 animating and interpolating content-visibility")]
-#[popularity(19.535)]
+#[popularity(Unknown)]
 #[caniuse("https://caniuse.com/css-content-visibility")]
 #[baseline(limited)]
-#[versions(chrome:108,chrome_android:108,edge:108,firefox:130,firefox_android:130)]
+#[versions(chrome:85,chrome_android:85,edge:85,firefox:125,opera:71,safari:18,safari_ios:18,samsunginternet_android:14)]
 pub enum ContentVisibilityStyleValue {}
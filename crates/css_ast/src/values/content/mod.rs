@@ -47,7 +47,7 @@ use impls::*;
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(81.529)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(widely)]
 // #[versions(chrome:87,chrome_android:87,edge:87,firefox:70,firefox_android:79,safari:14.1,safari_ios:14.5)]
@@ -70,7 +70,7 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(81.529)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -93,7 +93,7 @@ pub enum BookmarkLevelStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(81.529)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -116,7 +116,7 @@ pub struct BookmarkLabelStyleValue<'a>;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(81.529)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
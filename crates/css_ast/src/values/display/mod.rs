@@ -48,10 +48,10 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(92.253)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub struct OrderStyleValue;
 
 /// Represents the style value for `visibility` as defined in [css-display-4](https://drafts.csswg.org/css-display-4/#visibility).
@@ -72,7 +72,7 @@ pub struct OrderStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(92.253)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(widely)]
 #[versions(chrome:1,chrome_android:18,edge:12,firefox:1,firefox_android:4,safari:1.3,safari_ios:1)]
@@ -96,7 +96,7 @@ pub enum VisibilityStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("not animatable")]
-#[popularity(92.253)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(limited)]
 #[versions(chrome:137,chrome_android:137,edge:137)]
@@ -119,7 +119,7 @@ pub enum ReadingFlowStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(92.253)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub enum FlexDirectionStyleValue {}
 
 /// Represents the style value for `flex-wrap` as defined in [css-flexbox-1](https://drafts.csswg.org/css-flexbox-1/#flex-wrap).
@@ -48,7 +48,7 @@ pub enum FlexDirectionStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub enum FlexWrapStyleValue {}
 
 /// Represents the style value for `flex-flow` as defined in [css-flexbox-1](https://drafts.csswg.org/css-flexbox-1/#flex-flow).
@@ -71,7 +71,7 @@ pub enum FlexWrapStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub struct FlexFlowStyleValue;
 
 // /// Represents the style value for `flex` as defined in [css-flexbox-1](https://drafts.csswg.org/css-flexbox-1/#flex).
@@ -94,7 +94,7 @@ pub struct FlexFlowStyleValue;
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 // pub enum FlexStyleValue {}
 
 /// Represents the style value for `flex-grow` as defined in [css-flexbox-1](https://drafts.csswg.org/css-flexbox-1/#flex-grow).
@@ -117,7 +117,7 @@ pub struct FlexFlowStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub struct FlexGrowStyleValue;
 
 /// Represents the style value for `flex-shrink` as defined in [css-flexbox-1](https://drafts.csswg.org/css-flexbox-1/#flex-shrink).
@@ -140,7 +140,7 @@ pub struct FlexGrowStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub struct FlexShrinkStyleValue;
 
 /// Represents the style value for `flex-basis` as defined in [css-flexbox-1](https://drafts.csswg.org/css-flexbox-1/#flex-basis).
@@ -163,5 +163,5 @@ pub struct FlexShrinkStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:29,chrome_android:29,edge:12,firefox:28,opera:17,safari:9,safari_ios:9,samsunginternet_android:2)]
 pub enum FlexBasisStyleValue {}
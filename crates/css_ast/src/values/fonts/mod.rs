@@ -173,7 +173,7 @@ pub enum FontSizeStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 // pub enum FontStyleValue<'a> {}
 
 /// Represents the style value for `font-synthesis-weight` as defined in [css-fonts-5](https://drafts.csswg.org/css-fonts-5/#font-synthesis-weight).
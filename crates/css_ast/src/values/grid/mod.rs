@@ -22,10 +22,10 @@ use impls::*;
 // #[percentages("refer to corresponding dimension of the content area")]
 // #[canonical_order("per grammar")]
 // #[animation_type("if the list lengths match, by computed value type per item in the computed track list (see § 7.2.5 computed value of a track listing and § 7.2.3.3 interpolation/combination of repeat()); discrete otherwise")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub enum GridTemplateColumnsStyleValue {}
 
 // /// Represents the style value for `grid-template-rows` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-rows).
@@ -45,10 +45,10 @@ use impls::*;
 // #[percentages("refer to corresponding dimension of the content area")]
 // #[canonical_order("per grammar")]
 // #[animation_type("if the list lengths match, by computed value type per item in the computed track list (see § 7.2.5 computed value of a track listing and § 7.2.3.3 interpolation/combination of repeat()); discrete otherwise")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub enum GridTemplateRowsStyleValue {}
 
 /// Represents the style value for `grid-template-areas` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template-areas).
@@ -68,10 +68,10 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub enum GridTemplateAreasStyleValue<'a> {}
 
 // /// Represents the style value for `grid-template` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-template).
@@ -93,10 +93,10 @@ pub enum GridTemplateAreasStyleValue<'a> {}
 // #[percentages("see individual properties")]
 // #[canonical_order("per grammar")]
 // #[animation_type("see individual properties")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub enum GridTemplateStyleValue<'a> {}
 
 /// Represents the style value for `grid-auto-columns` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-columns).
@@ -116,10 +116,10 @@ pub enum GridTemplateAreasStyleValue<'a> {}
 #[percentages("see track sizing")]
 #[canonical_order("per grammar")]
 #[animation_type("if the list lengths match, by computed value type per item; discrete otherwise")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub struct GridAutoColumnsStyleValue<'a>;
 
 /// Represents the style value for `grid-auto-rows` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-rows).
@@ -139,10 +139,10 @@ pub struct GridAutoColumnsStyleValue<'a>;
 #[percentages("see track sizing")]
 #[canonical_order("per grammar")]
 #[animation_type("if the list lengths match, by computed value type per item; discrete otherwise")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub struct GridAutoRowsStyleValue<'a>;
 
 // /// Represents the style value for `grid-auto-flow` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-auto-flow).
@@ -162,10 +162,10 @@ pub struct GridAutoRowsStyleValue<'a>;
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub struct GridAutoFlowStyleValue;
 
 // /// Represents the style value for `grid` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid).
@@ -211,10 +211,10 @@ pub struct GridAutoRowsStyleValue<'a>;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub struct GridRowStartStyleValue;
 
 /// Represents the style value for `grid-column-start` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-column-start).
@@ -234,10 +234,10 @@ pub struct GridRowStartStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub struct GridColumnStartStyleValue;
 
 /// Represents the style value for `grid-row-end` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-row-end).
@@ -257,10 +257,10 @@ pub struct GridColumnStartStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub struct GridRowEndStyleValue;
 
 /// Represents the style value for `grid-column-end` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-column-end).
@@ -280,10 +280,10 @@ pub struct GridRowEndStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 pub struct GridColumnEndStyleValue;
 
 // /// Represents the style value for `grid-row` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-row).
@@ -303,10 +303,10 @@ pub struct GridColumnEndStyleValue;
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub struct GridRowStyleValue;
 
 // /// Represents the style value for `grid-column` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-column).
@@ -326,10 +326,10 @@ pub struct GridColumnEndStyleValue;
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub struct GridColumnStyleValue;
 
 // /// Represents the style value for `grid-area` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#grid-area).
@@ -349,10 +349,10 @@ pub struct GridColumnEndStyleValue;
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:57,chrome_android:57,edge:16,firefox:52,opera:44,safari:10.1,safari_ios:10.3,samsunginternet_android:6.2)]
 // pub struct GridAreaStyleValue;
 
 /// Represents the style value for `item-slack` as defined in [css-grid-3](https://drafts.csswg.org/css-grid-3/#item-slack).
@@ -372,7 +372,7 @@ pub struct GridColumnEndStyleValue;
 #[percentages("relative to the grid-axis content box size of the masonry container")]
 #[canonical_order("per grammar")]
 #[animation_type("as length")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -395,7 +395,7 @@ pub enum ItemSlackStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -418,7 +418,7 @@ pub enum ItemDirectionStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(0.606)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -441,7 +441,7 @@ pub enum ItemTrackStyleValue {}
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
 // #[versions(Unknown)]
@@ -464,7 +464,7 @@ pub enum ItemTrackStyleValue {}
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
 // #[versions(Unknown)]
@@ -487,7 +487,7 @@ pub enum ItemTrackStyleValue {}
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
 // #[versions(Unknown)]
@@ -510,7 +510,7 @@ pub enum ItemTrackStyleValue {}
 // #[percentages("see individual properties")]
 // #[canonical_order("per grammar")]
 // #[animation_type("see individual properties")]
-// #[popularity(0.606)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
 // #[versions(Unknown)]
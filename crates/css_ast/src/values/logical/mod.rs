@@ -163,7 +163,7 @@ pub struct MaxInlineSizeStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct MarginBlockStartStyleValue;
 
 /// Represents the style value for `margin-block-end` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#margin-block-end).
@@ -186,7 +186,7 @@ pub struct MarginBlockStartStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct MarginBlockEndStyleValue;
 
 /// Represents the style value for `margin-inline-start` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#margin-inline-start).
@@ -209,7 +209,7 @@ pub struct MarginBlockEndStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct MarginInlineStartStyleValue;
 
 /// Represents the style value for `margin-inline-end` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#margin-inline-end).
@@ -232,7 +232,7 @@ pub struct MarginInlineStartStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct MarginInlineEndStyleValue;
 
 /// Represents the style value for `margin-block` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#margin-block).
@@ -255,7 +255,7 @@ pub struct MarginInlineEndStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct MarginBlockStyleValue;
 
 /// Represents the style value for `margin-inline` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#margin-inline).
@@ -278,7 +278,7 @@ pub struct MarginBlockStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct MarginInlineStyleValue;
 
 /// Represents the style value for `padding-block-start` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#padding-block-start).
@@ -301,7 +301,7 @@ pub struct MarginInlineStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct PaddingBlockStartStyleValue;
 
 /// Represents the style value for `padding-block-end` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#padding-block-end).
@@ -324,7 +324,7 @@ pub struct PaddingBlockStartStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct PaddingBlockEndStyleValue;
 
 /// Represents the style value for `padding-inline-start` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#padding-inline-start).
@@ -347,7 +347,7 @@ pub struct PaddingBlockEndStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct PaddingInlineStartStyleValue;
 
 /// Represents the style value for `padding-inline-end` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#padding-inline-end).
@@ -370,7 +370,7 @@ pub struct PaddingInlineStartStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct PaddingInlineEndStyleValue;
 
 /// Represents the style value for `padding-block` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#padding-block).
@@ -393,7 +393,7 @@ pub struct PaddingInlineEndStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct PaddingBlockStyleValue;
 
 /// Represents the style value for `padding-inline` as defined in [css-logical-1](https://drafts.csswg.org/css-logical-1/#padding-inline).
@@ -416,5 +416,5 @@ pub struct PaddingBlockStyleValue;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct PaddingInlineStyleValue;
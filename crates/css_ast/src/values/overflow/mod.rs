@@ -22,7 +22,7 @@ use impls::*;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -45,7 +45,7 @@ pub enum OverflowXStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -68,7 +68,7 @@ pub enum OverflowYStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -91,7 +91,7 @@ pub enum OverflowBlockStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -118,7 +118,7 @@ pub enum OverflowInlineStyleValue {}
 #[popularity(87.038)]
 #[caniuse(Unknown)]
 #[baseline(newly)]
-#[versions(chrome:113,chrome_android:113,edge:113,firefox:66,firefox_android:66,safari:17,safari_ios:17)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub struct OverflowStyleValue;
 
 /// Represents the style value for `overflow-clip-margin` as defined in [css-overflow-5](https://drafts.csswg.org/css-overflow-5/#overflow-clip-margin).
@@ -139,7 +139,7 @@ pub struct OverflowStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(limited)]
 #[versions(Unknown)]
@@ -163,7 +163,7 @@ pub struct OverflowClipMarginStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("not animatable")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse("https://caniuse.com/css-scroll-behavior")]
 #[baseline(widely)]
 #[versions(chrome:61,chrome_android:61,edge:79,firefox:36,firefox_android:36,safari:15.4,safari_ios:15.4)]
@@ -187,7 +187,7 @@ pub enum ScrollBehaviorStyleValue {}
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("discrete")]
-// #[popularity(87.038)]
+// #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(newly)]
 // #[versions(chrome:94,chrome_android:94,edge:94,firefox:97,firefox_android:97,safari:18.2,safari_ios:18.2)]
@@ -211,7 +211,7 @@ pub enum ScrollBehaviorStyleValue {}
 // #[percentages("refer to the width of the line box")]
 // #[canonical_order("per grammar")]
 // #[animation_type("by computed value type")]
-// #[popularity(87.038)]
+// #[popularity(Unknown)]
 // #[caniuse("https://caniuse.com/text-overflow")]
 // #[baseline(widely)]
 // #[versions(chrome:1,chrome_android:18,edge:12,firefox:7,firefox_android:7,safari:1.3,safari_ios:1)]
@@ -234,7 +234,7 @@ pub enum ScrollBehaviorStyleValue {}
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -257,7 +257,7 @@ pub struct OverflowClipMarginTopStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -280,7 +280,7 @@ pub struct OverflowClipMarginRightStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -303,7 +303,7 @@ pub struct OverflowClipMarginBottomStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -326,7 +326,7 @@ pub struct OverflowClipMarginLeftStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -349,7 +349,7 @@ pub struct OverflowClipMarginBlockStartStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -372,7 +372,7 @@ pub struct OverflowClipMarginInlineStartStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -395,7 +395,7 @@ pub struct OverflowClipMarginBlockEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("per computed value if the <visual-box> values match; otherwise discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -418,7 +418,7 @@ pub struct OverflowClipMarginInlineEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -441,7 +441,7 @@ pub struct OverflowClipMarginInlineStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -464,7 +464,7 @@ pub struct OverflowClipMarginBlockStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -488,7 +488,7 @@ pub enum BlockEllipsisStyleValue {}
 // #[percentages("n/a")]
 // #[canonical_order("per grammar")]
 // #[animation_type("see individual properties")]
-// #[popularity(87.038)]
+// #[popularity(Unknown)]
 // #[caniuse("https://caniuse.com/css-line-clamp")]
 // #[baseline(limited)]
 // #[versions(safari:18.2,safari_ios:18.2)]
@@ -511,7 +511,7 @@ pub enum BlockEllipsisStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see individual properties")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -536,7 +536,7 @@ pub enum WebkitLineClampStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -559,7 +559,7 @@ pub enum MaxLinesStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -582,7 +582,7 @@ pub enum ContinueStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -605,7 +605,7 @@ pub enum ScrollTargetGroupStyleValue {}
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("discrete")]
-#[popularity(87.038)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
 #[versions(Unknown)]
@@ -50,7 +50,7 @@ pub enum FloatReferenceStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum FloatStyleValue {}
 
 /// Represents the style value for `clear` as defined in [css-page-floats-3](https://drafts.csswg.org/css-page-floats-3/#clear).
@@ -75,7 +75,7 @@ pub enum FloatStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum ClearStyleValue {}
 
 /// Represents the style value for `float-defer` as defined in [css-page-floats-3](https://drafts.csswg.org/css-page-floats-3/#float-defer).
@@ -46,10 +46,10 @@ pub enum PositionStyleValue {}
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub struct TopStyleValue;
 
 /// Represents the style value for `right` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#right).
@@ -69,10 +69,10 @@ pub struct TopStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub struct RightStyleValue;
 
 /// Represents the style value for `bottom` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#bottom).
@@ -92,10 +92,10 @@ pub struct RightStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub struct BottomStyleValue;
 
 /// Represents the style value for `left` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#left).
@@ -115,10 +115,10 @@ pub struct BottomStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub struct LeftStyleValue;
 
 /// Represents the style value for `inset-block-start` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset-block-start).
@@ -138,10 +138,10 @@ pub struct LeftStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetBlockStartStyleValue;
 
 /// Represents the style value for `inset-inline-start` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset-inline-start).
@@ -161,10 +161,10 @@ pub struct InsetBlockStartStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetInlineStartStyleValue;
 
 /// Represents the style value for `inset-block-end` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset-block-end).
@@ -184,10 +184,10 @@ pub struct InsetInlineStartStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetBlockEndStyleValue;
 
 /// Represents the style value for `inset-inline-end` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset-inline-end).
@@ -207,10 +207,10 @@ pub struct InsetBlockEndStyleValue;
 #[percentages("refer to size of containing block; see prose")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetInlineEndStyleValue;
 
 /// Represents the style value for `inset-block` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset-block).
@@ -230,10 +230,10 @@ pub struct InsetInlineEndStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetBlockStyleValue;
 
 /// Represents the style value for `inset-inline` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset-inline).
@@ -253,10 +253,10 @@ pub struct InsetBlockStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetInlineStyleValue;
 
 /// Represents the style value for `inset` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#inset).
@@ -276,10 +276,10 @@ pub struct InsetInlineStyleValue;
 #[percentages("see individual properties")]
 #[canonical_order("per grammar")]
 #[animation_type("by computed value type")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:87,chrome_android:87,edge:87,firefox:66,opera:73,safari:14.1,safari_ios:14.5,samsunginternet_android:14)]
 pub struct InsetStyleValue;
 
 /// Represents the style value for `overlay` as defined in [css-position-4](https://drafts.csswg.org/css-position-4/#overlay).
@@ -300,7 +300,7 @@ pub struct InsetStyleValue;
 #[percentages("n/a")]
 #[canonical_order("per grammar")]
 #[animation_type("see prose")]
-#[popularity(91.319)]
+#[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(limited)]
 #[versions(chrome:117,chrome_android:117,edge:117)]
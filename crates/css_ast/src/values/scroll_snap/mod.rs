@@ -25,7 +25,7 @@ use impls::*;
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:69,chrome_android:69,edge:79,firefox:68,opera:56,safari:11,safari_ios:11,samsunginternet_android:10.1)]
 // pub enum ScrollSnapTypeStyleValue {}
 
 /// Represents the style value for `scroll-padding` as defined in [css-scroll-snap-2](https://drafts.csswg.org/css-scroll-snap-2/#scroll-padding).
@@ -94,7 +94,7 @@ pub struct ScrollMarginStyleValue;
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:69,chrome_android:69,edge:79,firefox:68,opera:56,safari:11,safari_ios:11,samsunginternet_android:10.1)]
 // pub struct ScrollSnapAlignStyleValue;
 
 /// Represents the style value for `scroll-snap-stop` as defined in [css-scroll-snap-2](https://drafts.csswg.org/css-scroll-snap-2/#scroll-snap-stop).
@@ -27,7 +27,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum WidthStyleValue {}
 
 /// Represents the style value for `height` as defined in [css-sizing-4](https://drafts.csswg.org/css-sizing-4/#height).
@@ -52,7 +52,7 @@ pub enum WidthStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum HeightStyleValue {}
 
 /// Represents the style value for `min-width` as defined in [css-sizing-4](https://drafts.csswg.org/css-sizing-4/#min-width).
@@ -77,7 +77,7 @@ pub enum HeightStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum MinWidthStyleValue {}
 
 /// Represents the style value for `min-height` as defined in [css-sizing-4](https://drafts.csswg.org/css-sizing-4/#min-height).
@@ -102,7 +102,7 @@ pub enum MinWidthStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum MinHeightStyleValue {}
 
 /// Represents the style value for `max-width` as defined in [css-sizing-4](https://drafts.csswg.org/css-sizing-4/#max-width).
@@ -127,7 +127,7 @@ pub enum MinHeightStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum MaxWidthStyleValue {}
 
 /// Represents the style value for `max-height` as defined in [css-sizing-4](https://drafts.csswg.org/css-sizing-4/#max-height).
@@ -152,7 +152,7 @@ pub enum MaxWidthStyleValue {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:1,chrome_android:18,edge:12,firefox:1,opera:3.5,safari:1,safari_ios:1,samsunginternet_android:1)]
 pub enum MaxHeightStyleValue {}
 
 /// Represents the style value for `box-sizing` as defined in [css-sizing-4](https://drafts.csswg.org/css-sizing-4/#box-sizing).
@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:36,chrome_android:36,edge:12,firefox:16,opera:23,safari:9,safari_ios:9,samsunginternet_android:3)]
 pub enum TransformStyleValue<'a> {}
 
 // /// Represents the style value for `transform-origin` as defined in [css-transforms-2](https://drafts.csswg.org/css-transforms-2/#transform-origin).
@@ -50,7 +50,7 @@ pub enum TransformStyleValue<'a> {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:36,chrome_android:36,edge:12,firefox:16,opera:23,safari:9,safari_ios:9,samsunginternet_android:3)]
 // pub enum TransformOriginStyleValue {}
 
 /// Represents the style value for `transform-box` as defined in [css-transforms-2](https://drafts.csswg.org/css-transforms-2/#transform-box).
@@ -121,7 +121,7 @@ pub enum TransformBoxStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:104,chrome_android:104,edge:104,firefox:72,opera:90,safari:14.1,safari_ios:14.5,samsunginternet_android:20)]
 // pub enum RotateStyleValue {}
 
 // /// Represents the style value for `scale` as defined in [css-transforms-2](https://drafts.csswg.org/css-transforms-2/#scale).
@@ -144,7 +144,7 @@ pub enum TransformBoxStyleValue {}
 // #[popularity(Unknown)]
 // #[caniuse(Unknown)]
 // #[baseline(Unknown)]
-// #[versions(Unknown)]
+// #[versions(chrome:104,chrome_android:104,edge:104,firefox:72,opera:90,safari:14.1,safari_ios:14.5,samsunginternet_android:20)]
 // pub enum ScaleStyleValue {}
 
 /// Represents the style value for `transform-style` as defined in [css-transforms-2](https://drafts.csswg.org/css-transforms-2/#transform-style).
@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:26,chrome_android:26,edge:12,firefox:16,opera:12.1,safari:9,safari_ios:9,samsunginternet_android:1.5)]
 pub enum TransitionPropertyStyleValue<'a> {}
 
 /// Represents the style value for `transition-duration` as defined in [css-transitions-2](https://drafts.csswg.org/css-transitions-2/#transition-duration).
@@ -48,7 +48,7 @@ pub enum TransitionPropertyStyleValue<'a> {}
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:26,chrome_android:26,edge:12,firefox:16,opera:12.1,safari:9,safari_ios:9,samsunginternet_android:1.5)]
 pub struct TransitionDurationStyleValue<'a>;
 
 /// Represents the style value for `transition-timing-function` as defined in [css-transitions-2](https://drafts.csswg.org/css-transitions-2/#transition-timing-function).
@@ -71,7 +71,7 @@ pub struct TransitionDurationStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:26,chrome_android:26,edge:12,firefox:16,opera:12.1,safari:9,safari_ios:9,samsunginternet_android:1.5)]
 pub struct TransitionTimingFunctionStyleValue<'a>;
 
 /// Represents the style value for `transition-delay` as defined in [css-transitions-2](https://drafts.csswg.org/css-transitions-2/#transition-delay).
@@ -94,7 +94,7 @@ pub struct TransitionTimingFunctionStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:26,chrome_android:26,edge:12,firefox:16,opera:12.1,safari:9,safari_ios:9,samsunginternet_android:1.5)]
 pub struct TransitionDelayStyleValue<'a>;
 
 /// Represents the style value for `transition` as defined in [css-transitions-2](https://drafts.csswg.org/css-transitions-2/#transition).
@@ -117,7 +117,7 @@ pub struct TransitionDelayStyleValue<'a>;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:26,chrome_android:26,edge:12,firefox:16,opera:12.1,safari:9,safari_ios:9,samsunginternet_android:1.5)]
 pub struct TransitionStyleValue<'a>;
 
 /// Represents the style value for `transition-behavior` as defined in [css-transitions-2](https://drafts.csswg.org/css-transitions-2/#transition-behavior).
@@ -25,7 +25,7 @@ use impls::*;
 #[popularity(Unknown)]
 #[caniuse(Unknown)]
 #[baseline(Unknown)]
-#[versions(Unknown)]
+#[versions(chrome:111,chrome_android:111,edge:111,firefox:144,opera:97,safari:18,safari_ios:18,samsunginternet_android:22)]
 pub enum ViewTransitionNameStyleValue {}
 
 /// Represents the style value for `view-transition-class` as defined in [css-view-transitions-2](https://drafts.csswg.org/css-view-transitions-2/#view-transition-class).
@@ -1,19 +1,34 @@
 include!(concat!(env!("OUT_DIR"), "/css_node_kind.rs"));
 include!(concat!(env!("OUT_DIR"), "/css_apply_visit_methods.rs"));
 
+mod node_path;
+pub use node_path::*;
+
 use bumpalo::collections::Vec;
 use css_parse::{
-	AtRule, Block, CommaSeparated, ComponentValues, Declaration, DeclarationList, DeclarationValue, NoBlockAllowed,
-	NoPreludeAllowed, QualifiedRule, RuleList, syntax::BadDeclaration, token_macros,
+	AtRule, Block, CommaSeparated, ComponentValues, ConditionInParens, Declaration, DeclarationList, DeclarationValue,
+	NoBlockAllowed, NoPreludeAllowed, QualifiedRule, RuleList, syntax::BadDeclaration, token_macros,
 };
 
 use crate::*;
 
 macro_rules! visit_mut_trait {
 	( $(
-		$name: ident$(<$life:lifetime>)?($obj: ty),
+		$name: ident$(<$life:lifetime>)?($obj: ty): $kind: ident,
 	)+ ) => {
 		pub trait VisitMut: Sized + Default {
+			fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(
+				&mut self,
+				_rule: &mut Block<'a, D, R>,
+			) {
+			}
+			fn visit_at_rule<'a, AT, P, B>(&mut self, _rule: &mut AtRule<'a, AT, P, B>)
+			where
+				AT: Peek<'a> + Parse<'a> + Into<token_macros::AtKeyword>,
+				P: Parse<'a> + ToCursors + ToSpan,
+				B: Parse<'a> + ToCursors + ToSpan,
+			{
+			}
 			fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, _rule: &mut Declaration<'a, T>) {}
 			fn visit_bad_declaration<'a>(&mut self, _rule: &mut BadDeclaration<'a>) {}
 			$(
@@ -26,9 +41,21 @@ apply_visit_methods!(visit_mut_trait);
 
 macro_rules! visit_trait {
 	( $(
-		$name: ident$(<$life:lifetime>)?($obj: ty),
+		$name: ident$(<$life:lifetime>)?($obj: ty): $kind: ident,
 	)+ ) => {
 		pub trait Visit: Sized + Default {
+			fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(
+				&mut self,
+				_rule: &Block<'a, D, R>,
+			) {
+			}
+			fn visit_at_rule<'a, AT, P, B>(&mut self, _rule: &AtRule<'a, AT, P, B>)
+			where
+				AT: Peek<'a> + Parse<'a> + Into<token_macros::AtKeyword>,
+				P: Parse<'a> + ToCursors + ToSpan,
+				B: Parse<'a> + ToCursors + ToSpan,
+			{
+			}
 			fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, _rule: &Declaration<'a, T>) {}
 			fn visit_bad_declaration<'a>(&mut self, _rule: &BadDeclaration<'a>) {}
 			$(
@@ -140,6 +167,7 @@ where
 	B: VisitableMut + Parse<'a> + ToCursors + ToSpan,
 {
 	fn accept_mut<V: VisitMut>(&mut self, v: &mut V) {
+		v.visit_at_rule::<AT, P, B>(self);
 		self.prelude.accept_mut(v);
 		self.block.accept_mut(v);
 	}
@@ -152,6 +180,7 @@ where
 	B: Visitable + Parse<'a> + ToCursors + ToSpan,
 {
 	fn accept<V: Visit>(&self, v: &mut V) {
+		v.visit_at_rule::<AT, P, B>(self);
 		self.prelude.accept(v);
 		self.block.accept(v);
 	}
@@ -207,6 +236,7 @@ where
 	R: VisitableMut + Parse<'a> + ToCursors + ToSpan,
 {
 	fn accept_mut<V: VisitMut>(&mut self, v: &mut V) {
+		v.visit_block::<D, R>(self);
 		for declaration in &mut self.declarations {
 			declaration.accept_mut(v);
 		}
@@ -222,6 +252,7 @@ where
 	R: Visitable + Parse<'a> + ToCursors + ToSpan,
 {
 	fn accept<V: Visit>(&self, v: &mut V) {
+		v.visit_block::<D, R>(self);
 		for declaration in &self.declarations {
 			declaration.accept(v);
 		}
@@ -253,6 +284,32 @@ where
 	}
 }
 
+// A nested condition is a shared reference into the arena, so it can't be visited mutably.
+impl<'a, C, F> VisitableMut for ConditionInParens<'a, C, F>
+where
+	F: VisitableMut,
+{
+	fn accept_mut<V: VisitMut>(&mut self, v: &mut V) {
+		if let Self::Feature(feature) = self {
+			feature.accept_mut(v);
+		}
+	}
+}
+
+impl<'a, C, F> Visitable for ConditionInParens<'a, C, F>
+where
+	C: Visitable,
+	F: Visitable,
+{
+	fn accept<V: Visit>(&self, v: &mut V) {
+		match self {
+			Self::Feature(feature) => feature.accept(v),
+			Self::Condition(_, condition, _) => condition.accept(v),
+			Self::GeneralEnclosed(_) => {}
+		}
+	}
+}
+
 impl<'a> VisitableMut for BadDeclaration<'a> {
 	fn accept_mut<V: VisitMut>(&mut self, v: &mut V) {
 		v.visit_bad_declaration(self);
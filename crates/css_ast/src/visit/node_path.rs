@@ -0,0 +1,68 @@
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{Declaration, DeclarationValue, syntax::BadDeclaration};
+
+use crate::*;
+
+/// A [Visit] pass which finds the path of nodes containing a given [SourceOffset], ordered from the outermost node
+/// (typically the [StyleSheet]) to the innermost. Only nodes which have a `visit_` method are recorded, as well as
+/// [Declarations][Declaration].
+///
+/// ```
+/// use css_ast::{NodeKind, NodePath, StyleSheet, Visitable};
+/// use css_lexer::SourceOffset;
+/// use css_parse::Parser;
+/// use bumpalo::Bump;
+/// let bump = Bump::default();
+/// let result = Parser::new(&bump, "a{color:red}").parse_entirely::<StyleSheet>();
+/// let mut path = NodePath::new(SourceOffset(9));
+/// result.output.unwrap().accept(&mut path);
+/// let kinds: Vec<_> = path.nodes().iter().map(|(kind, _)| *kind).collect();
+/// assert_eq!(kinds, vec![NodeKind::StyleSheet, NodeKind::StyleRule, NodeKind::Declaration, NodeKind::StyleValue]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NodePath {
+	offset: SourceOffset,
+	nodes: Vec<(NodeKind, Span)>,
+}
+
+impl NodePath {
+	pub fn new(offset: SourceOffset) -> Self {
+		Self { offset, nodes: vec![] }
+	}
+
+	/// The [NodeKind] & [Span] of each node containing the offset, from outermost to innermost.
+	pub fn nodes(&self) -> &[(NodeKind, Span)] {
+		&self.nodes
+	}
+
+	fn push(&mut self, kind: NodeKind, span: Span) {
+		if span.start() > self.offset || self.offset > span.end() {
+			return;
+		}
+		// Where the offset sits between two adjacent nodes, the first is kept so that each node is within the last.
+		if self.nodes.last().is_none_or(|(_, last)| last.contains(span)) {
+			self.nodes.push((kind, span));
+		}
+	}
+}
+
+macro_rules! node_path_visit {
+	( $(
+		$name: ident$(<$life:lifetime>)?($obj: ty): $kind: ident,
+	)+ ) => {
+		impl Visit for NodePath {
+			fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, node: &Declaration<'a, T>) {
+				self.push(NodeKind::Declaration, node.to_span());
+			}
+			fn visit_bad_declaration<'a>(&mut self, node: &BadDeclaration<'a>) {
+				self.push(NodeKind::BadDeclaration, node.to_span());
+			}
+			$(
+				fn $name$(<$life>)?(&mut self, node: &$obj) {
+					self.push(NodeKind::$kind, node.to_span());
+				}
+			)+
+		}
+	}
+}
+apply_visit_methods!(node_path_visit);
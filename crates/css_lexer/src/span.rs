@@ -70,9 +70,17 @@ impl Span {
 
 /// Extends this [Span], ensuring that the resulting new [Span] is broader than both this and the given [Span].
 /// In other words the resulting span will always [Span::contains()] both [Spans][Span].
+///
+/// [Span::ZERO] is used to represent the span of an absent node (for example a [None] or an empty [Vec]), and so
+/// adding it to any other [Span] is a no-op.
 impl Add for Span {
 	type Output = Self;
 	fn add(self, rhs: Self) -> Self::Output {
+		if self == Self::ZERO {
+			return rhs;
+		} else if rhs == Self::ZERO {
+			return self;
+		}
 		let start = if self.start < rhs.start { self.start } else { rhs.start };
 		let end = if self.end > rhs.end { self.end } else { rhs.end };
 		Self { start, end }
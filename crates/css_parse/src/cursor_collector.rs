@@ -0,0 +1,18 @@
+use css_lexer::Cursor;
+
+use crate::CursorSink;
+
+/// A [CursorSink] which collects the [Cursors][Cursor] of a node, exactly as they are appended, into a [Vec]. This
+/// allows the tokens of a node to be inspected without needing to know its concrete type (for example the value of a
+/// [Declaration][crate::Declaration]).
+///
+/// Unlike [CursorVecSink][crate::CursorVecSink] no separators are inserted, so each [Cursor] still points into the
+/// source.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct CursorCollector(pub Vec<Cursor>);
+
+impl CursorSink for CursorCollector {
+	fn append(&mut self, c: Cursor) {
+		self.0.push(c);
+	}
+}
@@ -0,0 +1,285 @@
+use core::fmt;
+
+use css_lexer::{Cursor, Kind, Token};
+
+use crate::CursorSink;
+
+/// This is a [CursorSink] that wraps a Writer (`impl fmt::Write`) and writes the given [Cursors][Cursor] out in a
+/// human readable format: each declaration & statement is put on its own line, blocks are indented, and spaces are
+/// normalised around colons, commas, combinators, and `!important`.
+///
+/// Cursors are buffered until the end of each statement (a `;`, `{` or `}`), so that declarations can be told apart
+/// from selectors. Whitespace cursors within a statement are collapsed into a single space, so this is best used with
+/// [CursorTriviaSink][crate::CursorTriviaSink] (or [ParserReturn::with_trivia()][crate::ParserReturn::with_trivia]),
+/// which will also retain comments. Once all cursors have been appended, [CursorPrettyWriteSink::finish()] must be
+/// called to write any remaining buffered cursors.
+///
+/// Blocks are indented with a tab by default, which can be changed with [CursorPrettyWriteSink::with_indent()].
+pub struct CursorPrettyWriteSink<'a, T: fmt::Write> {
+	source_text: &'a str,
+	writer: T,
+	indent: &'a str,
+	depth: usize,
+	statement: Vec<Cursor>,
+	// A `{` was the last thing written, its newline is deferred so that empty blocks can be written as `{}`.
+	open_block: bool,
+	// A top level block was just closed, so a blank line should separate it from whatever comes next.
+	blank_line: bool,
+	// The depth of `{}` blocks within the value of the buffered declaration, such as `--x: {a: b}`.
+	value_blocks: usize,
+	err: Option<fmt::Error>,
+}
+
+impl<'a, T: fmt::Write> CursorPrettyWriteSink<'a, T> {
+	pub fn new(source_text: &'a str, writer: T) -> Self {
+		Self {
+			source_text,
+			writer,
+			indent: "\t",
+			depth: 0,
+			statement: vec![],
+			open_block: false,
+			blank_line: false,
+			value_blocks: 0,
+			err: None,
+		}
+	}
+
+	/// Sets the text written once per level of nesting at the start of each line, for example `"  "` to indent with
+	/// two spaces.
+	pub fn with_indent(mut self, indent: &'a str) -> Self {
+		self.indent = indent;
+		self
+	}
+
+	/// Writes out any remaining buffered cursors, returning the first error encountered while writing (if any).
+	pub fn finish(mut self) -> fmt::Result {
+		if self.err.is_none() && !self.statement.is_empty() {
+			let result = self.write_statement(false).and_then(|_| self.writer.write_char('\n'));
+			self.err = result.err();
+		}
+		self.err.map_or(Ok(()), Err)
+	}
+
+	fn start_line(&mut self) -> fmt::Result {
+		if self.open_block {
+			self.open_block = false;
+			self.writer.write_char('\n')?;
+		}
+		if self.blank_line && self.depth == 0 {
+			self.writer.write_char('\n')?;
+		}
+		self.blank_line = false;
+		for _ in 0..self.depth {
+			self.writer.write_str(self.indent)?;
+		}
+		Ok(())
+	}
+
+	fn write_statement(&mut self, is_prelude: bool) -> fmt::Result {
+		let cursors = std::mem::take(&mut self.statement);
+		let cursors = trim_whitespace(&cursors);
+		self.start_line()?;
+		let is_at_rule = cursors.first().is_some_and(|c| *c == Kind::AtKeyword);
+		let colon = if is_prelude || is_at_rule {
+			None
+		} else {
+			// Declarations are an Ident followed by a Colon.
+			let mut significant = cursors.iter().enumerate().filter(|(_, c)| **c != Kind::Whitespace);
+			match (significant.next(), significant.next()) {
+				(Some((_, name)), Some((i, colon))) if *name == Kind::Ident && *colon == Kind::Colon => Some(i),
+				_ => None,
+			}
+		};
+		let mut last: Option<Token> = None;
+		let mut space = false;
+		let mut parens = 0;
+		for (i, c) in cursors.iter().enumerate() {
+			let token = c.token();
+			let mut space_after = false;
+			match token.kind() {
+				Kind::Whitespace => {
+					space = true;
+					continue;
+				}
+				Kind::Comma => {
+					space = false;
+					space_after = true;
+				}
+				// The colon of a declaration, or of a feature in an at-rule prelude, such as `(min-width: 1px)`.
+				Kind::Colon if Some(i) == colon || (is_at_rule && parens > 0) => {
+					space = false;
+					space_after = true;
+				}
+				Kind::RightParen | Kind::RightSquare => {
+					space = false;
+					parens -= 1;
+				}
+				Kind::Delim if token.char() == Some('!') && colon.is_some() => space = true,
+				Kind::Delim if matches!(token.char(), Some('>' | '+' | '~')) && is_prelude && parens == 0 => {
+					space = true;
+					space_after = true;
+				}
+				_ => {}
+			}
+			if let Some(last) = last {
+				let after_open = matches!(last.kind(), Kind::Function | Kind::LeftParen | Kind::LeftSquare);
+				if (space && !after_open) || last.needs_separator_for(token) {
+					self.writer.write_char(' ')?;
+				}
+			}
+			c.write_str(self.source_text, &mut self.writer)?;
+			if matches!(token.kind(), Kind::Function | Kind::LeftParen | Kind::LeftSquare) {
+				parens += 1;
+			}
+			last = Some(token);
+			space = space_after;
+		}
+		Ok(())
+	}
+
+	// A `{` after the colon of a custom property is part of its value, rather than a block.
+	fn in_custom_property_value(&self) -> bool {
+		let mut significant = self.statement.iter().filter(|c| **c != Kind::Whitespace);
+		matches!(
+			(significant.next(), significant.next()),
+			(Some(name), Some(colon)) if *name == Kind::Ident && name.token().is_dashed_ident() && *colon == Kind::Colon
+		)
+	}
+
+	fn write(&mut self, c: Cursor) -> fmt::Result {
+		match c.token().kind() {
+			Kind::LeftCurly if self.value_blocks > 0 || self.in_custom_property_value() => {
+				self.value_blocks += 1;
+				self.statement.push(c);
+			}
+			Kind::RightCurly if self.value_blocks > 0 => {
+				self.value_blocks -= 1;
+				self.statement.push(c);
+			}
+			Kind::Semicolon if self.value_blocks > 0 => self.statement.push(c),
+			Kind::Whitespace if self.statement.is_empty() => {}
+			Kind::Comment if self.statement.is_empty() => {
+				self.start_line()?;
+				c.write_str(self.source_text, &mut self.writer)?;
+				self.writer.write_char('\n')?;
+			}
+			Kind::LeftCurly => {
+				self.write_statement(true)?;
+				self.writer.write_str(" {")?;
+				self.open_block = true;
+				self.depth += 1;
+			}
+			Kind::Semicolon => {
+				if !trim_whitespace(&self.statement).is_empty() {
+					self.write_statement(false)?;
+					self.writer.write_str(";\n")?;
+				}
+				self.statement.clear();
+			}
+			Kind::RightCurly => {
+				if !trim_whitespace(&self.statement).is_empty() {
+					self.write_statement(false)?;
+					self.writer.write_str(";\n")?;
+				}
+				self.statement.clear();
+				self.depth = self.depth.saturating_sub(1);
+				if self.open_block {
+					self.open_block = false;
+					self.writer.write_str("}\n")?;
+				} else {
+					self.start_line()?;
+					self.writer.write_str("}\n")?;
+				}
+				self.blank_line = self.depth == 0;
+			}
+			_ => self.statement.push(c),
+		}
+		Ok(())
+	}
+}
+
+fn trim_whitespace(cursors: &[Cursor]) -> &[Cursor] {
+	let start = cursors.iter().position(|c| *c != Kind::Whitespace).unwrap_or(cursors.len());
+	let end = cursors.iter().rposition(|c| *c != Kind::Whitespace).map_or(start, |i| i + 1);
+	&cursors[start..end]
+}
+
+impl<'a, T: fmt::Write> CursorSink for CursorPrettyWriteSink<'a, T> {
+	fn append(&mut self, c: Cursor) {
+		if self.err.is_some() {
+			return;
+		}
+		if let Err(err) = self.write(c) {
+			self.err = Some(err);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{ComponentValues, Parser, ToCursors};
+	use bumpalo::Bump;
+
+	fn pretty(source: &str) -> String {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<ComponentValues>().with_trivia();
+		let mut str = String::new();
+		let mut sink = CursorPrettyWriteSink::new(source, &mut str);
+		result.to_cursors(&mut sink);
+		sink.finish().unwrap();
+		str
+	}
+
+	#[test]
+	fn test_declarations() {
+		assert_eq!(
+			pretty("a{color:red;margin:0 auto!important}"),
+			"a {\n\tcolor: red;\n\tmargin: 0 auto !important;\n}\n"
+		);
+	}
+
+	#[test]
+	fn test_selectors() {
+		assert_eq!(pretty("a>b,c+d:hover ~ e{}"), "a > b, c + d:hover ~ e {}\n");
+		assert_eq!(pretty("li:nth-child(2n+1){}"), "li:nth-child(2n+1) {}\n");
+	}
+
+	#[test]
+	fn test_nesting() {
+		assert_eq!(
+			pretty("@media (min-width:1px){a{b:c}.d{e:f}}x{}"),
+			"@media (min-width: 1px) {\n\ta {\n\t\tb: c;\n\t}\n\t.d {\n\t\te: f;\n\t}\n}\n\nx {}\n"
+		);
+	}
+
+	#[test]
+	fn test_indent() {
+		let source = "@media screen{a{b:c}}";
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<ComponentValues>().with_trivia();
+		let mut str = String::new();
+		let mut sink = CursorPrettyWriteSink::new(source, &mut str).with_indent("  ");
+		result.to_cursors(&mut sink);
+		sink.finish().unwrap();
+		assert_eq!(str, "@media screen {\n  a {\n    b: c;\n  }\n}\n");
+	}
+
+	#[test]
+	fn test_whitespace_and_comments() {
+		assert_eq!(
+			pretty("/* a */\na {\n  color :  rgb( 0 0 0 / 50% ) ; /* b */\n}"),
+			"/* a */\na {\n\tcolor: rgb(0 0 0 / 50%);\n\t/* b */\n}\n"
+		);
+		assert_eq!(pretty("@import 'a.css';@import 'b.css'"), "@import 'a.css';\n@import 'b.css'\n");
+	}
+
+	#[test]
+	fn test_custom_property_blocks() {
+		assert_eq!(pretty("a{--x:{a:b};--y:1px}"), "a {\n\t--x: {a:b};\n\t--y: 1px;\n}\n");
+		assert_eq!(pretty("--x:{a:b;c:{d}};--y:1px"), "--x: {a:b;c:{d}};\n--y: 1px\n");
+		let formatted = pretty("--x:{a:b};--y:1px");
+		assert_eq!(pretty(&formatted), formatted);
+	}
+}
@@ -0,0 +1,40 @@
+use css_lexer::Cursor;
+
+use crate::CursorSink;
+
+/// This is a [CursorSink] that wraps another [CursorSink], and interleaves the given trivia [Cursors][Cursor] (such as
+/// whitespace & comments, which the [Parser][crate::Parser] skips over and so the AST nodes do not retain) into the
+/// wrapped sink, in source order. This is useful to (re)produce a stream of cursors which includes comments, for
+/// example when formatting.
+///
+/// The trivia must be in source order. Any trivia which appears after the last appended cursor will only be appended
+/// once [CursorTriviaSink::finish()] is called.
+pub struct CursorTriviaSink<'t, S: CursorSink> {
+	trivia: &'t [Cursor],
+	sink: S,
+}
+
+impl<'t, S: CursorSink> CursorTriviaSink<'t, S> {
+	pub fn new(trivia: &'t [Cursor], sink: S) -> Self {
+		Self { trivia, sink }
+	}
+
+	/// Appends all remaining trivia into the wrapped sink, returning it.
+	pub fn finish(mut self) -> S {
+		for c in self.trivia {
+			self.sink.append(*c);
+		}
+		self.sink
+	}
+}
+
+impl<S: CursorSink> CursorSink for CursorTriviaSink<'_, S> {
+	fn append(&mut self, c: Cursor) {
+		let pending = self.trivia.partition_point(|t| t.offset() < c.offset());
+		for t in &self.trivia[..pending] {
+			self.sink.append(*t);
+		}
+		self.trivia = &self.trivia[pending..];
+		self.sink.append(c);
+	}
+}
@@ -0,0 +1,57 @@
+use css_lexer::Span;
+
+/// A replacement of the text at [Span] with `text`. An empty [Span] inserts the text, while empty `text` removes it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edit {
+	pub span: Span,
+	pub text: String,
+}
+
+impl Edit {
+	pub fn new(span: Span, text: impl Into<String>) -> Self {
+		Self { span, text: text.into() }
+	}
+}
+
+/// Applies the [Edits][Edit] to the source text. Edits which overlap an earlier edit are skipped, so that the result is
+/// always well formed; running whatever produced the edits again will find anything left unchanged.
+pub fn apply_edits(source: &str, edits: &[Edit]) -> String {
+	let mut edits: Vec<&Edit> = edits.iter().collect();
+	edits.sort_by_key(|edit| (edit.span.start(), edit.span.end()));
+	let mut str = String::with_capacity(source.len());
+	let mut last = 0;
+	for edit in edits {
+		let (start, end) = (edit.span.start().0 as usize, edit.span.end().0 as usize);
+		if start < last {
+			continue;
+		}
+		str.push_str(&source[last..start]);
+		str.push_str(&edit.text);
+		last = end;
+	}
+	str.push_str(&source[last..]);
+	str
+}
+
+/// The text of the source at [Span].
+pub fn text(source: &str, span: Span) -> &str {
+	&source[span.start().0 as usize..span.end().0 as usize]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_lexer::SourceOffset;
+
+	fn span(start: u32, end: u32) -> Span {
+		Span::new(SourceOffset(start), SourceOffset(end))
+	}
+
+	#[test]
+	fn test_apply_edits() {
+		let edits = [Edit::new(span(7, 8), "b"), Edit::new(span(0, 0), "x "), Edit::new(span(8, 9), "")];
+		assert_eq!(apply_edits("a { c: d }", &edits), "x a { c: b}");
+		let overlapping = [Edit::new(span(0, 3), "b"), Edit::new(span(2, 5), "c")];
+		assert_eq!(apply_edits("a { }", &overlapping), "b }");
+	}
+}
@@ -274,10 +274,14 @@
 //! ```
 
 mod comparison;
+mod cursor_collector;
 mod cursor_fmt_sink;
+mod cursor_pretty_write_sink;
+mod cursor_trivia_sink;
 mod cursor_vec_sink;
 #[doc(hidden)]
 pub mod diagnostics;
+mod edit;
 mod feature;
 mod macros;
 mod parser;
@@ -293,8 +297,12 @@ pub mod token_macros;
 mod traits;
 
 pub use comparison::*;
+pub use cursor_collector::*;
 pub use cursor_fmt_sink::*;
+pub use cursor_pretty_write_sink::*;
+pub use cursor_trivia_sink::*;
 pub use cursor_vec_sink::*;
+pub use edit::*;
 pub use feature::*;
 pub use macros::optionals::*;
 pub use miette::{Error, Result};
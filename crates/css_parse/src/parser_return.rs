@@ -1,4 +1,4 @@
-use crate::{CursorSink, Error, ToCursors};
+use crate::{CursorSink, CursorTriviaSink, Error, ToCursors};
 use css_lexer::Cursor;
 
 #[derive(Debug)]
@@ -27,8 +27,13 @@ impl<'a, T: ToCursors> ParserReturn<'a, T> {
 impl<T: ToCursors> ToCursors for ParserReturn<'_, T> {
 	fn to_cursors(&self, s: &mut impl CursorSink) {
 		if let Some(output) = &self.output {
-			let sink = if self.with_trivia { todo!() } else { s };
-			ToCursors::to_cursors(output, sink);
+			if self.with_trivia {
+				let mut sink = CursorTriviaSink::new(&self.trivia, s);
+				ToCursors::to_cursors(output, &mut sink);
+				sink.finish();
+			} else {
+				ToCursors::to_cursors(output, s);
+			}
 		}
 	}
 }
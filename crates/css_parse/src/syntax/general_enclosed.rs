@@ -0,0 +1,75 @@
+use crate::{
+	CursorSink, Parse, Parser, Peek, Result as ParserResult, T, ToCursors, diagnostics,
+	syntax::{FunctionBlock, SimpleBlock},
+};
+use css_lexer::{Kind, KindSet};
+use csskit_derives::ToSpan;
+
+/// Represents a [`<general-enclosed>`][1], the catch-all for a parenthesised or functional condition which is not
+/// understood (yet). Media, Container and Supports conditions allow these so that future syntax does not invalidate
+/// the whole query; they evaluate to "unknown" (which is false).
+///
+/// ```md
+/// <general-enclosed>
+///  │├─╮─ <function-token> ─╭─╮─ <any-value> ─╭─ ")" ─┤│
+///     ╰─ "(" ──────────────╯ ╰───────────────╯
+/// ```
+///
+/// [1]: https://drafts.csswg.org/mediaqueries-4/#typedef-general-enclosed
+#[derive(ToSpan, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type"))]
+pub enum GeneralEnclosed<'a> {
+	Function(FunctionBlock<'a>),
+	Block(SimpleBlock<'a>),
+}
+
+impl<'a> Peek<'a> for GeneralEnclosed<'a> {
+	const PEEK_KINDSET: KindSet = KindSet::new(&[Kind::Function, Kind::LeftParen]);
+}
+
+impl<'a> Parse<'a> for GeneralEnclosed<'a> {
+	fn parse(p: &mut Parser<'a>) -> ParserResult<Self> {
+		if p.peek::<T![Function]>() {
+			p.parse::<FunctionBlock>().map(Self::Function)
+		} else if p.peek::<T!['(']>() {
+			p.parse::<SimpleBlock>().map(Self::Block)
+		} else {
+			let c = p.peek_n(1);
+			Err(diagnostics::Unexpected(c.into(), c.into()))?
+		}
+	}
+}
+
+impl<'a> ToCursors for GeneralEnclosed<'a> {
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Function(function) => ToCursors::to_cursors(function, s),
+			Self::Block(block) => ToCursors::to_cursors(block, s),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::*;
+
+	#[test]
+	fn size_test() {
+		assert_eq!(std::mem::size_of::<GeneralEnclosed>(), 72);
+	}
+
+	#[test]
+	fn test_writes() {
+		assert_parse!(GeneralEnclosed, "(foo)");
+		assert_parse!(GeneralEnclosed, "(foo:bar baz)");
+		assert_parse!(GeneralEnclosed, "foo(bar)");
+		assert_parse!(GeneralEnclosed, "(foo(bar)(baz))");
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_parse_error!(GeneralEnclosed, "foo");
+		assert_parse_error!(GeneralEnclosed, "[foo]");
+	}
+}
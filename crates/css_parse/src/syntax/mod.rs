@@ -10,6 +10,7 @@ mod declaration_list;
 mod declaration_rule_list;
 mod function;
 mod function_block;
+mod general_enclosed;
 mod no_block_allowed;
 mod no_prelude_allowed;
 mod qualified_rule;
@@ -28,6 +29,7 @@ pub use declaration_list::*;
 pub use declaration_rule_list::*;
 pub use function::*;
 pub use function_block::*;
+pub use general_enclosed::*;
 pub use no_block_allowed::*;
 pub use no_prelude_allowed::*;
 pub use qualified_rule::*;
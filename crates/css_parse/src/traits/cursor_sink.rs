@@ -6,3 +6,9 @@ use css_lexer::Cursor;
 pub trait CursorSink {
 	fn append(&mut self, c: Cursor);
 }
+
+impl<S: CursorSink> CursorSink for &mut S {
+	fn append(&mut self, c: Cursor) {
+		(**self).append(c)
+	}
+}
@@ -1,5 +1,9 @@
-use crate::{Build, Parse, Parser, Peek, Result, diagnostics, keyword_set};
+use crate::{
+	Build, CursorSink, GeneralEnclosed, Parse, Parser, Peek, Result, T, ToCursors, diagnostics, keyword_set,
+	token_macros,
+};
 use bumpalo::collections::Vec;
+use css_lexer::{Cursor, Kind, KindSet, Span, ToSpan};
 
 keyword_set!(
 	pub enum ConditionKeyword {
@@ -56,7 +60,9 @@ keyword_set!(
 ///
 /// Where `<feature>` is defined by `[FeatureConditionList::FeatureCondition]`, which is required to implement [Parse].
 /// There is a further subtle change for this trait, which is the introduction of the [ConditionKeyword] enum to better
-/// reason about the given condition keyword. This makes the final grammar:
+/// reason about the given condition keyword. Each `<*-in-parens>` is also common to all three, allowing a nested
+/// condition or a `<general-enclosed>` in place of a feature, which is modelled by [ConditionInParens]. This makes
+/// the final grammar:
 ///
 /// ```md
 /// <condition-keyword>
@@ -64,15 +70,23 @@ keyword_set!(
 ///      ├─ <ident-token "and"> ─┤
 ///      ╰─ <ident-token "or"> ──╯
 ///
+/// <condition-in-parens>
+///  │├─╮─ "(" ─ <condition-prelude-list> ─ ")" ─╭──┤│
+///     ├─────────────── <feature> ──────────────┤
+///     ╰─────────── <general-enclosed> ─────────╯
+///
 /// <condition-prelude-list>
-///  │├─╮─ <condition-keyword "not"> ─ <feature> ───────────────────╭──┤│
-///     ╰─ <feature> ─╮─╭─ <condition-keyword "and"> ─ <feature> ─╮─┤
-///                   │ ╰─────────────────────────────────────────╯ │
-///                   │─╭─ <condition-keyword "or"> ─ <feature> ─╮──│
-///                   │ ╰────────────────────────────────────────╯  │
-///                   ╰─────────────────────────────────────────────╯
+///  │├─╮─ <condition-keyword "not"> ─ <condition-in-parens> ───────────────────────────────╭──┤│
+///     ╰─ <condition-in-parens> ─╮─╭─ <condition-keyword "and"> ─ <condition-in-parens> ─╮─┤
+///                               │ ╰─────────────────────────────────────────────────────╯ │
+///                               │─╭─ <condition-keyword "or"> ─ <condition-in-parens> ─╮──│
+///                               │ ╰────────────────────────────────────────────────────╯  │
+///                               ╰─────────────────────────────────────────────────────────╯
 /// ```
 ///
+/// Mixing `and` & `or` at the same level (for example `(a) and (b) or (c)`) is not allowed, they must be grouped with
+/// parenthesis; parsing stops before the first keyword which differs.
+///
 /// [1]: https://drafts.csswg.org/css-conditional-3/#typedef-supports-condition
 /// [2]: https://drafts.csswg.org/mediaqueries/#media-condition
 /// [3]: https://drafts.csswg.org/css-conditional-5/#typedef-container-query
@@ -82,59 +96,121 @@ where
 {
 	type FeatureCondition: Sized + Parse<'a>;
 
-	fn build_is(feature: Self::FeatureCondition) -> Self;
-	fn build_not(keyword: ConditionKeyword, features: Self::FeatureCondition) -> Self;
-	fn build_and(features: Vec<'a, (Self::FeatureCondition, Option<ConditionKeyword>)>) -> Self;
-	fn build_or(features: Vec<'a, (Self::FeatureCondition, Option<ConditionKeyword>)>) -> Self;
+	fn build_is(feature: ConditionInParens<'a, Self, Self::FeatureCondition>) -> Self;
+	fn build_not(keyword: ConditionKeyword, feature: ConditionInParens<'a, Self, Self::FeatureCondition>) -> Self;
+	fn build_and(features: ConditionOperands<'a, Self, Self::FeatureCondition>) -> Self;
+	fn build_or(features: ConditionOperands<'a, Self, Self::FeatureCondition>) -> Self;
 
 	fn parse_condition(p: &mut Parser<'a>) -> Result<Self> {
-		let c = p.peek_next();
-		if ConditionKeyword::peek(p, c) {
-			let keyword = ConditionKeyword::build(p, c);
+		if let Some(keyword) = p.parse_if_peek::<ConditionKeyword>()? {
 			if matches!(keyword, ConditionKeyword::Not(_)) {
-				return Ok(Self::build_is(p.parse::<Self::FeatureCondition>()?));
+				return Ok(Self::build_not(keyword, p.parse::<ConditionInParens<'a, Self, Self::FeatureCondition>>()?));
 			}
+			let c: Cursor = keyword.into();
 			Err(diagnostics::UnexpectedIdent(p.parse_str(c).into(), c.into()))?
 		}
-		let mut feature = p.parse::<Self::FeatureCondition>()?;
-		let keyword = p.parse_if_peek::<ConditionKeyword>()?;
-		match keyword {
-			Some(ConditionKeyword::And(_)) => {
-				let mut features = Vec::new_in(p.bump());
-				let mut keyword = keyword.unwrap();
-				loop {
-					features.push((feature, Some(keyword)));
-					feature = p.parse::<Self::FeatureCondition>()?;
-					let c = p.peek_next();
-					if !ConditionKeyword::peek(p, c)
-						|| !matches!(ConditionKeyword::build(p, c), ConditionKeyword::And(_))
-					{
-						features.push((feature, None));
-						return Ok(Self::build_and(features));
-					}
-					keyword = ConditionKeyword::build(p, c);
-				}
+		let mut feature = p.parse::<ConditionInParens<'a, Self, Self::FeatureCondition>>()?;
+		let Some(mut keyword) = p.parse_if_peek::<ConditionKeyword>()? else {
+			return Ok(Self::build_is(feature));
+		};
+		if matches!(keyword, ConditionKeyword::Not(_)) {
+			let c: Cursor = keyword.into();
+			Err(diagnostics::UnexpectedIdent(p.parse_str(c).into(), c.into()))?
+		}
+		let is_and = matches!(keyword, ConditionKeyword::And(_));
+		let mut features = Vec::new_in(p.bump());
+		loop {
+			features.push((feature, Some(keyword)));
+			feature = p.parse::<ConditionInParens<'a, Self, Self::FeatureCondition>>()?;
+			let c = p.peek_next();
+			let same_keyword = ConditionKeyword::peek(p, c)
+				&& match ConditionKeyword::build(p, c) {
+					ConditionKeyword::And(_) => is_and,
+					ConditionKeyword::Or(_) => !is_and,
+					ConditionKeyword::Not(_) => false,
+				};
+			if !same_keyword {
+				features.push((feature, None));
+				return Ok(if is_and { Self::build_and(features) } else { Self::build_or(features) });
 			}
-			Some(ConditionKeyword::Or(_)) => {
-				let mut features = Vec::new_in(p.bump());
-				let mut keyword = keyword.unwrap();
-				loop {
-					features.push((feature, Some(keyword)));
-					feature = p.parse::<Self::FeatureCondition>()?;
-					let c = p.peek_next();
-					if !ConditionKeyword::peek(p, c)
-						|| !matches!(ConditionKeyword::build(p, c), ConditionKeyword::And(_))
-					{
-						features.push((feature, None));
-						return Ok(Self::build_or(features));
-					}
-					keyword = ConditionKeyword::build(p, c);
+			keyword = p.parse::<ConditionKeyword>()?;
+		}
+	}
+}
+
+/// The operands of an `and` or `or` [FeatureConditionList], each followed by the keyword joining it to the next.
+pub type ConditionOperands<'a, C, F> = Vec<'a, (ConditionInParens<'a, C, F>, Option<ConditionKeyword>)>;
+
+/// One operand of a [FeatureConditionList], the `<*-in-parens>` production of each condition grammar: either a
+/// `<feature>` (`F`), a nested condition (`C`) wrapped in parenthesis, or a [GeneralEnclosed] which was not
+/// understood. The nested condition is allocated in the parser's arena.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(tag = "type", content = "value"))]
+pub enum ConditionInParens<'a, C, F> {
+	Feature(F),
+	Condition(token_macros::LeftParen, &'a C, token_macros::RightParen),
+	GeneralEnclosed(GeneralEnclosed<'a>),
+}
+
+impl<'a, C, F> Peek<'a> for ConditionInParens<'a, C, F> {
+	const PEEK_KINDSET: KindSet = KindSet::new(&[Kind::Function, Kind::LeftParen]);
+}
+
+impl<'a, C, F> Parse<'a> for ConditionInParens<'a, C, F>
+where
+	C: FeatureConditionList<'a, FeatureCondition = F>,
+	F: Parse<'a>,
+{
+	fn parse(p: &mut Parser<'a>) -> Result<Self> {
+		let err = match p.try_parse::<F>() {
+			Ok(feature) => return Ok(Self::Feature(feature)),
+			Err(err) => err,
+		};
+		if p.peek::<T!['(']>() {
+			let checkpoint = p.checkpoint();
+			let open = p.parse::<T!['(']>()?;
+			if let Ok(condition) = p.parse::<C>() {
+				if p.peek::<T![')']>() {
+					return Ok(Self::Condition(open, p.bump().alloc(condition), p.parse::<T![')']>()?));
 				}
 			}
-			Some(ConditionKeyword::Not(_)) => {
-				Ok(Self::build_not(keyword.unwrap(), p.parse::<Self::FeatureCondition>()?))
+			p.rewind(checkpoint);
+		}
+		if p.peek::<GeneralEnclosed>() {
+			return p.parse::<GeneralEnclosed>().map(Self::GeneralEnclosed);
+		}
+		Err(err)
+	}
+}
+
+impl<'a, C, F> ToCursors for ConditionInParens<'a, C, F>
+where
+	C: ToCursors,
+	F: ToCursors,
+{
+	fn to_cursors(&self, s: &mut impl CursorSink) {
+		match self {
+			Self::Feature(feature) => ToCursors::to_cursors(feature, s),
+			Self::Condition(open, condition, close) => {
+				ToCursors::to_cursors(open, s);
+				ToCursors::to_cursors(*condition, s);
+				ToCursors::to_cursors(close, s);
 			}
-			None => Ok(Self::build_is(feature)),
+			Self::GeneralEnclosed(enclosed) => ToCursors::to_cursors(enclosed, s),
+		}
+	}
+}
+
+impl<'a, C, F> ToSpan for ConditionInParens<'a, C, F>
+where
+	C: ToSpan,
+	F: ToSpan,
+{
+	fn to_span(&self) -> Span {
+		match self {
+			Self::Feature(feature) => feature.to_span(),
+			Self::Condition(open, condition, close) => open.to_span() + condition.to_span() + close.to_span(),
+			Self::GeneralEnclosed(enclosed) => enclosed.to_span(),
 		}
 	}
 }
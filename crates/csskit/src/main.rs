@@ -1,10 +1,12 @@
 use bumpalo::Bump;
 use clap::{Parser, Subcommand, crate_version};
 use css_ast::StyleSheet;
-use css_parse::{CursorFmtSink, ToCursors};
+use css_parse::{CursorFmtSink, CursorPrettyWriteSink, ToCursors};
+use csskit_config::{BrowserTarget, Config};
 use csskit_lsp::{LSPService, Server};
+use csskit_transform::Edit;
 use miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
-use std::io;
+use std::{io, path::Path};
 use tracing::{level_filters::LevelFilter, trace};
 use tracing_subscriber::{Layer, fmt, layer::SubscriberExt, registry, util::SubscriberInitExt};
 
@@ -16,6 +18,10 @@ struct Cli {
 
 	#[arg(short, long)]
 	debug: bool,
+
+	/// A csskit.toml (or .csskitrc.json) file to use, rather than the nearest one to the current directory.
+	#[arg(long, global = true, value_parser)]
+	config: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -74,19 +80,122 @@ enum Commands {
 fn main() {
 	let cli = Cli::parse();
 	let debug = cli.debug;
+	let config = match &cli.config {
+		Some(path) => Config::load(Path::new(path)),
+		None => std::env::current_dir().map_or_else(|_| Ok(Config::default()), |dir| Config::discover(&dir)),
+	};
+	let config = config.unwrap_or_else(|err| {
+		eprintln!("{err}");
+		std::process::exit(1);
+	});
+	let features = config.features();
 
 	match &cli.command {
 		Commands::Check { input, fix } => {
-			todo!("Check ({:?}, {:?})", input, fix);
+			let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+			let mut problems = 0;
+			for file_name in input.iter().filter(|file_name| !config.is_ignored(Path::new(file_name))) {
+				let original = std::fs::read_to_string(file_name).unwrap();
+				let mut source_text = original.clone();
+				// Overlapping fixes are skipped, so fixing repeats until nothing changes.
+				if *fix {
+					loop {
+						let bump = Bump::default();
+						let result = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+							.parse_entirely::<StyleSheet>();
+						let Some(stylesheet) = result.output.as_ref() else { break };
+						let edits: Vec<_> = config
+							.lint
+							.apply(csskit_lint::lint(&source_text, stylesheet))
+							.into_iter()
+							.filter_map(|lint| lint.fix)
+							.flat_map(|fix| fix.edits)
+							.collect();
+						let fixed = csskit_lint::apply_edits(&source_text, &edits);
+						drop(result);
+						if fixed == source_text {
+							break;
+						}
+						source_text = fixed;
+					}
+				}
+				if source_text != original {
+					std::fs::write(file_name, source_text.as_bytes()).unwrap();
+				}
+				let bump = Bump::default();
+				let result = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+					.parse_entirely::<StyleSheet>();
+				let lints = result
+					.output
+					.as_ref()
+					.map(|stylesheet| {
+						let mut lints = csskit_lint::lint_file(&source_text, stylesheet, Path::new(file_name));
+						if let Some(targets) = &config.targets {
+							lints.extend(csskit_lint::lint_targets(&source_text, stylesheet, targets));
+							lints.sort_by_key(|lint| (lint.span.start(), lint.span.end()));
+						}
+						lints
+					})
+					.map(|lints| config.lint.apply(lints))
+					.unwrap_or_default();
+				let reports = result.errors.into_iter().chain(lints.into_iter().map(miette::Report::new));
+				for err in reports {
+					let mut report = String::new();
+					let named = NamedSource::new(file_name, source_text.clone());
+					let err = err.with_source_code(named);
+					handler.render_report(&mut report, err.as_ref()).unwrap();
+					println!("{report}");
+					problems += 1;
+				}
+			}
+			if problems > 0 {
+				eprintln!("Found {problems} problem(s)");
+				std::process::exit(1);
+			}
 		}
 		Commands::Fmt { input, check } => {
-			todo!("Fmt ({:?}, {:?})", input, check);
+			let mut unformatted = vec![];
+			let indent = config.format.indent.unwrap_or_default().text();
+			for file_name in input.iter().filter(|file_name| !config.is_ignored(Path::new(file_name))) {
+				let source_text = std::fs::read_to_string(file_name).unwrap();
+				let bump = Bump::default();
+				let result = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+					.parse_entirely::<StyleSheet>();
+				if result.output.is_none() || !result.errors.is_empty() {
+					let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+					for err in result.errors {
+						let mut report = String::new();
+						let named = NamedSource::new(file_name, source_text.clone());
+						let err = err.with_source_code(named);
+						handler.render_report(&mut report, err.as_ref()).unwrap();
+						println!("{report}");
+					}
+					std::process::exit(1);
+				}
+				let mut str = String::new();
+				let mut sink = CursorPrettyWriteSink::new(source_text.as_str(), &mut str).with_indent(&indent);
+				result.with_trivia().to_cursors(&mut sink);
+				sink.finish().unwrap();
+				if str == source_text {
+					continue;
+				}
+				if *check {
+					eprintln!("Would reformat {file_name}");
+					unformatted.push(file_name);
+				} else {
+					std::fs::write(file_name, str.as_bytes()).unwrap();
+				}
+			}
+			if !unformatted.is_empty() {
+				std::process::exit(1);
+			}
 		}
 		Commands::DbgParse { input } => {
 			let source_text = std::fs::read_to_string(input).unwrap();
 			println!("{source_text}");
 			let bump = Bump::default();
-			let result = css_parse::Parser::new(&bump, source_text.as_str()).parse_entirely::<StyleSheet>();
+			let result = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+				.parse_entirely::<StyleSheet>();
 			if let Some(stylesheet) = &result.output {
 				println!("{stylesheet:#?}");
 			} else {
@@ -106,10 +215,48 @@ fn main() {
 			}
 
 			let file_name = input.first().unwrap();
-			let source_text = std::fs::read_to_string(file_name).unwrap();
+			let mut source_text = std::fs::read_to_string(file_name).unwrap();
 			let bump = Bump::default();
 			let start = std::time::Instant::now();
-			let result = css_parse::Parser::new(&bump, source_text.as_str()).parse_entirely::<StyleSheet>();
+			// Before building, `@supports` rules which are true (or false) in every target are unwrapped (or removed),
+			// newer syntax which any target lacks is lowered, then vendor prefixes are added or removed for the targets.
+			if let Some(targets) = &config.targets {
+				type Transform = fn(&str, &StyleSheet, &[BrowserTarget]) -> Vec<Edit>;
+				let mut transforms: Vec<Transform> = vec![
+					csskit_transform::resolve_supports,
+					csskit_transform::lower_media_ranges,
+					csskit_transform::flatten_nesting,
+					csskit_transform::lower_is,
+					csskit_transform::expand_inset,
+				];
+				// Flow-relative properties are only lowered when asked to, as doing so assumes left-to-right text.
+				if config.build.lower_logical_properties.unwrap_or(false) {
+					transforms.push(csskit_transform::lower_logical_properties);
+				}
+				transforms.extend([csskit_transform::lower_colors as Transform, csskit_transform::autoprefix]);
+				for transform in transforms {
+					let edits = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+						.parse_entirely::<StyleSheet>()
+						.output
+						.map_or(vec![], |stylesheet| transform(&source_text, &stylesheet, targets));
+					source_text = csskit_transform::apply_edits(&source_text, &edits);
+				}
+			}
+			// `@media` rules with the same queries are merged where the cascade allows, or (when asked to) all merged and
+			// sorted mobile-first.
+			let mut transforms: Vec<fn(&str, &StyleSheet) -> Vec<Edit>> = vec![csskit_transform::merge_media_rules];
+			if config.build.sort_media_rules.unwrap_or(false) {
+				transforms.push(csskit_transform::sort_media_rules);
+			}
+			for transform in transforms {
+				let edits = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+					.parse_entirely::<StyleSheet>()
+					.output
+					.map_or(vec![], |stylesheet| transform(&source_text, &stylesheet));
+				source_text = csskit_transform::apply_edits(&source_text, &edits);
+			}
+			let result = css_parse::Parser::new_with_features(&bump, source_text.as_str(), features)
+				.parse_entirely::<StyleSheet>();
 			{
 				if result.output.is_some() {
 					let mut str = String::new();
@@ -0,0 +1,422 @@
+#![deny(warnings)]
+//! Configuration shared by the csskit CLI & LSP, read from a `csskit.toml` (or `.csskitrc.json`) file in the project:
+//!
+//! ```toml
+//! # Stylesheets which are never checked or formatted, relative to this file.
+//! ignore = ["vendor/**", "*.min.css"]
+//! # The oldest browser versions stylesheets should work in.
+//! targets = ["chrome 100", "firefox 115", "safari 15.4"]
+//!
+//! [parser]
+//! features = ["single-line-comments"]
+//!
+//! [lint]
+//! deprecated-property = "error"
+//! legacy-pseudo-element = "off"
+//!
+//! [format]
+//! indent = 2 # or "tab"
+//!
+//! [build]
+//! # Convert flow-relative properties for targets which lack them, assuming left-to-right horizontal text.
+//! lower-logical-properties = true
+//! # Merge every `@media` rule with the same queries, and move them to the end sorted by breakpoint, mobile-first.
+//! sort-media-rules = true
+//! ```
+//!
+//! The JSON file has the same shape. Every key is optional; a [Config] can be layered on top of another with
+//! [Config::merge()], which is how editor settings override the file.
+use css_parse::Feature;
+use csskit_lint::{Lint, Rule};
+use miette::Severity;
+use serde::{Deserialize, Deserializer, de};
+use std::{
+	collections::HashMap,
+	fs, io,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
+use thiserror::Error;
+
+pub use css_ast::{Browser, BrowserTarget, TargetError, Version};
+
+/// The names of config files, in order of preference when a directory has more than one.
+pub const CONFIG_FILE_NAMES: [&str; 2] = ["csskit.toml", ".csskitrc.json"];
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+	#[error("could not read {0}: {1}")]
+	Io(PathBuf, io::Error),
+	#[error("invalid config in {0}: {1}")]
+	Toml(PathBuf, toml::de::Error),
+	#[error("invalid config in {0}: {1}")]
+	Json(PathBuf, serde_json::Error),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Config {
+	pub parser: ParserConfig,
+	pub lint: LintConfig,
+	pub format: FormatConfig,
+	pub build: BuildConfig,
+	/// The oldest browser versions stylesheets should work in, if any are set.
+	#[serde(deserialize_with = "deserialize_targets")]
+	pub targets: Option<Vec<BrowserTarget>>,
+	/// Globs of stylesheets which are skipped, relative to the [root][Config::root].
+	pub ignore: Option<Vec<IgnorePattern>>,
+	/// The directory which the config applies to: the directory of the file it was loaded from.
+	#[serde(skip)]
+	pub root: Option<PathBuf>,
+}
+
+impl Config {
+	pub fn from_toml(text: &str) -> Result<Self, toml::de::Error> {
+		toml::from_str(text)
+	}
+
+	pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+		serde_json::from_str(text)
+	}
+
+	/// Reads the config file at the [Path], as JSON if it has a `.json` extension, otherwise as TOML.
+	pub fn load(path: &Path) -> Result<Self, ConfigError> {
+		let text = fs::read_to_string(path).map_err(|err| ConfigError::Io(path.into(), err))?;
+		let mut config = if path.extension().is_some_and(|ext| ext == "json") {
+			Self::from_json(&text).map_err(|err| ConfigError::Json(path.into(), err))?
+		} else {
+			Self::from_toml(&text).map_err(|err| ConfigError::Toml(path.into(), err))?
+		};
+		config.root = std::path::absolute(path).ok().and_then(|path| path.parent().map(Path::to_path_buf));
+		Ok(config)
+	}
+
+	/// Finds the nearest config file, searching the directory and then each of its parents.
+	pub fn find(dir: &Path) -> Option<PathBuf> {
+		dir.ancestors().flat_map(|dir| CONFIG_FILE_NAMES.map(|name| dir.join(name))).find(|path| path.is_file())
+	}
+
+	/// Loads the nearest config file to the directory (see [Config::find()]), or the default config if there is none.
+	pub fn discover(dir: &Path) -> Result<Self, ConfigError> {
+		Self::find(dir).map_or_else(|| Ok(Self::default()), |path| Self::load(&path))
+	}
+
+	/// Layers the `overrides` on top of this config: each setting in `overrides` replaces the one here, while lint
+	/// rules are replaced individually.
+	pub fn merge(mut self, overrides: Config) -> Self {
+		self.parser.features = overrides.parser.features.or(self.parser.features);
+		self.lint.rules.extend(overrides.lint.rules);
+		self.format.indent = overrides.format.indent.or(self.format.indent);
+		self.build.lower_logical_properties =
+			overrides.build.lower_logical_properties.or(self.build.lower_logical_properties);
+		self.build.sort_media_rules = overrides.build.sort_media_rules.or(self.build.sort_media_rules);
+		self.targets = overrides.targets.or(self.targets);
+		self.ignore = overrides.ignore.or(self.ignore);
+		self.root = self.root.or(overrides.root);
+		self
+	}
+
+	/// The parser [Features][Feature] to parse stylesheets with.
+	pub fn features(&self) -> Feature {
+		self.parser.features.iter().flatten().fold(Feature::none(), |features, feature| features | (*feature).into())
+	}
+
+	/// Returns true if the [Path] (or one of its parent directories) matches one of the [ignore][Config::ignore]
+	/// globs. Relative paths are resolved against the current directory.
+	pub fn is_ignored(&self, path: &Path) -> bool {
+		let Some(patterns) = &self.ignore else {
+			return false;
+		};
+		let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+		let path = self.root.as_deref().and_then(|root| path.strip_prefix(root).ok()).unwrap_or(&path);
+		path.ancestors()
+			.filter(|path| !path.as_os_str().is_empty())
+			.any(|path| patterns.iter().any(|pattern| pattern.0.matches_path(path)))
+	}
+}
+
+fn deserialize_targets<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<BrowserTarget>>, D::Error> {
+	Option::<Vec<String>>::deserialize(deserializer)?
+		.map(|targets| targets.iter().map(|target| target.parse().map_err(de::Error::custom)).collect())
+		.transpose()
+}
+
+/// Returns true if the [Path] is named like a config file (see [CONFIG_FILE_NAMES]).
+pub fn is_config_file(path: &Path) -> bool {
+	path.file_name().is_some_and(|name| CONFIG_FILE_NAMES.iter().any(|config| name == *config))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ParserConfig {
+	pub features: Option<Vec<ParserFeature>>,
+}
+
+/// The name of a parser [Feature], for example `single-line-comments`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParserFeature {
+	SingleLineComments,
+	SeparateWhitespace,
+}
+
+impl From<ParserFeature> for Feature {
+	fn from(value: ParserFeature) -> Self {
+		match value {
+			ParserFeature::SingleLineComments => Feature::SingleLineComments,
+			ParserFeature::SeparateWhitespace => Feature::SeparateWhitespace,
+		}
+	}
+}
+
+/// How lints of a [Rule] are reported, or `off` to disable the rule.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleLevel {
+	Off,
+	#[serde(alias = "hint")]
+	Advice,
+	#[serde(alias = "warn")]
+	Warning,
+	Error,
+}
+
+impl RuleLevel {
+	pub fn severity(&self) -> Option<Severity> {
+		match self {
+			Self::Off => None,
+			Self::Advice => Some(Severity::Advice),
+			Self::Warning => Some(Severity::Warning),
+			Self::Error => Some(Severity::Error),
+		}
+	}
+}
+
+/// The [RuleLevel] of each [Rule] which has been configured, keyed by the rule's name (such as `missing-file`).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(try_from = "HashMap<String, RuleLevel>")]
+pub struct LintConfig {
+	rules: HashMap<Rule, RuleLevel>,
+}
+
+impl LintConfig {
+	pub fn level(&self, rule: Rule) -> Option<RuleLevel> {
+		self.rules.get(&rule).copied()
+	}
+
+	/// Removes the [Lints][Lint] of rules which are turned off, and changes the severity of those which are configured.
+	pub fn apply(&self, lints: Vec<Lint>) -> Vec<Lint> {
+		lints
+			.into_iter()
+			.filter_map(|mut lint| {
+				if let Some(level) = self.level(lint.rule) {
+					lint.severity = level.severity()?;
+				}
+				Some(lint)
+			})
+			.collect()
+	}
+}
+
+impl TryFrom<HashMap<String, RuleLevel>> for LintConfig {
+	type Error = String;
+
+	fn try_from(value: HashMap<String, RuleLevel>) -> Result<Self, Self::Error> {
+		let rules = value
+			.into_iter()
+			.map(|(name, level)| Ok((Rule::from_str(&name).map_err(|_| format!("unknown lint rule `{name}`"))?, level)))
+			.collect::<Result<_, String>>()?;
+		Ok(Self { rules })
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct FormatConfig {
+	pub indent: Option<Indent>,
+}
+
+/// The indentation of each level of nesting, written as `"tab"` or a number of spaces.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "IndentValue")]
+pub enum Indent {
+	#[default]
+	Tab,
+	Spaces(u8),
+}
+
+impl Indent {
+	/// The text written once per level of nesting.
+	pub fn text(&self) -> String {
+		match self {
+			Self::Tab => "\t".into(),
+			Self::Spaces(n) => " ".repeat(*n as usize),
+		}
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IndentValue {
+	Spaces(u8),
+	Name(String),
+}
+
+impl TryFrom<IndentValue> for Indent {
+	type Error = String;
+
+	fn try_from(value: IndentValue) -> Result<Self, Self::Error> {
+		match value {
+			IndentValue::Spaces(n @ 1..=16) => Ok(Self::Spaces(n)),
+			IndentValue::Name(name) if name == "tab" => Ok(Self::Tab),
+			_ => Err("expected \"tab\" or a number of spaces between 1 and 16".into()),
+		}
+	}
+}
+
+/// Options for `csskit build`, beyond those implied by the [targets][Config::targets].
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct BuildConfig {
+	/// Converts flow-relative properties (such as `margin-inline-start`) into their physical equivalent for targets
+	/// which don't support them. This is off by default, as it assumes every element is laid out left-to-right and
+	/// horizontally, which isn't the case for an element within a `dir="rtl"` element.
+	pub lower_logical_properties: Option<bool>,
+	/// Merges every `@media` rule with the same queries and moves them to the end of the stylesheet, sorted by
+	/// breakpoint. This is off by default, as moving rules past others can change which declarations apply; `@media`
+	/// rules are otherwise only merged where the cascade stays the same.
+	pub sort_media_rules: Option<bool>,
+}
+
+/// A glob, such as `vendor/**`, of paths to skip.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct IgnorePattern(glob::Pattern);
+
+impl TryFrom<String> for IgnorePattern {
+	type Error = glob::PatternError;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> {
+		glob::Pattern::new(&value).map(Self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_lexer::Span;
+
+	#[test]
+	fn test_from_toml() {
+		let config = Config::from_toml(
+			r#"
+			ignore = ["vendor/**"]
+			targets = ["chrome 100", "safari 15.4"]
+
+			[parser]
+			features = ["single-line-comments"]
+
+			[lint]
+			deprecated-property = "error"
+			legacy-pseudo-element = "off"
+
+			[format]
+			indent = 2
+
+			[build]
+			lower-logical-properties = true
+			sort-media-rules = true
+			"#,
+		)
+		.unwrap();
+		assert_eq!(config.features(), Feature::SingleLineComments);
+		assert_eq!(config.lint.level(Rule::DeprecatedProperty), Some(RuleLevel::Error));
+		assert_eq!(config.lint.level(Rule::LegacyPseudoElement), Some(RuleLevel::Off));
+		assert_eq!(config.lint.level(Rule::MissingFile), None);
+		assert_eq!(config.format.indent, Some(Indent::Spaces(2)));
+		assert_eq!(config.build.lower_logical_properties, Some(true));
+		assert_eq!(config.build.sort_media_rules, Some(true));
+		assert_eq!(
+			config.targets,
+			Some(vec![
+				BrowserTarget::new(Browser::Chrome, Version::new(100, 0)),
+				BrowserTarget::new(Browser::Safari, Version::new(15, 4))
+			])
+		);
+		assert_eq!(Config::from_toml("").unwrap(), Config::default());
+	}
+
+	#[test]
+	fn test_from_json() {
+		let config = Config::from_json(r#"{ "format": { "indent": "tab" }, "lint": { "missing-file": "warn" } }"#);
+		let config = config.unwrap();
+		assert_eq!(config.format.indent, Some(Indent::Tab));
+		assert_eq!(config.lint.level(Rule::MissingFile), Some(RuleLevel::Warning));
+	}
+
+	#[test]
+	fn test_invalid() {
+		let err = Config::from_toml("[lint]\nno-such-rule = \"off\"").unwrap_err();
+		assert!(err.message().contains("unknown lint rule `no-such-rule`"));
+		assert!(Config::from_toml("[format]\nindent = 0").is_err());
+		assert!(Config::from_toml("targets = [\"chrome\"]").is_err());
+		assert!(Config::from_toml("[parser]\nfeatures = [\"sass\"]").is_err());
+	}
+
+	#[test]
+	fn test_merge() {
+		let base = Config::from_toml(
+			"targets = [\"chrome 100\"]\n[lint]\nmissing-file = \"off\"\nduplicate-declaration = \"error\"",
+		);
+		let overrides = Config::from_json(r#"{ "lint": { "missing-file": "error" }, "format": { "indent": 4 } }"#);
+		let config = base.unwrap().merge(overrides.unwrap());
+		assert_eq!(config.lint.level(Rule::MissingFile), Some(RuleLevel::Error));
+		assert_eq!(config.lint.level(Rule::DuplicateDeclaration), Some(RuleLevel::Error));
+		assert_eq!(config.format.indent, Some(Indent::Spaces(4)));
+		assert_eq!(config.targets.map(|targets| targets.len()), Some(1));
+	}
+
+	#[test]
+	fn test_apply_lints() {
+		let config = Config::from_toml("[lint]\nmissing-file = \"off\"\nlegacy-pseudo-element = \"error\"").unwrap();
+		let lints = vec![
+			Lint::new(Rule::MissingFile, "a", Span::DUMMY),
+			Lint::new(Rule::LegacyPseudoElement, "b", Span::DUMMY),
+			Lint::new(Rule::DuplicateDeclaration, "c", Span::DUMMY),
+		];
+		let lints = config.lint.apply(lints);
+		assert_eq!(
+			lints.iter().map(|lint| (lint.rule, lint.severity)).collect::<Vec<_>>(),
+			vec![(Rule::LegacyPseudoElement, Severity::Error), (Rule::DuplicateDeclaration, Severity::Warning)]
+		);
+	}
+
+	#[test]
+	fn test_is_ignored() {
+		let mut config = Config::from_toml("ignore = [\"vendor\", \"*.min.css\"]").unwrap();
+		config.root = Some(PathBuf::from("/project"));
+		assert!(config.is_ignored(Path::new("/project/vendor/a.css")));
+		assert!(config.is_ignored(Path::new("/project/styles/a.min.css")));
+		assert!(!config.is_ignored(Path::new("/project/styles/a.css")));
+		assert!(!Config::default().is_ignored(Path::new("/project/vendor/a.css")));
+	}
+
+	#[test]
+	fn test_discover() {
+		let dir = std::env::temp_dir().join(format!("csskit_config_discover_{}", std::process::id()));
+		let nested = dir.join("a/b");
+		fs::create_dir_all(&nested).unwrap();
+		fs::write(dir.join(".csskitrc.json"), r#"{ "format": { "indent": 2 } }"#).unwrap();
+		let found = Config::find(&nested);
+		let config = Config::discover(&nested).unwrap();
+		fs::write(dir.join("csskit.toml"), "[format]\nindent = 4").unwrap();
+		let preferred = Config::discover(&nested).unwrap();
+		fs::remove_dir_all(&dir).unwrap();
+		assert_eq!(found, Some(dir.join(".csskitrc.json")));
+		assert_eq!(config.format.indent, Some(Indent::Spaces(2)));
+		assert_eq!(config.root.as_deref(), Some(dir.as_path()));
+		assert_eq!(preferred.format.indent, Some(Indent::Spaces(4)));
+		assert!(is_config_file(Path::new("/a/csskit.toml")));
+		assert!(!is_config_file(Path::new("/a/csskit.json")));
+	}
+}
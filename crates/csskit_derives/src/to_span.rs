@@ -7,6 +7,7 @@ use crate::err;
 
 trait TypeIsOption {
 	fn is_option(&self) -> bool;
+	fn is_phantom(&self) -> bool;
 }
 
 impl TypeIsOption for Type {
@@ -16,6 +17,13 @@ impl TypeIsOption for Type {
 			_ => false,
 		}
 	}
+
+	fn is_phantom(&self) -> bool {
+		match self {
+			Self::Path(TypePath { path, .. }) => path.segments.last().is_some_and(|s| s.ident == "PhantomData"),
+			_ => false,
+		}
+	}
 }
 
 pub fn derive(input: DeriveInput) -> TokenStream {
@@ -26,14 +34,20 @@ pub fn derive(input: DeriveInput) -> TokenStream {
 		Data::Union(_) => err(ident.span(), "Cannot derive ToSpan on a Union"),
 
 		Data::Struct(DataStruct { fields, .. }) => {
-			let members: Vec<_> = fields.members().zip(fields.iter().map(|f| f.ty.is_option())).collect();
+			// PhantomData never has a Span, so it can't contribute to the Span of the struct.
+			let members: Vec<_> = fields
+				.members()
+				.zip(fields.iter())
+				.filter(|(_, f)| !f.ty.is_phantom())
+				.map(|(member, f)| (member, f.ty.is_option()))
+				.collect();
 			if members.len() == 1 {
-				let member = fields.members().next().unwrap();
+				let member = &members[0].0;
 				quote! { self.#member.to_span() }
 
 			// All members are Option<T>, so we have no choice but to try and add them all to get something useful.
 			} else if members.iter().all(|(_, is_option)| *is_option) {
-				let members = fields.members();
+				let members = members.iter().map(|(member, _)| member);
 				quote! { #(self.#members.to_span())+* }
 			} else {
 				// To get a reliable span we need to find the first member, and the last. However as some members are
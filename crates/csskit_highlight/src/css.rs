@@ -1,8 +1,81 @@
 use css_ast::{Declaration, DeclarationValue, PropertyRule, PseudoClass, StyleRule, Tag, Visit};
-use css_lexer::ToSpan;
+use css_lexer::{Kind, SourceOffset, Span, ToSpan};
+use css_parse::{AtRule, CursorCollector, Parse, Peek, ToCursors, token_macros};
 
 use crate::{SemanticKind, SemanticModifier, TokenHighlighter};
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TokenContext {
+	Value,
+	Prelude,
+}
+
+impl TokenHighlighter {
+	// Values & preludes are not (all) visited as nodes, so their tokens are highlighted based on their kind. Within an
+	// at-rule prelude, identifiers inside parenthesis are media features (e.g. `(hover)` or `(width > 1px)`), unless
+	// they follow a colon, in which case they are the feature's value (e.g. `(orientation: landscape)`).
+	fn highlight_tokens<T: ToCursors>(&mut self, node: &T, context: TokenContext) {
+		let mut cursors = CursorCollector::default();
+		node.to_cursors(&mut cursors);
+		let mut depth = 0;
+		let mut after_colon = false;
+		for c in cursors.0 {
+			let token = c.token();
+			let span = c.span();
+			match token.kind() {
+				Kind::LeftParen => {
+					depth += 1;
+					after_colon = false;
+				}
+				Kind::RightParen => {
+					depth -= 1;
+					after_colon = false;
+				}
+				Kind::Colon => {
+					after_colon = true;
+				}
+				Kind::Function => {
+					depth += 1;
+					after_colon = false;
+					// The trailing `(` is not part of the function name.
+					let end = SourceOffset(span.end().0 - 1);
+					self.insert(Span::new(span.start(), end), SemanticKind::Function, SemanticModifier::none());
+				}
+				Kind::Ident if token.is_dashed_ident() => {
+					self.insert(span, SemanticKind::Declaration, SemanticModifier::Custom);
+				}
+				Kind::Ident => {
+					let kind = match context {
+						TokenContext::Prelude if depth > 0 && !after_colon => SemanticKind::MediaFeature,
+						TokenContext::Prelude if depth == 0 => SemanticKind::Prelude,
+						_ => SemanticKind::StyleValueKeyword,
+					};
+					self.insert(span, kind, SemanticModifier::none());
+				}
+				Kind::Number => {
+					self.insert(span, SemanticKind::StyleValueNumber, SemanticModifier::none());
+				}
+				Kind::Dimension => {
+					let unit = SourceOffset(span.start().0 + token.numeric_len());
+					self.insert(
+						Span::new(span.start(), unit),
+						SemanticKind::StyleValueDimension,
+						SemanticModifier::none(),
+					);
+					self.insert(Span::new(unit, span.end()), SemanticKind::StyleValueUnit, SemanticModifier::none());
+				}
+				Kind::String => {
+					self.insert(span, SemanticKind::String, SemanticModifier::none());
+				}
+				Kind::Url => {
+					self.insert(span, SemanticKind::Url, SemanticModifier::none());
+				}
+				_ => {}
+			}
+		}
+	}
+}
+
 impl Visit for TokenHighlighter {
 	fn visit_tag(&mut self, tag: &Tag) {
 		let span = tag.to_span();
@@ -57,6 +130,17 @@ impl Visit for TokenHighlighter {
 		}
 		self.insert(span, SemanticKind::Declaration, modifier);
 		self.insert(property.colon.to_span(), SemanticKind::Punctuation, SemanticModifier::none());
+		self.highlight_tokens(&property.value, TokenContext::Value);
+	}
+
+	fn visit_at_rule<'a, AT, P, B>(&mut self, rule: &AtRule<'a, AT, P, B>)
+	where
+		AT: Peek<'a> + Parse<'a> + Into<token_macros::AtKeyword>,
+		P: Parse<'a> + ToCursors + ToSpan,
+		B: Parse<'a> + ToCursors + ToSpan,
+	{
+		self.insert(rule.name.to_span(), SemanticKind::AtKeyword, SemanticModifier::none());
+		self.highlight_tokens(&rule.prelude, TokenContext::Prelude);
 	}
 
 	fn visit_property_rule<'a>(&mut self, property: &PropertyRule<'a>) {
@@ -31,12 +31,17 @@ pub enum SemanticKind {
 	/* Rule Elements */
 	AtKeyword,
 	Prelude,
+	MediaFeature,
 
 	/* Property Declarations */
 	Declaration,
 	StyleValueKeyword,
 	StyleValueDimension,
 	StyleValueNumber,
+	StyleValueUnit,
+	Function,
+	String,
+	Url,
 
 	Punctuation,
 }
@@ -1,7 +1,7 @@
 use core::fmt;
 
-use crate::TokenHighlighter;
-use css_lexer::{Cursor, Token};
+use crate::{Highlight, TokenHighlighter};
+use css_lexer::{Cursor, Span, Token};
 use css_parse::CursorSink;
 
 pub(crate) struct HTMLHighlightCursorStream<'a, T> {
@@ -72,21 +72,33 @@ impl<'a, T: fmt::Write> CursorSink for HTMLHighlightCursorStream<'a, T> {
 			return;
 		}
 		self.last_token = Some(c.into());
-		let highlight = self.highlighter.get(c.into());
-		if let Some(highlight) = highlight {
-			if let Err(err) =
-				self.writer.write_str(format!(r#"<span class="{}{}">"#, highlight.kind, highlight.modifier).as_str())
-			{
+		// A token may have several highlights which each cover a part of it, such as the number & unit of a dimension.
+		let span: Span = c.into();
+		let mut parts: Vec<&Highlight> = self.highlighter.highlights().filter(|h| span.contains(h.span())).collect();
+		parts.sort();
+		if parts.is_empty() {
+			if let Err(err) = c.write_str(self.source_text, &mut self.writer) {
 				self.err = Some(err);
 			}
+			return;
 		}
-		if let Err(err) = c.write_str(self.source_text, &mut self.writer) {
-			self.err = Some(err);
-		}
-		if highlight.is_some() {
-			if let Err(err) = self.writer.write_str(r#"</span>"#) {
+		let mut offset = span.start().0 as usize;
+		for highlight in parts {
+			let (start, end) = (highlight.span().start().0 as usize, highlight.span().end().0 as usize);
+			if let Err(err) = write!(
+				self.writer,
+				r#"{}<span class="{}{}">{}</span>"#,
+				&self.source_text[offset..start],
+				highlight.kind(),
+				highlight.modifier(),
+				&self.source_text[start..end]
+			) {
 				self.err = Some(err);
 			}
+			offset = end;
+		}
+		if let Err(err) = self.writer.write_str(&self.source_text[offset..span.end().0 as usize]) {
+			self.err = Some(err);
 		}
 	}
 }
@@ -26,3 +26,19 @@ fn test_nesting() {
 fn test_pseudo_classes() {
 	assert_highlight!("pseudo_classes", r#"body:focus,dialog:modal{}"#);
 }
+
+#[test]
+fn test_values() {
+	assert_highlight!(
+		"values",
+		r#"a{width:calc(100% - 2.5rem);z-index:2;--brand:red;color:var(--brand);background:url(a.png),url("b.png");content:"x"}"#
+	);
+}
+
+#[test]
+fn test_at_rules() {
+	assert_highlight!(
+		"at_rules",
+		r#"@media screen and (min-width: 600px) and (orientation: landscape){a{}}@container card (width > 40em){b{}}@layer base, theme;"#
+	);
+}
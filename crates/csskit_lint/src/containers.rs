@@ -0,0 +1,208 @@
+use css_ast::{ContainerRule, StyleRule, StyleSheet, Visit, Visitable};
+use css_lexer::{Span, ToSpan};
+
+use crate::{rules::value_text, text};
+
+/// The `container-type` of a style rule establishing a query container.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ContainerType {
+	/// A container which can only be used for style queries.
+	#[default]
+	Normal,
+	/// A container which can be queried for its inline size.
+	InlineSize,
+	/// A container which can be queried for its inline and block sizes.
+	Size,
+}
+
+impl ContainerType {
+	fn parse(value: &str) -> Self {
+		let mut container_type = Self::Normal;
+		for keyword in value.split_ascii_whitespace() {
+			if keyword.eq_ignore_ascii_case("size") {
+				container_type = Self::Size;
+			} else if keyword.eq_ignore_ascii_case("inline-size") {
+				container_type = Self::InlineSize;
+			}
+		}
+		container_type
+	}
+}
+
+/// A style rule which establishes a query container with `container`, `container-name` or `container-type`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContainerDeclaration {
+	/// The names given by `container-name` (or the `container` shorthand), which `@container` rules can query.
+	pub names: Vec<String>,
+	pub container_type: ContainerType,
+	/// The span of the style rule.
+	pub span: Span,
+}
+
+/// A condition of a `@container` rule, linked to the style rules which establish the containers it can query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContainerReference {
+	/// The container name the condition queries, if it has one.
+	pub name: Option<String>,
+	/// The span of the name, or the whole condition when it has no name.
+	pub span: Span,
+	/// Whether the condition queries the size of its container, which needs a size `container-type`.
+	pub size_query: bool,
+	/// Whether the condition queries the block axis of its container, which needs `container-type: size`.
+	pub block_axis: bool,
+	/// Indexes into [Containers::declarations] of the style rules establishing the containers this condition can query.
+	pub declarations: Vec<usize>,
+}
+
+impl ContainerReference {
+	/// Checks if the container type can answer this condition's query.
+	pub fn can_query(&self, container_type: ContainerType) -> bool {
+		match container_type {
+			ContainerType::Normal => !self.size_query,
+			ContainerType::InlineSize => !self.block_axis,
+			ContainerType::Size => true,
+		}
+	}
+}
+
+/// The query containers established by a stylesheet, and the `@container` conditions which query them.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Containers {
+	pub declarations: Vec<ContainerDeclaration>,
+	pub references: Vec<ContainerReference>,
+}
+
+/// A [Visit] pass which finds container declarations and `@container` conditions, before they are linked.
+#[derive(Default)]
+struct ContainerCollector<'s> {
+	source: &'s str,
+	containers: Containers,
+}
+
+impl Visit for ContainerCollector<'_> {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		let mut declaration: Option<ContainerDeclaration> = None;
+		// Later declarations in a block override earlier ones, and `container` resets both longhands.
+		for decl in &rule.0.block.declarations {
+			let name = text(self.source, decl.name.to_span()).to_ascii_lowercase();
+			let value = value_text(self.source, decl);
+			let value = match value.len().checked_sub(10) {
+				Some(i) if value.is_char_boundary(i) && value[i..].eq_ignore_ascii_case("!important") => {
+					value[..i].trim()
+				}
+				_ => value,
+			};
+			let (names, container_type) = match name.as_str() {
+				"container" => {
+					let (names, container_type) = value.split_once('/').unwrap_or((value, ""));
+					(Some(names), Some(container_type))
+				}
+				"container-name" => (Some(value), None),
+				"container-type" => (None, Some(value)),
+				_ => continue,
+			};
+			let declaration = declaration.get_or_insert_with(|| ContainerDeclaration {
+				names: vec![],
+				container_type: ContainerType::Normal,
+				span: rule.to_span(),
+			});
+			if let Some(names) = names {
+				declaration.names = names
+					.split_ascii_whitespace()
+					.filter(|name| !name.eq_ignore_ascii_case("none"))
+					.map(String::from)
+					.collect();
+			}
+			if let Some(container_type) = container_type {
+				declaration.container_type = ContainerType::parse(container_type);
+			}
+		}
+		self.containers.declarations.extend(declaration);
+	}
+
+	fn visit_container_rule<'a>(&mut self, rule: &ContainerRule<'a>) {
+		for (condition, _) in &rule.0.prelude.0 {
+			let query = condition.condition.as_ref();
+			self.containers.references.push(ContainerReference {
+				name: condition.name.map(|name| text(self.source, name.to_span()).into()),
+				span: condition.name.map_or(condition.to_span(), |name| name.to_span()),
+				size_query: query.is_some_and(|query| query.is_size_query()),
+				block_axis: query.is_some_and(|query| query.queries_block_axis()),
+				declarations: vec![],
+			});
+		}
+	}
+}
+
+/// Finds the style rules establishing query containers, and links each `@container` condition to the rules which
+/// establish a container it could query: those with its name, or for unnamed conditions those with a `container-type`.
+pub fn find_containers(source: &str, stylesheet: &StyleSheet) -> Containers {
+	let mut collector = ContainerCollector { source, containers: Containers::default() };
+	stylesheet.accept(&mut collector);
+	let Containers { declarations, mut references } = collector.containers;
+	for reference in &mut references {
+		reference.declarations = declarations
+			.iter()
+			.enumerate()
+			.filter(|(_, declaration)| match &reference.name {
+				Some(name) => declaration.names.contains(name),
+				None => declaration.container_type != ContainerType::Normal,
+			})
+			.map(|(i, _)| i)
+			.collect();
+	}
+	Containers { declarations, references }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_parse::Parser;
+
+	#[test]
+	fn test_find_containers() {
+		let bump = Bump::default();
+		let source = ".a{container:sidebar main / inline-size}.b{container-name:card;container-type:size!important}.c{container-type:size;container-name:none}.d{color:red}@container sidebar (width>1px){}@container card (height>1px), (width>1px){}@container missing{}";
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let containers = find_containers(source, result.output.as_ref().unwrap());
+		let declarations: Vec<_> = containers
+			.declarations
+			.iter()
+			.map(|declaration| (declaration.names.clone(), declaration.container_type, text(source, declaration.span)))
+			.collect();
+		assert_eq!(
+			declarations,
+			vec![
+				(
+					vec!["sidebar".into(), "main".into()],
+					ContainerType::InlineSize,
+					".a{container:sidebar main / inline-size}"
+				),
+				(vec!["card".into()], ContainerType::Size, ".b{container-name:card;container-type:size!important}"),
+				(vec![], ContainerType::Size, ".c{container-type:size;container-name:none}"),
+			]
+		);
+		let references: Vec<_> = containers
+			.references
+			.iter()
+			.map(|reference| {
+				(
+					text(source, reference.span),
+					reference.size_query,
+					reference.block_axis,
+					reference.declarations.clone(),
+				)
+			})
+			.collect();
+		assert_eq!(
+			references,
+			vec![
+				("sidebar", true, false, vec![0]),
+				("card", true, true, vec![1]),
+				("(width>1px)", true, false, vec![0, 1, 2]),
+				("missing", false, false, vec![]),
+			]
+		);
+	}
+}
@@ -0,0 +1,146 @@
+#![deny(warnings)]
+//! Lint rules which check a parsed [StyleSheet] for problems, each [Lint] optionally carrying a [Fix] which can be
+//! applied automatically (for example by `csskit check --fix`, or as a quick fix in an editor), alongside source actions
+//! which rewrite a whole stylesheet, such as sorting declarations.
+use css_ast::{BrowserTarget, StyleSheet};
+use css_lexer::Span;
+use miette::{Diagnostic, LabeledSpan, Severity};
+use std::{fmt::Display, path::Path};
+use strum::{Display, EnumString, VariantNames};
+use thiserror::Error;
+
+mod containers;
+mod logical;
+mod rules;
+mod sort;
+mod urls;
+
+pub use containers::*;
+pub use css_parse::{Edit, apply_edits};
+pub use logical::*;
+pub use sort::*;
+pub use urls::*;
+
+pub(crate) use css_parse::text;
+use rules::*;
+
+/// Each of the rules which can produce a [Lint].
+#[derive(Display, EnumString, VariantNames, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Rule {
+	/// A property which has been renamed, for example `word-wrap` (now `overflow-wrap`).
+	DeprecatedProperty,
+	/// A pseudo-element using the single colon syntax, for example `:before` (rather than `::before`).
+	LegacyPseudoElement,
+	/// A declaration which is identical to an earlier one in the same block.
+	DuplicateDeclaration,
+	/// A vendor prefixed property, such as `-webkit-user-select`, without its standard counterpart in the same block.
+	MissingUnprefixedProperty,
+	/// A `url()` or `@import` which refers to a local file that does not exist.
+	MissingFile,
+	/// A `@container` condition which queries a container name that is never declared.
+	UndeclaredContainer,
+	/// A `@container` size query against a container whose `container-type` cannot answer it.
+	ContainerTypeMismatch,
+	/// A property, value, selector or at-rule which at least one of the browser targets doesn't support.
+	UnsupportedFeature,
+}
+
+impl Rule {
+	/// The [Severity] lints of this rule are reported with.
+	pub fn severity(&self) -> Severity {
+		match self {
+			Self::LegacyPseudoElement => Severity::Advice,
+			Self::MissingFile => Severity::Error,
+			Self::DeprecatedProperty
+			| Self::DuplicateDeclaration
+			| Self::MissingUnprefixedProperty
+			| Self::UndeclaredContainer
+			| Self::ContainerTypeMismatch
+			| Self::UnsupportedFeature => Severity::Warning,
+		}
+	}
+}
+
+/// A set of [Edits][Edit] which resolve a [Lint], along with a short title describing them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fix {
+	pub title: String,
+	pub edits: Vec<Edit>,
+}
+
+/// A problem found within a stylesheet by a [Rule].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct Lint {
+	pub rule: Rule,
+	/// The [Severity] this is reported with, which starts as the [Rule's severity][Rule::severity] but may be
+	/// overridden by configuration.
+	pub severity: Severity,
+	pub message: String,
+	pub span: Span,
+	pub fix: Option<Fix>,
+}
+
+impl Lint {
+	pub fn new(rule: Rule, message: impl Into<String>, span: Span) -> Self {
+		Self { rule, severity: rule.severity(), message: message.into(), span, fix: None }
+	}
+
+	pub fn with_fix(mut self, title: impl Into<String>, edits: Vec<Edit>) -> Self {
+		self.fix = Some(Fix { title: title.into(), edits });
+		self
+	}
+}
+
+impl Diagnostic for Lint {
+	fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+		Some(Box::new(format!("csskit_lint::{}", self.rule)))
+	}
+
+	fn severity(&self) -> Option<Severity> {
+		Some(self.severity)
+	}
+
+	fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+		self.fix.as_ref().map(|fix| Box::new(&fix.title) as Box<dyn Display>)
+	}
+
+	fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+		Some(Box::new(std::iter::once(LabeledSpan::underline(self.span))))
+	}
+}
+
+/// Runs every [Rule] over the stylesheet, returning the [Lints][Lint] in source order.
+pub fn lint(source: &str, stylesheet: &StyleSheet) -> Vec<Lint> {
+	let mut lints = vec![];
+	lints.extend(DeprecatedProperty::run(source, stylesheet));
+	lints.extend(LegacyPseudoElement::run(source, stylesheet));
+	lints.extend(DuplicateDeclaration::run(source, stylesheet));
+	lints.extend(MissingUnprefixedProperty::run(source, stylesheet));
+	lints.extend(UndeclaredContainer::run(source, stylesheet));
+	lints.extend(ContainerTypeMismatch::run(source, stylesheet));
+	lints.sort_by_key(|lint| (lint.span.start(), lint.span.end()));
+	lints
+}
+
+/// Runs every [Rule] over the stylesheet at the given path, including those which check the files it refers to.
+pub fn lint_file(source: &str, stylesheet: &StyleSheet, path: &Path) -> Vec<Lint> {
+	let mut lints = lint(source, stylesheet);
+	if let Some(dir) = path.parent() {
+		lints.extend(MissingFile::run(source, stylesheet, dir));
+		lints.sort_by_key(|lint| (lint.span.start(), lint.span.end()));
+	}
+	lints
+}
+
+/// Runs the [Rules][Rule] which check the stylesheet against the browser targets it should work in, returning the
+/// [Lints][Lint] in source order.
+pub fn lint_targets(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Lint> {
+	let mut lints = UnsupportedFeature::run(source, stylesheet, targets);
+	lints.sort_by_key(|lint| (lint.span.start(), lint.span.end()));
+	lints
+}
+
+#[cfg(test)]
+mod test_helpers;
@@ -0,0 +1,55 @@
+use css_ast::{StyleSheet, Visit, Visitable, to_logical_property};
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{Declaration, DeclarationValue};
+
+use crate::{Edit, text};
+
+/// Returns the [Edits][Edit] which convert each physical property (such as `margin-left`) into its flow-relative
+/// equivalent (`margin-inline-start`), along with the `left` & `right` values of `text-align`.
+pub fn physical_to_logical(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+	let mut visitor = PhysicalToLogical { source, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct PhysicalToLogical<'s> {
+	source: &'s str,
+	edits: Vec<Edit>,
+}
+
+impl Visit for PhysicalToLogical<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		let name_span = declaration.name.to_span();
+		let name = text(self.source, name_span);
+		if let Some(logical) = to_logical_property(name) {
+			self.edits.push(Edit::new(name_span, logical));
+		} else if name.eq_ignore_ascii_case("text-align") {
+			let span = declaration.value.to_span();
+			let value = text(self.source, span).trim();
+			let logical = match value.to_ascii_lowercase().as_str() {
+				"left" => "start",
+				"right" => "end",
+				_ => return,
+			};
+			self.edits
+				.push(Edit::new(Span::new(span.start(), SourceOffset(span.start().0 + value.len() as u32)), logical));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_action;
+
+	#[test]
+	fn test_physical_to_logical() {
+		assert_action!(
+			physical_to_logical,
+			"a{width:1px;margin-left:0;border-top-left-radius:2px;text-align:left;margin:0;--left:1px}",
+			"a{inline-size:1px;margin-inline-start:0;border-start-start-radius:2px;text-align:start;margin:0;--left:1px}"
+		);
+		assert_action!(physical_to_logical, "a{text-align:center}", "a{text-align:center}");
+	}
+}
@@ -0,0 +1,59 @@
+use css_ast::StyleSheet;
+
+use crate::{ContainerType, Lint, Rule, find_containers};
+
+/// Finds `@container` conditions which query the size of a named container, where none of the style rules declaring
+/// that name give it a `container-type` able to answer the query: size queries need `inline-size` or `size`, and
+/// queries of the block axis (such as `height`) need `size`.
+pub(crate) struct ContainerTypeMismatch;
+
+impl ContainerTypeMismatch {
+	pub fn run(source: &str, stylesheet: &StyleSheet) -> Vec<Lint> {
+		let containers = find_containers(source, stylesheet);
+		let mut lints = vec![];
+		for reference in &containers.references {
+			let Some(name) = &reference.name else { continue };
+			let types: Vec<ContainerType> =
+				reference.declarations.iter().map(|&i| containers.declarations[i].container_type).collect();
+			if types.is_empty() || types.iter().any(|&container_type| reference.can_query(container_type)) {
+				continue;
+			}
+			let message = if types.contains(&ContainerType::InlineSize) {
+				format!(
+					"`{name}` containers have `container-type: inline-size`, so queries of their block size never match"
+				)
+			} else {
+				format!("`{name}` containers have no size `container-type`, so size queries against them never match")
+			};
+			lints.push(Lint::new(Rule::ContainerTypeMismatch, message, reference.span));
+		}
+		lints
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_container_type_mismatch() {
+		assert_lint!(
+			ContainerTypeMismatch,
+			".a{container-name:card}@container card (width>1px){}@container card{}",
+			["`card` containers have no size `container-type`, so size queries against them never match"],
+			".a{container-name:card}@container card (width>1px){}@container card{}"
+		);
+		assert_lint!(
+			ContainerTypeMismatch,
+			".a{container:card / inline-size}@container card (width>1px){}@container card (orientation:portrait){}",
+			["`card` containers have `container-type: inline-size`, so queries of their block size never match"],
+			".a{container:card / inline-size}@container card (width>1px){}@container card (orientation:portrait){}"
+		);
+		assert_lint!(
+			ContainerTypeMismatch,
+			".a{container:card}.b{container:card / size}@container card (height>1px){}@container (height>1px){}",
+			[],
+			".a{container:card}.b{container:card / size}@container card (height>1px){}@container (height>1px){}"
+		);
+	}
+}
@@ -0,0 +1,63 @@
+use css_ast::{StyleSheet, Visit, Visitable};
+use css_lexer::ToSpan;
+use css_parse::{Declaration, DeclarationValue};
+
+use crate::{Edit, Lint, Rule, text};
+
+// Properties which were renamed, and can be replaced without changing their values.
+const DEPRECATED: &[(&str, &str)] = &[
+	("word-wrap", "overflow-wrap"),
+	("grid-gap", "gap"),
+	("grid-row-gap", "row-gap"),
+	("grid-column-gap", "column-gap"),
+	("color-adjust", "print-color-adjust"),
+];
+
+/// Finds declarations of a property with a newer name, such as `word-wrap` (which is now `overflow-wrap`).
+#[derive(Default)]
+pub(crate) struct DeprecatedProperty<'s> {
+	source: &'s str,
+	lints: Vec<Lint>,
+}
+
+impl<'s> DeprecatedProperty<'s> {
+	pub fn run(source: &'s str, stylesheet: &StyleSheet) -> Vec<Lint> {
+		let mut rule = Self { source, lints: vec![] };
+		stylesheet.accept(&mut rule);
+		rule.lints
+	}
+}
+
+impl Visit for DeprecatedProperty<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		let span = declaration.name.to_span();
+		let name = text(self.source, span);
+		if let Some((_, replacement)) = DEPRECATED.iter().find(|(deprecated, _)| name.eq_ignore_ascii_case(deprecated))
+		{
+			self.lints.push(
+				Lint::new(
+					Rule::DeprecatedProperty,
+					format!("`{name}` is deprecated, use `{replacement}` instead"),
+					span,
+				)
+				.with_fix(format!("Replace `{name}` with `{replacement}`"), vec![Edit::new(span, *replacement)]),
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_deprecated_property() {
+		assert_lint!(
+			DeprecatedProperty,
+			"a{word-wrap:break-word;grid-gap:1px 2px}",
+			["`word-wrap` is deprecated, use `overflow-wrap` instead", "`grid-gap` is deprecated, use `gap` instead"],
+			"a{overflow-wrap:break-word;gap:1px 2px}"
+		);
+		assert_lint!(DeprecatedProperty, "a{overflow-wrap:anywhere}", [], "a{overflow-wrap:anywhere}");
+	}
+}
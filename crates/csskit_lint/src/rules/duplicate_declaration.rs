@@ -0,0 +1,74 @@
+use css_ast::{StyleSheet, Visit, Visitable};
+use css_lexer::{Span, ToSpan};
+use css_parse::{Block, DeclarationValue, Parse, ToCursors};
+
+use super::{leading_whitespace, value_text};
+use crate::{Edit, Lint, Rule, text};
+
+/// Finds declarations which are identical to an earlier declaration in the same block, and so have no effect. Repeated
+/// properties with differing values are left alone, as they're commonly used as fallbacks.
+#[derive(Default)]
+pub(crate) struct DuplicateDeclaration<'s> {
+	source: &'s str,
+	lints: Vec<Lint>,
+}
+
+impl<'s> DuplicateDeclaration<'s> {
+	pub fn run(source: &'s str, stylesheet: &StyleSheet) -> Vec<Lint> {
+		let mut rule = Self { source, lints: vec![] };
+		stylesheet.accept(&mut rule);
+		rule.lints
+	}
+}
+
+impl Visit for DuplicateDeclaration<'_> {
+	fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(&mut self, block: &Block<'a, D, R>) {
+		let mut seen: Vec<(String, &str)> = vec![];
+		for declaration in &block.declarations {
+			let name = text(self.source, declaration.name.to_span()).to_ascii_lowercase();
+			let key = (name, value_text(self.source, declaration));
+			if !seen.contains(&key) {
+				seen.push(key);
+				continue;
+			}
+			let span = declaration.to_span();
+			// Removing the whitespace before the declaration keeps the formatting of the remaining declarations intact.
+			let removal = Span::new(leading_whitespace(self.source, span.start()).start(), span.end());
+			self.lints.push(
+				Lint::new(
+					Rule::DuplicateDeclaration,
+					format!("`{}: {}` duplicates an earlier declaration", key.0, key.1),
+					span,
+				)
+				.with_fix("Remove duplicate declaration", vec![Edit::new(removal, "")]),
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_duplicate_declaration() {
+		assert_lint!(
+			DuplicateDeclaration,
+			"a {\n\tcolor: red;\n\twidth: 1px;\n\tcolor: red;\n}",
+			["`color: red` duplicates an earlier declaration"],
+			"a {\n\tcolor: red;\n\twidth: 1px;\n}"
+		);
+		assert_lint!(
+			DuplicateDeclaration,
+			"a{display:flex;display:grid;COLOR:red;color:red!important}b{color:red}",
+			[],
+			"a{display:flex;display:grid;COLOR:red;color:red!important}b{color:red}"
+		);
+		assert_lint!(
+			DuplicateDeclaration,
+			"a{width:1px;&:hover{width:1px; width:1px}}",
+			["`width: 1px` duplicates an earlier declaration"],
+			"a{width:1px;&:hover{width:1px;}}"
+		);
+	}
+}
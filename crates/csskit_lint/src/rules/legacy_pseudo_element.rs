@@ -0,0 +1,54 @@
+use css_ast::{StyleSheet, Visit, Visitable};
+use css_lexer::{Span, ToSpan};
+
+use crate::{Edit, Lint, Rule, text};
+
+/// Finds pseudo-elements which use the single colon syntax from CSS2, such as `:before`, which should be written with
+/// a double colon like every other pseudo-element.
+#[derive(Default)]
+pub(crate) struct LegacyPseudoElement<'s> {
+	source: &'s str,
+	lints: Vec<Lint>,
+}
+
+impl<'s> LegacyPseudoElement<'s> {
+	pub fn run(source: &'s str, stylesheet: &StyleSheet) -> Vec<Lint> {
+		let mut rule = Self { source, lints: vec![] };
+		stylesheet.accept(&mut rule);
+		rule.lints
+	}
+}
+
+impl Visit for LegacyPseudoElement<'_> {
+	fn visit_legacy_pseudo_element(&mut self, pseudo: &css_ast::LegacyPseudoElement) {
+		let span = pseudo.to_span();
+		let legacy = text(self.source, span);
+		self.lints.push(
+			Lint::new(
+				Rule::LegacyPseudoElement,
+				format!("`{legacy}` uses the legacy single colon syntax, use `:{legacy}` instead"),
+				span,
+			)
+			.with_fix(format!("Convert to `:{legacy}`"), vec![Edit::new(Span::new(span.start(), span.start()), ":")]),
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_legacy_pseudo_element() {
+		assert_lint!(
+			LegacyPseudoElement,
+			"a:before,b:first-line{color:red}",
+			[
+				"`:before` uses the legacy single colon syntax, use `::before` instead",
+				"`:first-line` uses the legacy single colon syntax, use `::first-line` instead"
+			],
+			"a::before,b::first-line{color:red}"
+		);
+		assert_lint!(LegacyPseudoElement, "a::after:hover{}", [], "a::after:hover{}");
+	}
+}
@@ -0,0 +1,39 @@
+use css_ast::StyleSheet;
+use std::path::Path;
+
+use crate::{Lint, Rule, find_urls};
+
+/// Finds `url()`s and `@import`s which refer to a file relative to the stylesheet which does not exist. This needs to
+/// know where the stylesheet is, so it is only run by [lint_file][crate::lint_file].
+pub(crate) struct MissingFile;
+
+impl MissingFile {
+	pub fn run(source: &str, stylesheet: &StyleSheet, dir: &Path) -> Vec<Lint> {
+		find_urls(source, stylesheet)
+			.into_iter()
+			.filter(|url| url.resolve(dir).is_some_and(|path| !path.exists()))
+			.map(|url| Lint::new(Rule::MissingFile, format!("`{}` does not exist", url.href), url.span))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_parse::Parser;
+
+	#[test]
+	fn test_missing_file() {
+		let dir = std::env::temp_dir().join(format!("csskit_lint_missing_file_{}", std::process::id()));
+		std::fs::create_dir_all(dir.join("img")).unwrap();
+		std::fs::write(dir.join("img/a.png"), "").unwrap();
+		let source = "@import 'b.css';a{background:url(img/a.png),url(img/b.png),url(https://example.com/c.png)}";
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let lints = MissingFile::run(source, result.output.as_ref().unwrap(), &dir);
+		std::fs::remove_dir_all(&dir).unwrap();
+		let messages: Vec<_> = lints.iter().map(|lint| lint.message.as_str()).collect();
+		assert_eq!(messages, vec!["`b.css` does not exist", "`img/b.png` does not exist"]);
+	}
+}
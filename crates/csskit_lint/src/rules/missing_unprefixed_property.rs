@@ -0,0 +1,91 @@
+use css_ast::{PropertyId, StyleSheet, Visit, Visitable};
+use css_lexer::{Span, ToSpan};
+use css_parse::{Block, DeclarationValue, Parse, ToCursors};
+
+use super::{leading_whitespace, value_text};
+use crate::{Edit, Lint, Rule, text};
+
+const VENDOR_PREFIXES: &[&str] = &["-webkit-", "-moz-", "-ms-", "-o-"];
+
+/// Finds vendor prefixed declarations, such as `-webkit-user-select`, where the block does not also declare the
+/// standard property. Only prefixed properties which have a known standard counterpart are checked.
+#[derive(Default)]
+pub(crate) struct MissingUnprefixedProperty<'s> {
+	source: &'s str,
+	lints: Vec<Lint>,
+}
+
+impl<'s> MissingUnprefixedProperty<'s> {
+	pub fn run(source: &'s str, stylesheet: &StyleSheet) -> Vec<Lint> {
+		let mut rule = Self { source, lints: vec![] };
+		stylesheet.accept(&mut rule);
+		rule.lints
+	}
+}
+
+impl Visit for MissingUnprefixedProperty<'_> {
+	fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(&mut self, block: &Block<'a, D, R>) {
+		let names: Vec<String> =
+			block.declarations.iter().map(|d| text(self.source, d.name.to_span()).to_ascii_lowercase()).collect();
+		for (i, declaration) in block.declarations.iter().enumerate() {
+			let Some(unprefixed) = VENDOR_PREFIXES.iter().find_map(|prefix| names[i].strip_prefix(prefix)) else {
+				continue;
+			};
+			if PropertyId::from_name(unprefixed).is_none() || names.iter().any(|name| name == unprefixed) {
+				continue;
+			}
+			// Where there are several prefixed versions (e.g. -webkit- & -moz-) only the last is reported, so that the
+			// standard property is added after all of them.
+			if names[i + 1..]
+				.iter()
+				.any(|name| VENDOR_PREFIXES.iter().any(|p| name.strip_prefix(p) == Some(unprefixed)))
+			{
+				continue;
+			}
+			let span = declaration.to_span();
+			let value = value_text(self.source, declaration);
+			let whitespace = text(self.source, leading_whitespace(self.source, span.start()));
+			let whitespace = if whitespace.is_empty() { " " } else { whitespace };
+			let insert = if declaration.semicolon.is_some() {
+				format!("{whitespace}{unprefixed}: {value};")
+			} else {
+				format!(";{whitespace}{unprefixed}: {value}")
+			};
+			self.lints.push(
+				Lint::new(
+					Rule::MissingUnprefixedProperty,
+					format!("`{}` is missing the standard `{unprefixed}` property", names[i]),
+					span,
+				)
+				.with_fix(format!("Add `{unprefixed}`"), vec![Edit::new(Span::new(span.end(), span.end()), insert)]),
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_missing_unprefixed_property() {
+		assert_lint!(
+			MissingUnprefixedProperty,
+			"a {\n\t-webkit-user-select: none;\n\t-moz-user-select: none;\n\tcolor: red;\n}",
+			["`-moz-user-select` is missing the standard `user-select` property"],
+			"a {\n\t-webkit-user-select: none;\n\t-moz-user-select: none;\n\tuser-select: none;\n\tcolor: red;\n}"
+		);
+		assert_lint!(
+			MissingUnprefixedProperty,
+			"a{-webkit-appearance:none!important}",
+			["`-webkit-appearance` is missing the standard `appearance` property"],
+			"a{-webkit-appearance:none!important; appearance: none!important}"
+		);
+		assert_lint!(
+			MissingUnprefixedProperty,
+			"a{-webkit-user-select:none;user-select:none;-webkit-font-smoothing:auto}",
+			[],
+			"a{-webkit-user-select:none;user-select:none;-webkit-font-smoothing:auto}"
+		);
+	}
+}
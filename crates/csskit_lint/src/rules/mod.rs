@@ -0,0 +1,38 @@
+mod container_type_mismatch;
+mod deprecated_property;
+mod duplicate_declaration;
+mod legacy_pseudo_element;
+mod missing_file;
+mod missing_unprefixed_property;
+mod undeclared_container;
+mod unsupported_feature;
+
+pub(crate) use container_type_mismatch::*;
+pub(crate) use deprecated_property::*;
+pub(crate) use duplicate_declaration::*;
+pub(crate) use legacy_pseudo_element::*;
+pub(crate) use missing_file::*;
+pub(crate) use missing_unprefixed_property::*;
+pub(crate) use undeclared_container::*;
+pub(crate) use unsupported_feature::*;
+
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{Declaration, DeclarationValue};
+
+use crate::text;
+
+/// Returns the [Span] of the whitespace which precedes `offset`, so that edits can remove or copy the indentation of a
+/// declaration.
+pub(crate) fn leading_whitespace(source: &str, offset: SourceOffset) -> Span {
+	let trimmed = source[..offset.0 as usize].trim_end_matches(|c: char| c.is_ascii_whitespace());
+	Span::new(SourceOffset(trimmed.len() as u32), offset)
+}
+
+/// Returns the text of a declaration's value, including any `!important`, but not the trailing semicolon.
+pub(crate) fn value_text<'s, 'a, T: DeclarationValue<'a>>(
+	source: &'s str,
+	declaration: &Declaration<'a, T>,
+) -> &'s str {
+	let end = declaration.semicolon.map_or(declaration.to_span().end(), |semicolon| semicolon.to_span().start());
+	text(source, Span::new(declaration.value.to_span().start(), end)).trim()
+}
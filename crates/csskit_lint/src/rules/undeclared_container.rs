@@ -0,0 +1,46 @@
+use css_ast::StyleSheet;
+
+use crate::{Lint, Rule, find_containers};
+
+/// Finds `@container` conditions which query a container name that no style rule in the stylesheet declares with
+/// `container-name` (or the `container` shorthand), and so can never match.
+pub(crate) struct UndeclaredContainer;
+
+impl UndeclaredContainer {
+	pub fn run(source: &str, stylesheet: &StyleSheet) -> Vec<Lint> {
+		find_containers(source, stylesheet)
+			.references
+			.into_iter()
+			.filter(|reference| reference.declarations.is_empty())
+			.filter_map(|reference| {
+				let name = reference.name?;
+				Some(Lint::new(
+					Rule::UndeclaredContainer,
+					format!("`@container {name}` queries a container name which is never declared"),
+					reference.span,
+				))
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_undeclared_container() {
+		assert_lint!(
+			UndeclaredContainer,
+			".a{container-name:card}@container card (width>1px){}@container sidebar (width>1px){}@container (width>1px){}",
+			["`@container sidebar` queries a container name which is never declared"],
+			".a{container-name:card}@container card (width>1px){}@container sidebar (width>1px){}@container (width>1px){}"
+		);
+		assert_lint!(
+			UndeclaredContainer,
+			".a{container:card / size}.b{container-name:none}@container card{}@container none{}",
+			[],
+			".a{container:card / size}.b{container-name:none}@container card{}@container none{}"
+		);
+	}
+}
@@ -0,0 +1,215 @@
+use css_ast::{
+	BrowserTarget, Combinator, CompoundSelector, SelectorComponent, StyleSheet, Support, SupportsRule, Visit,
+	Visitable, at_rule_support, function_support, property_support, selector_support, value_functions, value_support,
+};
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{AtRule, Block, DeclarationValue, Parse, Peek, ToCursors, token_macros};
+
+use crate::{Lint, Rule, text};
+
+const VENDOR_PREFIXES: &[&str] = &["-webkit-", "-moz-", "-ms-", "-o-"];
+
+/// Finds properties, values, functions, selectors and at-rules which at least one of the browser targets doesn't
+/// support. Usage is not reported for targets where an enclosing `@supports` rule is false (or unknown), nor where an
+/// earlier declaration of the same property in the block provides a fallback.
+#[derive(Default)]
+pub(crate) struct UnsupportedFeature<'s> {
+	source: &'s str,
+	targets: &'s [BrowserTarget],
+	// The span of each `@supports` rule and its prelude, along with the condition's result in each target.
+	supports: Vec<(Span, Span, Vec<Option<bool>>)>,
+	lints: Vec<Lint>,
+}
+
+impl<'s> UnsupportedFeature<'s> {
+	pub fn run(source: &'s str, stylesheet: &StyleSheet, targets: &'s [BrowserTarget]) -> Vec<Lint> {
+		let mut rule = Self { source, targets, supports: vec![], lints: vec![] };
+		stylesheet.accept(&mut rule);
+		rule.lints
+	}
+
+	// Returns true if the target (by index) is excluded by an `@supports` rule which the span is within.
+	fn guarded(&self, target: usize, span: Span) -> bool {
+		self.supports.iter().any(|(rule, _, results)| rule.contains(span) && results[target] != Some(true))
+	}
+
+	// Reports the feature if any target which can reach it doesn't support it. Unless the support is `exhaustive`,
+	// browsers which it has no version for are unknown rather than unsupported. Returns true if it was reported.
+	fn check(&mut self, feature: &str, span: Span, support: Support, exhaustive: bool) -> bool {
+		// The conditions of `@supports` rules test for features, rather than use them.
+		if self.supports.iter().any(|(_, prelude, _)| prelude.contains(span)) {
+			return false;
+		}
+		let unsupported: Vec<String> = self
+			.targets
+			.iter()
+			.enumerate()
+			.filter(|(i, target)| {
+				!support.supports(target)
+					&& (exhaustive || support.since(target.browser).is_some())
+					&& !self.guarded(*i, span)
+			})
+			.map(|(_, target)| match support.since(target.browser) {
+				Some(since) => format!("{target} (supported from {since})"),
+				None => target.to_string(),
+			})
+			.collect();
+		if unsupported.is_empty() {
+			return false;
+		}
+		self.lints.push(Lint::new(
+			Rule::UnsupportedFeature,
+			format!("`{feature}` is not supported by {}", unsupported.join(", ")),
+			span,
+		));
+		true
+	}
+}
+
+impl Visit for UnsupportedFeature<'_> {
+	fn visit_supports_rule<'a>(&mut self, rule: &SupportsRule<'a>) {
+		let results = self.targets.iter().map(|target| rule.0.prelude.evaluate_target(self.source, target)).collect();
+		self.supports.push((rule.to_span(), rule.0.prelude.to_span(), results));
+	}
+
+	fn visit_at_rule<'a, AT, P, B>(&mut self, rule: &AtRule<'a, AT, P, B>)
+	where
+		AT: Peek<'a> + Parse<'a> + Into<token_macros::AtKeyword>,
+		P: Parse<'a> + ToCursors + ToSpan,
+		B: Parse<'a> + ToCursors + ToSpan,
+	{
+		let span = rule.to_span();
+		let rule_text = text(self.source, span);
+		let len =
+			rule_text[1..].find(|c: char| !c.is_ascii_alphanumeric() && c != '-').map_or(rule_text.len(), |i| i + 1);
+		let name = rule_text[..len].to_ascii_lowercase();
+		if let Some(support) = at_rule_support(&name) {
+			self.check(&name, Span::new(span.start(), SourceOffset(span.start().0 + len as u32)), support, true);
+		}
+	}
+
+	fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(&mut self, block: &Block<'a, D, R>) {
+		let names: Vec<String> =
+			block.declarations.iter().map(|d| text(self.source, d.name.to_span()).to_ascii_lowercase()).collect();
+		for (i, declaration) in block.declarations.iter().enumerate() {
+			let name = &names[i];
+			// Custom properties are only used through `var()`, which is checked where they are used.
+			if name.starts_with("--") {
+				continue;
+			}
+			// An earlier declaration of the property (or a vendor prefixed version of it) is a fallback for browsers
+			// which don't support this one.
+			if names[..i].iter().any(|earlier| {
+				earlier == name || VENDOR_PREFIXES.iter().any(|prefix| earlier.strip_prefix(prefix) == Some(name))
+			}) {
+				continue;
+			}
+			let name_span = declaration.name.to_span();
+			// The generated compat data only lists the browsers which support a property, so browsers which aren't listed
+			// are unknown.
+			let reported = property_support(name).is_some_and(|support| self.check(name, name_span, support, false));
+			// Values of an unsupported property would be reported for the same targets.
+			if reported {
+				continue;
+			}
+			let value_span = declaration.value.to_span();
+			let value = text(self.source, value_span).to_ascii_lowercase();
+			let value = value.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+			// Vendor prefixed values (such as `-webkit-sticky`) are only ever fallbacks for some browsers.
+			if let Some(support) = value_support(name, &value).filter(|_| !value.starts_with('-')) {
+				self.check(&format!("{name}: {value}"), value_span, support, true);
+			}
+			let mut functions: Vec<&str> = value_functions(&value).collect();
+			functions.dedup();
+			for function in functions {
+				if let Some(support) = function_support(function) {
+					self.check(&format!("{function}()"), value_span, support, true);
+				}
+			}
+		}
+	}
+
+	fn visit_compound_selector<'a>(&mut self, compound: &CompoundSelector<'a>) {
+		for component in &compound.0 {
+			let (span, functional) = match component {
+				SelectorComponent::Combinator(Combinator::Nesting(_) | Combinator::Column(_)) => {
+					(component.to_span(), false)
+				}
+				SelectorComponent::PseudoClass(_) | SelectorComponent::PseudoElement(_) => (component.to_span(), false),
+				SelectorComponent::FunctionalPseudoClass(_) | SelectorComponent::FunctionalPseudoElement(_) => {
+					(component.to_span(), true)
+				}
+				_ => continue,
+			};
+			let selector = text(self.source, span).trim();
+			let name = selector[..selector.find('(').unwrap_or(selector.len())].to_ascii_lowercase();
+			if let Some(support) = selector_support(&name) {
+				let name = if functional { format!("{name}()") } else { name };
+				let span = Span::new(span.start(), SourceOffset(span.start().0 + name.len() as u32));
+				self.check(&name, span, support, true);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::assert_lint;
+
+	#[test]
+	fn test_unsupported_feature() {
+		assert_lint!(
+			UnsupportedFeature,
+			"a {\n\tcontainer-name: card;\n\toverflow: clip;\n\tcolor: oklch(50% 0.1 30);\n}",
+			[
+				"`container-name` is not supported by chrome 100 (supported from 105), safari 15.4 (supported from 16)",
+				"`overflow: clip` is not supported by safari 15.4 (supported from 16)",
+				"`oklch()` is not supported by chrome 100 (supported from 111)"
+			],
+			"a {\n\tcontainer-name: card;\n\toverflow: clip;\n\tcolor: oklch(50% 0.1 30);\n}"
+		);
+		assert_lint!(
+			UnsupportedFeature,
+			"@container (width > 1px) { a:has(b), ::backdrop, :nth-col(2) { text-wrap: pretty } }",
+			[
+				"`@container` is not supported by chrome 100 (supported from 105), safari 15.4 (supported from 16)",
+				"`:has()` is not supported by chrome 100 (supported from 105), firefox 115 (supported from 121)",
+				"`:nth-col()` is not supported by chrome 100, firefox 115, safari 15.4",
+				"`text-wrap` is not supported by chrome 100 (supported from 114), firefox 115 (supported from 124), safari 15.4 (supported from 17.4)"
+			],
+			"@container (width > 1px) { a:has(b), ::backdrop, :nth-col(2) { text-wrap: pretty } }"
+		);
+	}
+
+	#[test]
+	fn test_nested_selector() {
+		assert_lint!(
+			UnsupportedFeature,
+			":is(a:has(b)) {}",
+			["`:has()` is not supported by chrome 100 (supported from 105), firefox 115 (supported from 121)"],
+			":is(a:has(b)) {}"
+		);
+	}
+
+	#[test]
+	fn test_guarded_feature() {
+		assert_lint!(
+			UnsupportedFeature,
+			"a { color: red; color: oklch(50% 0.1 30); position: -webkit-sticky; position: sticky }",
+			[],
+			"a { color: red; color: oklch(50% 0.1 30); position: -webkit-sticky; position: sticky }"
+		);
+		assert_lint!(
+			UnsupportedFeature,
+			"@supports selector(:has(a)) { a:has(b) { container-name: card } }",
+			["`container-name` is not supported by safari 15.4 (supported from 16)"],
+			"@supports selector(:has(a)) { a:has(b) { container-name: card } }"
+		);
+		assert_lint!(
+			UnsupportedFeature,
+			"@supports (container-name: card) { a { container-name: card } @container (width > 1px) {} }",
+			[],
+			"@supports (container-name: card) { a { container-name: card } @container (width > 1px) {} }"
+		);
+	}
+}
@@ -0,0 +1,312 @@
+use css_ast::{StyleSheet, Visit, Visitable, to_physical_property};
+use css_lexer::{Span, ToSpan};
+use css_parse::{Block, DeclarationValue, Parse, ToCursors};
+
+use crate::{Edit, text};
+
+/// Returns the [Edits][Edit] which sort the declarations of each block: custom properties first (in their original
+/// order), followed by the remaining properties alphabetically, with vendor prefixed properties placed just before their
+/// standard counterpart.
+///
+/// Blocks where sorting could change the computed style, such as a longhand which precedes its shorthand, or where
+/// declarations are interleaved with nested rules, are left as is.
+pub fn sort_declarations(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+	let mut visitor = SortDeclarations { source, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct SortDeclarations<'s> {
+	source: &'s str,
+	edits: Vec<Edit>,
+}
+
+// Each shorthand property, along with the properties it sets. Longhands which are shorthands themselves are expanded
+// further, so only the direct longhands are listed.
+const SHORTHANDS: &[(&str, &[&str])] = &[
+	(
+		"animation",
+		&[
+			"animation-name",
+			"animation-duration",
+			"animation-timing-function",
+			"animation-delay",
+			"animation-iteration-count",
+			"animation-direction",
+			"animation-fill-mode",
+			"animation-play-state",
+			"animation-timeline",
+		],
+	),
+	(
+		"background",
+		&[
+			"background-color",
+			"background-image",
+			"background-position",
+			"background-size",
+			"background-repeat",
+			"background-attachment",
+			"background-origin",
+			"background-clip",
+		],
+	),
+	("background-position", &["background-position-x", "background-position-y"]),
+	("border", &["border-top", "border-right", "border-bottom", "border-left", "border-image"]),
+	("border-block", &["border-block-start", "border-block-end"]),
+	("border-block-color", &["border-block-start-color", "border-block-end-color"]),
+	("border-block-end", &["border-block-end-width", "border-block-end-style", "border-block-end-color"]),
+	("border-block-start", &["border-block-start-width", "border-block-start-style", "border-block-start-color"]),
+	("border-block-style", &["border-block-start-style", "border-block-end-style"]),
+	("border-block-width", &["border-block-start-width", "border-block-end-width"]),
+	("border-bottom", &["border-bottom-width", "border-bottom-style", "border-bottom-color"]),
+	("border-color", &["border-top-color", "border-right-color", "border-bottom-color", "border-left-color"]),
+	(
+		"border-image",
+		&[
+			"border-image-source",
+			"border-image-slice",
+			"border-image-width",
+			"border-image-outset",
+			"border-image-repeat",
+		],
+	),
+	("border-inline", &["border-inline-start", "border-inline-end"]),
+	("border-inline-color", &["border-inline-start-color", "border-inline-end-color"]),
+	("border-inline-end", &["border-inline-end-width", "border-inline-end-style", "border-inline-end-color"]),
+	("border-inline-start", &["border-inline-start-width", "border-inline-start-style", "border-inline-start-color"]),
+	("border-inline-style", &["border-inline-start-style", "border-inline-end-style"]),
+	("border-inline-width", &["border-inline-start-width", "border-inline-end-width"]),
+	("border-left", &["border-left-width", "border-left-style", "border-left-color"]),
+	(
+		"border-radius",
+		&[
+			"border-top-left-radius",
+			"border-top-right-radius",
+			"border-bottom-right-radius",
+			"border-bottom-left-radius",
+		],
+	),
+	("border-right", &["border-right-width", "border-right-style", "border-right-color"]),
+	("border-style", &["border-top-style", "border-right-style", "border-bottom-style", "border-left-style"]),
+	("border-top", &["border-top-width", "border-top-style", "border-top-color"]),
+	("border-width", &["border-top-width", "border-right-width", "border-bottom-width", "border-left-width"]),
+	("column-rule", &["column-rule-width", "column-rule-style", "column-rule-color"]),
+	("columns", &["column-width", "column-count"]),
+	("contain-intrinsic-size", &["contain-intrinsic-width", "contain-intrinsic-height"]),
+	("container", &["container-name", "container-type"]),
+	("flex", &["flex-grow", "flex-shrink", "flex-basis"]),
+	("flex-flow", &["flex-direction", "flex-wrap"]),
+	(
+		"font",
+		&[
+			"font-style",
+			"font-variant",
+			"font-weight",
+			"font-stretch",
+			"font-size",
+			"line-height",
+			"font-family",
+			"font-size-adjust",
+			"font-kerning",
+			"font-feature-settings",
+			"font-language-override",
+			"font-optical-sizing",
+			"font-variation-settings",
+			"font-palette",
+		],
+	),
+	("font-synthesis", &["font-synthesis-weight", "font-synthesis-style", "font-synthesis-small-caps"]),
+	(
+		"font-variant",
+		&[
+			"font-variant-ligatures",
+			"font-variant-caps",
+			"font-variant-alternates",
+			"font-variant-numeric",
+			"font-variant-east-asian",
+			"font-variant-position",
+			"font-variant-emoji",
+		],
+	),
+	("gap", &["row-gap", "column-gap"]),
+	("grid", &["grid-template", "grid-auto-rows", "grid-auto-columns", "grid-auto-flow"]),
+	("grid-area", &["grid-row", "grid-column"]),
+	("grid-column", &["grid-column-start", "grid-column-end"]),
+	("grid-column-gap", &["column-gap"]),
+	("grid-gap", &["row-gap", "column-gap"]),
+	("grid-row", &["grid-row-start", "grid-row-end"]),
+	("grid-row-gap", &["row-gap"]),
+	("grid-template", &["grid-template-rows", "grid-template-columns", "grid-template-areas"]),
+	("inset", &["top", "right", "bottom", "left"]),
+	("inset-block", &["inset-block-start", "inset-block-end"]),
+	("inset-inline", &["inset-inline-start", "inset-inline-end"]),
+	("list-style", &["list-style-type", "list-style-position", "list-style-image"]),
+	("margin", &["margin-top", "margin-right", "margin-bottom", "margin-left"]),
+	("margin-block", &["margin-block-start", "margin-block-end"]),
+	("margin-inline", &["margin-inline-start", "margin-inline-end"]),
+	("marker", &["marker-start", "marker-mid", "marker-end"]),
+	(
+		"mask",
+		&[
+			"mask-image",
+			"mask-mode",
+			"mask-repeat",
+			"mask-position",
+			"mask-clip",
+			"mask-origin",
+			"mask-size",
+			"mask-composite",
+		],
+	),
+	("offset", &["offset-position", "offset-path", "offset-distance", "offset-rotate", "offset-anchor"]),
+	("outline", &["outline-color", "outline-style", "outline-width"]),
+	("overflow", &["overflow-x", "overflow-y"]),
+	("overscroll-behavior", &["overscroll-behavior-x", "overscroll-behavior-y"]),
+	("padding", &["padding-top", "padding-right", "padding-bottom", "padding-left"]),
+	("padding-block", &["padding-block-start", "padding-block-end"]),
+	("padding-inline", &["padding-inline-start", "padding-inline-end"]),
+	("place-content", &["align-content", "justify-content"]),
+	("place-items", &["align-items", "justify-items"]),
+	("place-self", &["align-self", "justify-self"]),
+	("scroll-margin", &["scroll-margin-top", "scroll-margin-right", "scroll-margin-bottom", "scroll-margin-left"]),
+	("scroll-margin-block", &["scroll-margin-block-start", "scroll-margin-block-end"]),
+	("scroll-margin-inline", &["scroll-margin-inline-start", "scroll-margin-inline-end"]),
+	("scroll-padding", &["scroll-padding-top", "scroll-padding-right", "scroll-padding-bottom", "scroll-padding-left"]),
+	("scroll-padding-block", &["scroll-padding-block-start", "scroll-padding-block-end"]),
+	("scroll-padding-inline", &["scroll-padding-inline-start", "scroll-padding-inline-end"]),
+	(
+		"text-decoration",
+		&["text-decoration-line", "text-decoration-style", "text-decoration-color", "text-decoration-thickness"],
+	),
+	("text-emphasis", &["text-emphasis-style", "text-emphasis-color"]),
+	("text-wrap", &["text-wrap-mode", "text-wrap-style"]),
+	(
+		"transition",
+		&[
+			"transition-property",
+			"transition-duration",
+			"transition-timing-function",
+			"transition-delay",
+			"transition-behavior",
+		],
+	),
+	("white-space", &["white-space-collapse", "text-wrap-mode"]),
+];
+
+// Collects the longhand properties which a property sets, with flow-relative properties mapped to their physical
+// equivalent, as the two share a value.
+fn longhands(name: &str, longhands_of: &mut Vec<String>) {
+	match SHORTHANDS.iter().find(|(shorthand, _)| *shorthand == name) {
+		Some((_, names)) => names.iter().for_each(|name| longhands(name, longhands_of)),
+		None => longhands_of.push(to_physical_property(name).unwrap_or(name).to_owned()),
+	}
+}
+
+// Returns true if the order of the two declarations matters, because they set some of the same properties.
+fn overlaps(a: &str, b: &str) -> bool {
+	if a.starts_with("--") || b.starts_with("--") {
+		return false;
+	}
+	// `all` sets every property other than `direction`, `unicode-bidi` and custom properties.
+	let all = |name: &str| name == "all" && !matches!(a.max(b), "direction" | "unicode-bidi");
+	if all(a) || all(b) {
+		return true;
+	}
+	let (mut a_longhands, mut b_longhands) = (vec![], vec![]);
+	longhands(a, &mut a_longhands);
+	longhands(b, &mut b_longhands);
+	a_longhands.iter().any(|longhand| b_longhands.contains(longhand))
+}
+
+fn sort_key(name: &str) -> (bool, &str, bool) {
+	if name.starts_with("--") {
+		return (false, "", false);
+	}
+	let unprefixed =
+		name.strip_prefix('-').and_then(|name| name.split_once('-')).map(|(_, unprefixed)| unprefixed).unwrap_or(name);
+	(true, unprefixed, unprefixed == name)
+}
+
+impl Visit for SortDeclarations<'_> {
+	fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(&mut self, block: &Block<'a, D, R>) {
+		let (Some(first), Some(last)) = (block.declarations.first(), block.declarations.last()) else {
+			return;
+		};
+		let span = Span::new(first.to_span().start(), last.to_span().end());
+		if block.rules.iter().any(|rule| span.contains(rule.to_span())) {
+			return;
+		}
+		let names: Vec<String> =
+			block.declarations.iter().map(|d| text(self.source, d.name.to_span()).to_ascii_lowercase()).collect();
+		let mut order: Vec<usize> = (0..names.len()).collect();
+		order.sort_by(|a, b| sort_key(&names[*a]).cmp(&sort_key(&names[*b])));
+		if order.iter().enumerate().all(|(i, j)| i == *j) {
+			return;
+		}
+		// Swapping two declarations which set the same property (such as `row-gap` and `gap`) changes which applies.
+		let swaps_overlapping = order
+			.iter()
+			.enumerate()
+			.any(|(slot, i)| order[slot + 1..].iter().any(|j| j < i && overlaps(&names[*i], &names[*j])));
+		if swaps_overlapping {
+			return;
+		}
+		// Each declaration is moved into the "slot" of another, keeping the whitespace & comments between them in place.
+		let spans: Vec<Span> = block.declarations.iter().map(|d| d.to_span()).collect();
+		let mut str = String::new();
+		for (slot, i) in order.into_iter().enumerate() {
+			if slot > 0 {
+				str.push_str(text(self.source, Span::new(spans[slot - 1].end(), spans[slot].start())));
+			}
+			// The last declaration only ends with a semicolon if the last declaration before sorting did.
+			let declaration = &block.declarations[i];
+			let end = declaration.semicolon.map_or(spans[i].end(), |semicolon| semicolon.to_span().start());
+			str.push_str(text(self.source, Span::new(spans[i].start(), end)));
+			if slot < spans.len() - 1 || block.declarations[slot].semicolon.is_some() {
+				str.push(';');
+			}
+		}
+		self.edits.push(Edit::new(span, str));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::assert_action;
+
+	#[test]
+	fn test_sort_declarations() {
+		assert_action!(
+			sort_declarations,
+			"a {\n\tz-index: 1;\n\t--b: 1;\n\tcolor: red;\n\tuser-select: none;\n\t-webkit-user-select: none;\n\t--a: 2\n}",
+			"a {\n\t--b: 1;\n\t--a: 2;\n\tcolor: red;\n\t-webkit-user-select: none;\n\tuser-select: none;\n\tz-index: 1\n}"
+		);
+		assert_action!(sort_declarations, "a{color:red;width:1px}", "a{color:red;width:1px}");
+		assert_action!(sort_declarations, "a{padding-top:1px;padding:0}", "a{padding-top:1px;padding:0}");
+		assert_action!(sort_declarations, "a{row-gap:2px;gap:1px}", "a{row-gap:2px;gap:1px}");
+		assert_action!(sort_declarations, "a{line-height:1;font:12px serif}", "a{line-height:1;font:12px serif}");
+		assert_action!(sort_declarations, "a{top:0;inset:1px}", "a{top:0;inset:1px}");
+		assert_action!(
+			sort_declarations,
+			"a{margin-left:0;margin-inline-start:1px}",
+			"a{margin-left:0;margin-inline-start:1px}"
+		);
+		assert_action!(sort_declarations, "a{border-top-color:red;border:0}", "a{border-top-color:red;border:0}");
+		assert_action!(sort_declarations, "a{width:1px;all:unset}", "a{width:1px;all:unset}");
+		assert_action!(sort_declarations, "a{z-index:1;gap:1px;color:red}", "a{color:red;gap:1px;z-index:1}");
+		assert_action!(
+			sort_declarations,
+			"a{padding:0;color:red;padding-top:1px}",
+			"a{color:red;padding:0;padding-top:1px}"
+		);
+		assert_action!(sort_declarations, "a{width:1px;b{z-index:1}color:red}", "a{width:1px;b{z-index:1}color:red}");
+		assert_action!(
+			sort_declarations,
+			"@media print{a{width:1px;color:red;&:hover{z-index:1;top:0}}}",
+			"@media print{a{color:red;width:1px;&:hover{top:0;z-index:1}}}"
+		);
+	}
+}
@@ -0,0 +1,33 @@
+/// Lints the source, asserting the message of each lint of the given rule, and that applying every fix produces the
+/// expected source. Rules which check browser targets are run against `chrome 100`, `firefox 115` and `safari 15.4`.
+#[macro_export]
+macro_rules! assert_lint {
+	($rule: ident, $source: literal, [$($message: literal),* $(,)?], $fixed: literal) => {{
+		let bump = ::bumpalo::Bump::default();
+		let source = $source;
+		let result = ::css_parse::Parser::new(&bump, source).parse_entirely::<::css_ast::StyleSheet>();
+		let stylesheet = result.output.as_ref().unwrap();
+		let targets = ["chrome 100", "firefox 115", "safari 15.4"].map(|target| target.parse().unwrap());
+		let lints: Vec<_> = $crate::lint(source, stylesheet)
+			.into_iter()
+			.chain($crate::lint_targets(source, stylesheet, &targets))
+			.filter(|l| l.rule == $crate::Rule::$rule)
+			.collect();
+		let messages: Vec<_> = lints.iter().map(|l| l.message.as_str()).collect();
+		assert_eq!(messages, vec![$($message),*] as Vec<&str>);
+		let edits: Vec<_> = lints.into_iter().filter_map(|l| l.fix).flat_map(|f| f.edits).collect();
+		assert_eq!($crate::apply_edits(source, &edits), $fixed);
+	}};
+}
+
+/// Runs a source action over the source, asserting the result of applying its edits.
+#[macro_export]
+macro_rules! assert_action {
+	($action: path, $source: literal, $expected: literal) => {{
+		let bump = ::bumpalo::Bump::default();
+		let source = $source;
+		let result = ::css_parse::Parser::new(&bump, source).parse_entirely::<::css_ast::StyleSheet>();
+		let edits = $action(source, result.output.as_ref().unwrap());
+		assert_eq!($crate::apply_edits(source, &edits), $expected);
+	}};
+}
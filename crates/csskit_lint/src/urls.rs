@@ -0,0 +1,181 @@
+use bumpalo::Bump;
+use css_ast::{ImportRule, ImportTarget, StyleSheet, Url, Visit, Visitable};
+use css_lexer::{Cursor, Kind, SourceOffset, Span, ToSpan};
+use css_parse::{CursorCollector, Declaration, DeclarationValue, Parser};
+use std::path::{Component, Path, PathBuf};
+
+/// A reference to another file, from either a `url()` or the target of an `@import`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UrlReference {
+	/// The URL as written, without the surrounding `url()` or quotes.
+	pub href: String,
+	/// The span of the whole `url()`, or the string of an `@import`.
+	pub span: Span,
+}
+
+impl UrlReference {
+	/// Checks if the URL refers to a file relative to the stylesheet, rather than another origin (such as
+	/// `https://...`), a `data:` URL, a fragment of the same document, or a path from the root of the site (which can't
+	/// be resolved without knowing where that is).
+	pub fn is_relative(&self) -> bool {
+		let href = self.href.as_str();
+		let scheme = href.find(':').is_some_and(|colon| {
+			href[..colon].chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+				&& !href[..colon].is_empty()
+		});
+		!href.is_empty() && !scheme && !href.starts_with('/') && !href.starts_with('#')
+	}
+
+	/// Resolves a relative URL against the directory of the stylesheet, ignoring any query string or fragment and
+	/// decoding any percent-encoded characters (so `my%20image.png` refers to `my image.png`).
+	pub fn resolve(&self, dir: &Path) -> Option<PathBuf> {
+		if !self.is_relative() {
+			return None;
+		}
+		let path = percent_decode(self.href.split(['?', '#']).next()?)?;
+		let mut resolved = dir.to_path_buf();
+		for component in Path::new(&path).components() {
+			match component {
+				Component::ParentDir => {
+					if matches!(resolved.components().next_back(), Some(Component::Normal(_))) {
+						resolved.pop();
+					} else {
+						resolved.push("..");
+					}
+				}
+				Component::Normal(part) => resolved.push(part),
+				_ => {}
+			}
+		}
+		Some(resolved)
+	}
+}
+
+// Decodes each `%XX` escape in a URL path into the byte it encodes, or returns `None` if the decoded path isn't UTF-8.
+// A `%` which isn't followed by two hex digits is kept as it is.
+fn percent_decode(path: &str) -> Option<String> {
+	let bytes = path.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+	while i < bytes.len() {
+		let hex = path.get(i + 1..i + 3).filter(|hex| bytes[i] == b'%' && hex.bytes().all(|b| b.is_ascii_hexdigit()));
+		match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+			Some(byte) => {
+				decoded.push(byte);
+				i += 3;
+			}
+			_ => {
+				decoded.push(bytes[i]);
+				i += 1;
+			}
+		}
+	}
+	String::from_utf8(decoded).ok()
+}
+
+/// A [Visit] pass which finds every [UrlReference] in a stylesheet.
+#[derive(Default)]
+struct UrlCollector<'s> {
+	source: &'s str,
+	urls: Vec<UrlReference>,
+}
+
+impl UrlCollector<'_> {
+	// Declaration values are not all typed (for example custom properties), so rather than walking the value each
+	// token which could begin a url is re-parsed as one.
+	fn parse_url(&self, offset: SourceOffset) -> Option<UrlReference> {
+		let source = &self.source[offset.0 as usize..];
+		let bump = Bump::default();
+		let url = Parser::new(&bump, source).parse::<Url>().ok()?;
+		let span = url.to_span();
+		Some(UrlReference {
+			href: url.href(source, &bump).into(),
+			span: Span::new(SourceOffset(offset.0 + span.start().0), SourceOffset(offset.0 + span.end().0)),
+		})
+	}
+}
+
+impl Visit for UrlCollector<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		let mut cursors = CursorCollector::default();
+		declaration.value.to_cursors(&mut cursors);
+		for c in cursors.0 {
+			if !matches!(c.token().kind(), Kind::Url | Kind::Function) {
+				continue;
+			}
+			if let Some(url) = self.parse_url(c.offset()) {
+				self.urls.push(url);
+			}
+		}
+	}
+
+	fn visit_import_rule<'a>(&mut self, rule: &ImportRule<'a>) {
+		let url = match &rule.0.prelude.target {
+			ImportTarget::Url(url) => self.parse_url(url.to_span().start()),
+			ImportTarget::String(string) => {
+				let bump = Bump::default();
+				let c: Cursor = (*string).into();
+				Some(UrlReference { href: c.parse_str(self.source, &bump).into(), span: c.span() })
+			}
+		};
+		self.urls.extend(url);
+	}
+}
+
+/// Finds every `url()` within declaration values, and the target of every `@import`, in source order.
+pub fn find_urls(source: &str, stylesheet: &StyleSheet) -> Vec<UrlReference> {
+	let mut collector = UrlCollector { source, urls: vec![] };
+	stylesheet.accept(&mut collector);
+	collector.urls.sort_by_key(|url| url.span.start());
+	collector.urls
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn urls(source: &str) -> Vec<(String, &str)> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		find_urls(source, result.output.as_ref().unwrap())
+			.into_iter()
+			.map(|url| (url.href, crate::text(source, url.span)))
+			.collect()
+	}
+
+	fn reference(href: &str) -> UrlReference {
+		UrlReference { href: href.into(), span: Span::ZERO }
+	}
+
+	#[test]
+	fn test_find_urls() {
+		assert_eq!(
+			urls(
+				"@import 'reset.css';@import url(theme.css) screen;a{background:url(a.png) no-repeat,url(\"b.svg#icon\")}@font-face{src:url(f.woff2)}"
+			),
+			vec![
+				("reset.css".into(), "'reset.css'"),
+				("theme.css".into(), "url(theme.css)"),
+				("a.png".into(), "url(a.png)"),
+				("b.svg#icon".into(), "url(\"b.svg#icon\")"),
+				("f.woff2".into(), "url(f.woff2)"),
+			]
+		);
+	}
+
+	#[test]
+	fn test_resolve() {
+		let dir = Path::new("/site/css");
+		assert_eq!(reference("../img/a.png?v=2").resolve(dir), Some(PathBuf::from("/site/img/a.png")));
+		assert_eq!(reference("./b.svg#icon").resolve(dir), Some(PathBuf::from("/site/css/b.svg")));
+		assert_eq!(reference("../../a.png").resolve(Path::new("css")), Some(PathBuf::from("../a.png")));
+		assert_eq!(reference("https://example.com/a.png").resolve(dir), None);
+		assert_eq!(reference("data:image/png;base64,AAAA").resolve(dir), None);
+		assert_eq!(reference("/img/a.png").resolve(dir), None);
+		assert_eq!(reference("#gradient").resolve(dir), None);
+		assert_eq!(reference("my%20image.png").resolve(dir), Some(PathBuf::from("/site/css/my image.png")));
+		assert_eq!(reference("caf%C3%A9/100%.png").resolve(dir), Some(PathBuf::from("/site/css/café/100%.png")));
+		assert_eq!(reference("%+1.png").resolve(dir), Some(PathBuf::from("/site/css/%+1.png")));
+		assert_eq!(reference("%FF.png").resolve(dir), None);
+	}
+}
@@ -1,7 +1,13 @@
 #![deny(warnings)]
+// lsp_types keys maps such as WorkspaceEdit::changes by Uri, which clippy considers mutable.
+#![allow(clippy::mutable_key_type)]
 mod jsonrpc;
 mod server;
 mod service;
+#[cfg(test)]
+mod test_helpers;
+#[cfg(test)]
+mod tests;
 
 #[doc(inline)]
 pub use jsonrpc::*;
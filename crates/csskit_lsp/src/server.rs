@@ -20,8 +20,10 @@ use crate::{Notification, TracingLayer};
 
 use super::Message;
 
+mod client;
 mod handler;
 
+pub use client::Client;
 pub use handler::Handler;
 
 pub struct ThreadConnection {
@@ -54,6 +56,7 @@ impl Server {
 
 		let handler_receiver = read_receiver.clone();
 		let handler_sender = write_sender.clone();
+		handler.connect(Client::new(write_sender.clone()));
 		let trace_level = Arc::new(RwLock::new(LevelFilter::OFF));
 		let level_set = trace_level.clone();
 		let request_handler = Builder::new()
@@ -0,0 +1,42 @@
+use crossbeam_channel::Sender;
+use lsp_types::{notification::Notification as NotificationTrait, request::Request as RequestTrait};
+use std::sync::{
+	Arc,
+	atomic::{AtomicI32, Ordering},
+};
+use tracing::warn;
+
+use crate::{Id, Message, Notification, Request};
+
+/// Sends requests and notifications from the server to the client. The client's [`Response`s](crate::Response) to
+/// requests are passed to [Handler::on_response()](super::Handler::on_response), matched by the [Id] which
+/// [Client::request()] returns.
+#[derive(Debug, Clone)]
+pub struct Client {
+	sender: Sender<Message>,
+	next_id: Arc<AtomicI32>,
+}
+
+impl Client {
+	pub(crate) fn new(sender: Sender<Message>) -> Self {
+		Self { sender, next_id: Arc::new(AtomicI32::new(1)) }
+	}
+
+	/// Sends a request to the client, returning the [Id] its response will have.
+	pub fn request<T: RequestTrait>(&self, params: T::Params) -> Id {
+		let id: Id = format!("csskit-{}", self.next_id.fetch_add(1, Ordering::SeqCst)).into();
+		self.send(Message::Request(Request::new::<T>(id.clone(), params)));
+		id
+	}
+
+	/// Sends a notification to the client.
+	pub fn notify<T: NotificationTrait>(&self, params: T::Params) {
+		self.send(Message::Notification(Notification::new::<T>(params)));
+	}
+
+	fn send(&self, message: Message) {
+		if let Err(e) = self.sender.send(message) {
+			warn!("Failed to send message to the client {:?}", &e);
+		}
+	}
+}
@@ -1,4 +1,7 @@
-use crate::jsonrpc::{ErrorCode, Id, Message, Response};
+use crate::{
+	Client,
+	jsonrpc::{ErrorCode, Id, Message, Response},
+};
 use lsp_types::{notification::*, request::*};
 use serde_json::Value;
 use tracing::{debug, trace_span};
@@ -20,6 +23,12 @@ pub trait Handler: Sized + Send + Sync + 'static {
 		false
 	}
 
+	/// Called once before any messages are handled, with a [Client] which can send requests to the client.
+	fn connect(&self, _client: Client) {}
+
+	/// Handles the client's [Response] to a request sent with [Client::request()].
+	fn on_response(&self, _response: Response) {}
+
 	fn handle(&self, message: Message) -> Option<Message> {
 		let span = trace_span!("Handling request", "{:#?}", message);
 		let _ = span.enter();
@@ -27,6 +36,10 @@ pub trait Handler: Sized + Send + Sync + 'static {
 		if message.is_exit_notification() {
 			return None;
 		}
+		if let Message::Response(response) = message {
+			self.on_response(response);
+			return None;
+		}
 		let initialize_request = message.is_initialize_request();
 		if !self.initialized() && !initialize_request {
 			debug!("Skipping message {:?} before initialization", message);
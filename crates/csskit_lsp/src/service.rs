@@ -1,38 +1,96 @@
 use bumpalo::Bump;
 use crossbeam_channel::{Receiver, Sender, bounded};
 use css_ast::{StyleSheet, Visitable};
+use css_lexer::Span;
 use css_parse::{Parser, ParserReturn};
-use csskit_highlight::{Highlight, SemanticKind, SemanticModifier, TokenHighlighter};
+use csskit_config::Config;
+use csskit_highlight::{SemanticKind, SemanticModifier, TokenHighlighter};
 use dashmap::DashMap;
-use itertools::Itertools;
 use lsp_types::Uri;
 use ropey::Rope;
 use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
 	sync::{
-		Arc,
+		Arc, Mutex, OnceLock, RwLock,
 		atomic::{AtomicBool, Ordering},
 	},
 	thread::{Builder, JoinHandle},
 };
 use strum::VariantNames;
-use tracing::{instrument, trace, trace_span};
+use tracing::{instrument, trace, trace_span, warn};
 
-use crate::{ErrorCode, Handler};
+use crate::{Client, ErrorCode, Handler, Id, Response};
 
-type Line = u32;
-type Col = u32;
+mod actions;
+mod colors;
+mod format;
+mod hints;
+mod index;
+mod links;
+mod position;
+mod ranges;
+mod settings;
+mod signatures;
+mod symbols;
+mod tokens;
+mod workspace;
+
+use colors::ColorCollector;
+use hints::{InlayHintCollector, InlayHintSettings};
+use index::{Index, SymbolCollector};
+use ranges::FoldingCollector;
+use settings::Settings;
+use symbols::DocumentOutline;
+use tokens::SemanticTokensCache;
 
 #[derive(Debug)]
 enum FileCall {
-	// Re-parse the document based on changes
+	// Re-parse & index the document based on changes, returning once it has been indexed
 	RopeChange(Rope),
-	// Highlight a document, returning the semantic highlights
-	Highlight,
+	// Use a new config, re-parsing the document if the parser features changed
+	Configure(Box<Config>),
+	// Highlight a document, or just the tokens within the range, returning the semantic tokens
+	Highlight(Option<lsp_types::Range>),
+	// Build the outline of a document, returning nested symbols
+	DocumentSymbols,
+	// Pretty print a document, or just the rules within the range, returning the edits to make
+	Format(Option<lsp_types::Range>),
+	// Find all colors within the document, resolved to RGBA
+	DocumentColors,
+	// Find the inlay hints within the range, of the kinds enabled in the settings
+	InlayHints(lsp_types::Range, InlayHintSettings),
+	// Find every url() & @import target, as links relative to the document
+	DocumentLinks,
+	// Find the signature of the function the position is within, highlighting the argument at the position
+	SignatureHelp(lsp_types::Position),
+	// Find all foldable ranges: blocks & multi-line comments
+	FoldingRanges,
+	// Find the nested ranges of nodes around each position, from innermost to outermost
+	SelectionRanges(Vec<lsp_types::Position>),
+	// Lint the document, returning parse errors & lints as diagnostics
+	Diagnostics,
+	// Find the quick fixes for each lint within the range
+	CodeActions(lsp_types::Range),
+	// Compute the edits of a source action, such as sorting declarations
+	SourceAction(lsp_types::CodeActionKind),
 }
 
 #[derive(Debug)]
 enum FileReturn {
-	Highlights(Vec<(Highlight, Line, Col)>),
+	// The document has been re-parsed & its symbols indexed
+	Indexed,
+	SemanticTokens(Vec<lsp_types::SemanticToken>),
+	DocumentSymbols(Vec<lsp_types::DocumentSymbol>),
+	TextEdits(Vec<lsp_types::TextEdit>),
+	Colors(Vec<lsp_types::ColorInformation>),
+	InlayHints(Vec<lsp_types::InlayHint>),
+	DocumentLinks(Vec<lsp_types::DocumentLink>),
+	SignatureHelp(Option<lsp_types::SignatureHelp>),
+	FoldingRanges(Vec<lsp_types::FoldingRange>),
+	SelectionRanges(Vec<lsp_types::SelectionRange>),
+	Diagnostics(Vec<lsp_types::Diagnostic>),
+	CodeActions(Vec<lsp_types::CodeActionOrCommand>),
 }
 
 #[derive(Debug)]
@@ -42,21 +100,28 @@ pub struct File {
 	thread: JoinHandle<()>,
 	sender: Sender<FileCall>,
 	receiver: Receiver<FileReturn>,
+	semantic_tokens: Mutex<SemanticTokensCache>,
 }
 
 impl File {
-	fn new() -> Self {
+	fn new(uri: Uri, index: Arc<Index>, config: Config) -> Self {
 		let (sender, read_receiver) = bounded::<FileCall>(0);
 		let (write_sender, receiver) = bounded::<FileReturn>(0);
 		Self {
 			content: Rope::new(),
 			sender,
 			receiver,
+			semantic_tokens: Mutex::new(SemanticTokensCache::default()),
 			thread: Builder::new()
 				.name("LspDocumentHandler".into())
 				.spawn(move || {
+					let mut config = config;
+					// Ignored documents are still parsed & indexed, but aren't linted or formatted.
+					let path = workspace::uri_to_path(&uri);
+					let is_ignored = |config: &Config| path.as_ref().is_some_and(|path| config.is_ignored(path));
 					let mut bump = Bump::default();
 					let mut string: String = "".into();
+					let mut content = Rope::new();
 					let mut result: ParserReturn<'_, StyleSheet<'_>> =
 						Parser::new(&bump, "").parse_entirely::<StyleSheet>();
 					while let Ok(call) = read_receiver.recv() {
@@ -69,35 +134,200 @@ impl File {
 								drop(result);
 								bump.reset();
 								string = rope.clone().into();
-								result = Parser::new(&bump, &string).parse_entirely::<StyleSheet>();
+								content = rope;
+								result = Parser::new_with_features(&bump, &string, config.features())
+									.parse_entirely::<StyleSheet>();
+								let mut collector = SymbolCollector::new(&string);
+								if let Some(stylesheet) = &result.output {
+									stylesheet.accept(&mut collector);
+								}
+								index.update(uri.clone(), collector.into_symbols(&content));
+								write_sender.send(FileReturn::Indexed).ok();
 								// if let Some(stylesheet) = &result.output {
 								// 	trace!("Sucessfully parsed stylesheet: {:#?}", &stylesheet);
 								// }
 							}
-							FileCall::Highlight => {
+							FileCall::Configure(new_config) => {
+								let features = config.features();
+								config = *new_config;
+								if config.features() == features {
+									continue;
+								}
+								let span = trace_span!("Re-parsing document with new features");
+								let _ = span.enter();
+								drop(result);
+								bump.reset();
+								result = Parser::new_with_features(&bump, &string, config.features())
+									.parse_entirely::<StyleSheet>();
+								let mut collector = SymbolCollector::new(&string);
+								if let Some(stylesheet) = &result.output {
+									stylesheet.accept(&mut collector);
+								}
+								index.update(uri.clone(), collector.into_symbols(&content));
+							}
+							FileCall::Highlight(range) => {
 								let span = trace_span!("Highlighting document");
 								let _ = span.enter();
 								let mut highlighter = TokenHighlighter::new();
 								if let Some(stylesheet) = &result.output {
 									stylesheet.accept(&mut highlighter);
-									let mut current_line = 0;
-									let mut current_start = 0;
-									let data = highlighter
-										.highlights()
-										.sorted_by(|a, b| Ord::cmp(&a.span(), &b.span()))
-										.map(|h| {
-											// TODO: figure out a more efficient way to get line/col
-											let span_contents = h.span().span_contents(&string);
-											let (line, start) = span_contents.line_and_column();
-											let delta_line: Line = line - current_line;
-											current_line = line;
-											let delta_start: Col =
-												if delta_line == 0 { start - current_start } else { start };
-											current_start = start;
-											(*h, delta_line, delta_start)
-										});
-									write_sender.send(FileReturn::Highlights(data.collect())).ok();
 								}
+								let tokens = tokens::semantic_tokens(&content, highlighter.highlights(), range);
+								write_sender.send(FileReturn::SemanticTokens(tokens)).ok();
+							}
+							FileCall::DocumentSymbols => {
+								let span = trace_span!("Building document outline");
+								let _ = span.enter();
+								let mut outline = DocumentOutline::new(&string);
+								if let Some(stylesheet) = &result.output {
+									stylesheet.accept(&mut outline);
+								}
+								write_sender.send(FileReturn::DocumentSymbols(outline.into_symbols(&content))).ok();
+							}
+							FileCall::DocumentColors => {
+								let span = trace_span!("Finding document colors");
+								let _ = span.enter();
+								let mut collector = ColorCollector::new(&string);
+								if let Some(stylesheet) = &result.output {
+									stylesheet.accept(&mut collector);
+								}
+								write_sender.send(FileReturn::Colors(collector.into_colors(&content))).ok();
+							}
+							FileCall::InlayHints(range, settings) => {
+								let span = trace_span!("Finding inlay hints");
+								let _ = span.enter();
+								let mut collector = InlayHintCollector::new(&string, settings);
+								if let Some(stylesheet) = &result.output {
+									stylesheet.accept(&mut collector);
+								}
+								write_sender
+									.send(FileReturn::InlayHints(collector.into_hints(&content, &index, range)))
+									.ok();
+							}
+							FileCall::DocumentLinks => {
+								let span = trace_span!("Finding document links");
+								let _ = span.enter();
+								let urls = result
+									.output
+									.as_ref()
+									.map(|stylesheet| csskit_lint::find_urls(&string, stylesheet))
+									.unwrap_or_default();
+								write_sender
+									.send(FileReturn::DocumentLinks(links::document_links(&uri, &content, urls)))
+									.ok();
+							}
+							FileCall::SignatureHelp(position) => {
+								let span = trace_span!("Finding signature help");
+								let _ = span.enter();
+								let help = position::position_to_offset(&content, position).and_then(|offset| {
+									signatures::signature_help(&string, config.features().into(), offset.0 as usize)
+								});
+								write_sender.send(FileReturn::SignatureHelp(help)).ok();
+							}
+							FileCall::FoldingRanges => {
+								let span = trace_span!("Finding folding ranges");
+								let _ = span.enter();
+								let mut collector = FoldingCollector::default();
+								if let Some(stylesheet) = &result.output {
+									stylesheet.accept(&mut collector);
+								}
+								write_sender
+									.send(FileReturn::FoldingRanges(collector.into_ranges(&content, &result.trivia)))
+									.ok();
+							}
+							FileCall::SelectionRanges(positions) => {
+								let span = trace_span!("Finding selection ranges");
+								let _ = span.enter();
+								let ranges = positions
+									.into_iter()
+									.map(|position| {
+										let spans = result
+											.output
+											.as_ref()
+											.zip(position::position_to_offset(&content, position))
+											.map(|(stylesheet, offset)| ranges::selection_spans(stylesheet, offset))
+											.unwrap_or_default();
+										// Each position must have a range, so an empty one is returned where nothing matches.
+										ranges::selection_range(&content, spans).unwrap_or(lsp_types::SelectionRange {
+											range: lsp_types::Range::new(position, position),
+											parent: None,
+										})
+									})
+									.collect();
+								write_sender.send(FileReturn::SelectionRanges(ranges)).ok();
+							}
+							FileCall::Diagnostics => {
+								let span = trace_span!("Linting document");
+								let _ = span.enter();
+								// Documents saved to disk can also be checked for urls to files which don't exist.
+								let lints = result
+									.output
+									.as_ref()
+									.map(|stylesheet| {
+										let mut lints = match &path {
+											Some(path) => csskit_lint::lint_file(&string, stylesheet, path),
+											None => csskit_lint::lint(&string, stylesheet),
+										};
+										if let Some(targets) = &config.targets {
+											lints.extend(csskit_lint::lint_targets(&string, stylesheet, targets));
+										}
+										lints
+									})
+									.map(|lints| config.lint.apply(lints))
+									.unwrap_or_default();
+								let diagnostics = if is_ignored(&config) {
+									vec![]
+								} else {
+									actions::diagnostics(&content, &result.errors, &lints)
+								};
+								write_sender.send(FileReturn::Diagnostics(diagnostics)).ok();
+							}
+							FileCall::CodeActions(range) => {
+								let span = trace_span!("Finding code actions");
+								let _ = span.enter();
+								let lints = result
+									.output
+									.as_ref()
+									.filter(|_| !is_ignored(&config))
+									.map(|stylesheet| config.lint.apply(csskit_lint::lint(&string, stylesheet)))
+									.unwrap_or_default();
+								let code_actions = actions::quick_fixes(&uri, &content, &lints, range);
+								write_sender.send(FileReturn::CodeActions(code_actions)).ok();
+							}
+							FileCall::SourceAction(kind) => {
+								let span = trace_span!("Resolving source action");
+								let _ = span.enter();
+								let edits = result
+									.output
+									.as_ref()
+									.map(|stylesheet| {
+										let lints = config.lint.apply(csskit_lint::lint(&string, stylesheet));
+										actions::source_action_edits(&kind, &string, stylesheet, lints)
+									})
+									.unwrap_or_default();
+								write_sender.send(FileReturn::TextEdits(actions::text_edits(&content, &edits))).ok();
+							}
+							FileCall::Format(range) => {
+								let span = trace_span!("Formatting document");
+								let _ = span.enter();
+								let selection = range.and_then(|range| {
+									let start = position::position_to_offset(&content, range.start)?;
+									let end = position::position_to_offset(&content, range.end)?;
+									Some(Span::new(start, end))
+								});
+								let indent = config.format.indent.unwrap_or_default().text();
+								let edits = result
+									.output
+									.as_ref()
+									// Like `csskit fmt`, documents with errors are left as they are, as formatting could lose content.
+									.filter(|_| result.errors.is_empty())
+									.filter(|_| (range.is_none() || selection.is_some()) && !is_ignored(&config))
+									.and_then(|stylesheet| {
+										format::format(&string, stylesheet, &result.trivia, selection, &indent)
+									})
+									.map(|(span, formatted)| format::minimal_edits(&content, span, &formatted))
+									.unwrap_or_default();
+								write_sender.send(FileReturn::TextEdits(edits)).ok();
 							}
 						}
 					}
@@ -106,17 +336,121 @@ impl File {
 		}
 	}
 
+	// Waits for the document to be indexed, so that requests which read the index (such as definitions) see the change.
 	fn commit(&mut self, rope: Rope) {
 		self.content = rope;
 		self.sender.send(FileCall::RopeChange(self.content.clone())).unwrap();
+		self.receiver.recv().ok();
+	}
+
+	fn configure(&self, config: Config) {
+		self.sender.send(FileCall::Configure(Box::new(config))).unwrap();
 	}
 
 	#[instrument]
-	fn get_highlights(&self) -> Vec<(Highlight, Line, Col)> {
-		self.sender.send(FileCall::Highlight).unwrap();
-		if let Ok(ret) = self.receiver.recv() {
-			let FileReturn::Highlights(highlights) = ret;
-			return highlights;
+	fn get_semantic_tokens(&self, range: Option<lsp_types::Range>) -> Vec<lsp_types::SemanticToken> {
+		self.sender.send(FileCall::Highlight(range)).unwrap();
+		if let Ok(FileReturn::SemanticTokens(tokens)) = self.receiver.recv() {
+			return tokens;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_format_edits(&self, range: Option<lsp_types::Range>) -> Vec<lsp_types::TextEdit> {
+		self.sender.send(FileCall::Format(range)).unwrap();
+		if let Ok(FileReturn::TextEdits(edits)) = self.receiver.recv() {
+			return edits;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_document_colors(&self) -> Vec<lsp_types::ColorInformation> {
+		self.sender.send(FileCall::DocumentColors).unwrap();
+		if let Ok(FileReturn::Colors(colors)) = self.receiver.recv() {
+			return colors;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_inlay_hints(&self, range: lsp_types::Range, settings: InlayHintSettings) -> Vec<lsp_types::InlayHint> {
+		self.sender.send(FileCall::InlayHints(range, settings)).unwrap();
+		if let Ok(FileReturn::InlayHints(hints)) = self.receiver.recv() {
+			return hints;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_document_links(&self) -> Vec<lsp_types::DocumentLink> {
+		self.sender.send(FileCall::DocumentLinks).unwrap();
+		if let Ok(FileReturn::DocumentLinks(links)) = self.receiver.recv() {
+			return links;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_signature_help(&self, position: lsp_types::Position) -> Option<lsp_types::SignatureHelp> {
+		self.sender.send(FileCall::SignatureHelp(position)).unwrap();
+		if let Ok(FileReturn::SignatureHelp(help)) = self.receiver.recv() {
+			return help;
+		}
+		None
+	}
+
+	#[instrument]
+	fn get_folding_ranges(&self) -> Vec<lsp_types::FoldingRange> {
+		self.sender.send(FileCall::FoldingRanges).unwrap();
+		if let Ok(FileReturn::FoldingRanges(ranges)) = self.receiver.recv() {
+			return ranges;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_selection_ranges(&self, positions: Vec<lsp_types::Position>) -> Vec<lsp_types::SelectionRange> {
+		self.sender.send(FileCall::SelectionRanges(positions)).unwrap();
+		if let Ok(FileReturn::SelectionRanges(ranges)) = self.receiver.recv() {
+			return ranges;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_diagnostics(&self) -> Vec<lsp_types::Diagnostic> {
+		self.sender.send(FileCall::Diagnostics).unwrap();
+		if let Ok(FileReturn::Diagnostics(diagnostics)) = self.receiver.recv() {
+			return diagnostics;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_code_actions(&self, range: lsp_types::Range) -> Vec<lsp_types::CodeActionOrCommand> {
+		self.sender.send(FileCall::CodeActions(range)).unwrap();
+		if let Ok(FileReturn::CodeActions(code_actions)) = self.receiver.recv() {
+			return code_actions;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_source_action_edits(&self, kind: lsp_types::CodeActionKind) -> Vec<lsp_types::TextEdit> {
+		self.sender.send(FileCall::SourceAction(kind)).unwrap();
+		if let Ok(FileReturn::TextEdits(edits)) = self.receiver.recv() {
+			return edits;
+		}
+		vec![]
+	}
+
+	#[instrument]
+	fn get_document_symbols(&self) -> Vec<lsp_types::DocumentSymbol> {
+		self.sender.send(FileCall::DocumentSymbols).unwrap();
+		if let Ok(FileReturn::DocumentSymbols(symbols)) = self.receiver.recv() {
+			return symbols;
 		}
 		vec![]
 	}
@@ -126,24 +460,215 @@ impl File {
 pub struct LSPService {
 	version: String,
 	files: Arc<DashMap<Uri, File>>,
+	index: Arc<Index>,
+	workspace_folders: RwLock<Vec<PathBuf>>,
+	// The config file of each workspace folder, which the settings are layered on top of.
+	configs: RwLock<HashMap<PathBuf, Config>>,
+	// The settings the client has for each workspace folder, pulled with `workspace/configuration`. Folders without
+	// any use the settings given by `initialize` or `workspace/didChangeConfiguration`.
+	folder_settings: RwLock<HashMap<PathBuf, Settings>>,
+	settings: RwLock<Settings>,
+	// The workspace folders each pending `workspace/configuration` request asked for the settings of, in order.
+	configuration_requests: Mutex<HashMap<Id, Vec<PathBuf>>>,
+	capabilities: RwLock<lsp_types::ClientCapabilities>,
+	client: OnceLock<Client>,
 	initialized: AtomicBool,
 }
 
 impl LSPService {
 	pub fn new(version: &'static str) -> Self {
-		Self { version: version.into(), files: Arc::new(DashMap::new()), initialized: AtomicBool::new(false) }
+		Self {
+			version: version.into(),
+			files: Arc::new(DashMap::new()),
+			index: Arc::new(Index::default()),
+			workspace_folders: RwLock::new(vec![]),
+			configs: RwLock::new(HashMap::new()),
+			folder_settings: RwLock::new(HashMap::new()),
+			settings: RwLock::new(Settings::default()),
+			configuration_requests: Mutex::new(HashMap::new()),
+			capabilities: RwLock::new(lsp_types::ClientCapabilities::default()),
+			client: OnceLock::new(),
+			initialized: AtomicBool::new(false),
+		}
+	}
+
+	/// Indexes every stylesheet within the given folders, in the background, skipping those which are already open.
+	fn index_folders(&self, folders: &[PathBuf]) {
+		let paths = folders
+			.iter()
+			.flat_map(|folder| workspace::discover(folder))
+			.filter_map(|path| {
+				let config = self.config_for_path(Some(&path));
+				(!config.is_ignored(&path)).then(|| (path, config.features()))
+			})
+			.collect();
+		let files = self.files.clone();
+		workspace::index_in_background(self.index.clone(), paths, move |uri| files.contains_key(uri));
+	}
+
+	fn is_in_workspace(&self, path: &Path) -> bool {
+		self.workspace_folders.read().unwrap().iter().any(|folder| path.starts_with(folder))
+	}
+
+	/// (Re)loads the nearest config file of each of the workspace folders. Folders without one use the default config,
+	/// with ignore globs relative to the folder, as do folders whose config file can't be loaded (which the user is
+	/// told about, as otherwise their config would be silently ignored).
+	fn load_configs(&self, folders: &[PathBuf]) {
+		let mut configs = self.configs.write().unwrap();
+		for folder in folders {
+			let mut config = Config::discover(folder).unwrap_or_else(|err| {
+				warn!("Could not load config for {:?}: {}", folder, err);
+				if let Some(client) = self.client.get() {
+					client.notify::<lsp_types::notification::ShowMessage>(lsp_types::ShowMessageParams {
+						typ: lsp_types::MessageType::WARNING,
+						message: format!("Using the default csskit config instead: {err}"),
+					});
+				}
+				Config::default()
+			});
+			config.root.get_or_insert_with(|| folder.clone());
+			configs.insert(folder.clone(), config);
+		}
+	}
+
+	/// Asks the client for its settings for each of the workspace folders, if it supports `workspace/configuration`.
+	/// The settings are stored once the client responds (see [Handler::on_response()]).
+	fn request_configuration(&self, folders: &[PathBuf]) {
+		let supported = self.capabilities.read().unwrap().workspace.as_ref().and_then(|w| w.configuration);
+		if folders.is_empty() || !supported.unwrap_or(false) {
+			return;
+		}
+		let Some(client) = self.client.get() else {
+			return;
+		};
+		let items = folders
+			.iter()
+			.map(|folder| lsp_types::ConfigurationItem {
+				scope_uri: workspace::path_to_uri(folder),
+				section: Some("csskit".into()),
+			})
+			.collect();
+		trace!("Requesting configuration for {:?}", &folders);
+		let id = client.request::<lsp_types::request::WorkspaceConfiguration>(lsp_types::ConfigurationParams { items });
+		self.configuration_requests.lock().unwrap().insert(id, folders.to_vec());
+	}
+
+	/// Asks the client to watch stylesheets & config files, if it supports registering for
+	/// `workspace/didChangeWatchedFiles`, so that changes made outside of the editor are indexed.
+	fn register_file_watchers(&self) {
+		let capabilities = self.capabilities.read().unwrap();
+		let watched_files = capabilities.workspace.as_ref().and_then(|w| w.did_change_watched_files);
+		if !watched_files.and_then(|w| w.dynamic_registration).unwrap_or(false) {
+			return;
+		}
+		drop(capabilities);
+		let Some(client) = self.client.get() else {
+			return;
+		};
+		let watchers = ["*.css"]
+			.into_iter()
+			.chain(csskit_config::CONFIG_FILE_NAMES)
+			.map(|name| lsp_types::FileSystemWatcher {
+				glob_pattern: lsp_types::GlobPattern::String(format!("**/{name}")),
+				kind: None,
+			})
+			.collect();
+		let options = lsp_types::DidChangeWatchedFilesRegistrationOptions { watchers };
+		client.request::<lsp_types::request::RegisterCapability>(lsp_types::RegistrationParams {
+			registrations: vec![lsp_types::Registration {
+				id: "csskit-watched-files".into(),
+				method: "workspace/didChangeWatchedFiles".into(),
+				register_options: serde_json::to_value(options).ok(),
+			}],
+		});
+	}
+
+	/// The client's settings for the workspace folder the path is in (the innermost, if folders are nested).
+	fn settings_for_path(&self, path: Option<&Path>) -> Settings {
+		let folder_settings = self.folder_settings.read().unwrap();
+		path.and_then(|path| innermost(&folder_settings, path))
+			.cloned()
+			.unwrap_or_else(|| self.settings.read().unwrap().clone())
+	}
+
+	/// The config of the workspace folder the path is in (the innermost, if folders are nested), overridden by the
+	/// client's settings.
+	fn config_for_path(&self, path: Option<&Path>) -> Config {
+		let config = path.and_then(|path| innermost(&self.configs.read().unwrap(), path).cloned()).unwrap_or_default();
+		config.merge(self.settings_for_path(path).config)
+	}
+
+	fn config_for(&self, uri: &Uri) -> Config {
+		self.config_for_path(workspace::uri_to_path(uri).as_deref())
+	}
+
+	/// Sends each open document its config, after the config files or settings change.
+	fn reconfigure(&self) {
+		for file in self.files.iter() {
+			file.configure(self.config_for(file.key()));
+		}
 	}
 }
 
+// The value of the workspace folder which the path is in, using the innermost if folders are nested.
+fn innermost<'a, T>(folders: &'a HashMap<PathBuf, T>, path: &Path) -> Option<&'a T> {
+	folders
+		.iter()
+		.filter(|(folder, _)| path.starts_with(folder))
+		.max_by_key(|(folder, _)| folder.components().count())
+		.map(|(_, value)| value)
+}
+
 impl Handler for LSPService {
 	#[instrument]
 	fn initialized(&self) -> bool {
 		self.initialized.load(Ordering::SeqCst)
 	}
 
+	fn connect(&self, client: Client) {
+		self.client.set(client).ok();
+	}
+
+	#[instrument]
+	fn on_response(&self, response: Response) {
+		let (Response::Ok(id, _) | Response::Err(id, ..)) = &response;
+		let Some(folders) = self.configuration_requests.lock().unwrap().remove(id) else {
+			return;
+		};
+		let Response::Ok(_, result) = response else {
+			warn!("Could not get the configuration of {:?}: {:?}", &folders, &response);
+			return;
+		};
+		let results: Vec<serde_json::Value> = serde_json::from_value(result).unwrap_or_default();
+		{
+			let mut folder_settings = self.folder_settings.write().unwrap();
+			for (folder, value) in folders.into_iter().zip(results) {
+				// Folders the client has no (valid) settings for use the settings which apply to every folder.
+				match Settings::from_value(value) {
+					Some(settings) => folder_settings.insert(folder, settings),
+					None => folder_settings.remove(&folder),
+				};
+			}
+		}
+		self.reconfigure();
+	}
+
 	#[instrument]
 	fn initialize(&self, req: lsp_types::InitializeParams) -> Result<lsp_types::InitializeResult, ErrorCode> {
 		self.initialized.swap(true, Ordering::SeqCst);
+		*self.capabilities.write().unwrap() = req.capabilities;
+		#[allow(deprecated)]
+		let folders = match (req.workspace_folders, req.root_uri) {
+			(Some(folders), _) => folders.into_iter().map(|folder| folder.uri).collect(),
+			(None, Some(root)) => vec![root],
+			(None, None) => vec![],
+		};
+		let folders: Vec<PathBuf> = folders.iter().filter_map(workspace::uri_to_path).collect();
+		self.load_configs(&folders);
+		*self.workspace_folders.write().unwrap() = folders;
+		if let Some(settings) = req.initialization_options.and_then(Settings::from_value) {
+			*self.settings.write().unwrap() = settings;
+		}
 		Ok(lsp_types::InitializeResult {
 			capabilities: lsp_types::ServerCapabilities {
 				// position_encoding: (),
@@ -157,7 +682,7 @@ impl Handler for LSPService {
 					},
 				)),
 				// notebook_document_sync: (),
-				// selection_range_provider: (),
+				selection_range_provider: Some(lsp_types::SelectionRangeProviderCapability::Simple(true)),
 				// hover_provider: (),
 				completion_provider: Some(lsp_types::CompletionOptions {
 					resolve_provider: None,
@@ -166,26 +691,56 @@ impl Handler for LSPService {
 					work_done_progress_options: lsp_types::WorkDoneProgressOptions { work_done_progress: None },
 					completion_item: None,
 				}),
-				// signature_help_provider: (),
-				// definition_provider: (),
+				signature_help_provider: Some(lsp_types::SignatureHelpOptions {
+					trigger_characters: Some(vec!["(".into(), ",".into(), "/".into()]),
+					retrigger_characters: Some(vec![" ".into()]),
+					work_done_progress_options: lsp_types::WorkDoneProgressOptions { work_done_progress: None },
+				}),
+				definition_provider: Some(lsp_types::OneOf::Left(true)),
 				// type_definition_provider: (),
 				// implementation_provider: (),
-				// references_provider: (),
+				references_provider: Some(lsp_types::OneOf::Left(true)),
 				// document_highlight_provider: (),
-				// document_symbol_provider: (),
-				// workspace_symbol_provider: (),
-				// code_action_provider: (),
+				document_symbol_provider: Some(lsp_types::OneOf::Left(true)),
+				workspace_symbol_provider: Some(lsp_types::OneOf::Left(true)),
+				code_action_provider: Some(lsp_types::CodeActionProviderCapability::Options(
+					lsp_types::CodeActionOptions {
+						code_action_kinds: Some(
+							[lsp_types::CodeActionKind::QUICKFIX]
+								.into_iter()
+								.chain(actions::SOURCE_ACTIONS.map(|(kind, _)| kind))
+								.collect(),
+						),
+						work_done_progress_options: lsp_types::WorkDoneProgressOptions { work_done_progress: None },
+						resolve_provider: Some(true),
+					},
+				)),
 				// code_lens_provider: (),
-				// document_formatting_provider: (),
-				// document_range_formatting_provider: (),
-				// document_on_type_formatting_provider: (),
-				// rename_provider: (),
-				// document_link_provider: (),
-				// color_provider: (),
-				// folding_range_provider: (),
+				document_formatting_provider: Some(lsp_types::OneOf::Left(true)),
+				document_range_formatting_provider: Some(lsp_types::OneOf::Left(true)),
+				document_on_type_formatting_provider: Some(lsp_types::DocumentOnTypeFormattingOptions {
+					first_trigger_character: ";".into(),
+					more_trigger_character: Some(vec!["}".into()]),
+				}),
+				rename_provider: Some(lsp_types::OneOf::Right(lsp_types::RenameOptions {
+					prepare_provider: Some(true),
+					work_done_progress_options: lsp_types::WorkDoneProgressOptions { work_done_progress: None },
+				})),
+				document_link_provider: Some(lsp_types::DocumentLinkOptions {
+					resolve_provider: Some(true),
+					work_done_progress_options: lsp_types::WorkDoneProgressOptions { work_done_progress: None },
+				}),
+				color_provider: Some(lsp_types::ColorProviderCapability::Simple(true)),
+				folding_range_provider: Some(lsp_types::FoldingRangeProviderCapability::Simple(true)),
 				// declaration_provider: (),
 				// execute_command_provider: (),
-				// workspace: (),
+				workspace: Some(lsp_types::WorkspaceServerCapabilities {
+					workspace_folders: Some(lsp_types::WorkspaceFoldersServerCapabilities {
+						supported: Some(true),
+						change_notifications: Some(lsp_types::OneOf::Left(true)),
+					}),
+					file_operations: None,
+				}),
 				// call_hierarchy_provider: (),
 				semantic_tokens_provider: Some(lsp_types::SemanticTokensServerCapabilities::SemanticTokensOptions(
 					lsp_types::SemanticTokensOptions {
@@ -202,15 +757,22 @@ impl Handler for LSPService {
 								.map(|v| lsp_types::SemanticTokenModifier::new(v))
 								.collect(),
 						},
-						range: Some(false),
+						range: Some(true),
 						full: Some(lsp_types::SemanticTokensFullOptions::Delta { delta: Some(true) }),
 					},
 				)),
 				// moniker_provider: (),
-				// linked_editing_range_provider: (),
+				linked_editing_range_provider: Some(lsp_types::LinkedEditingRangeServerCapabilities::Simple(true)),
 				// inline_value_provider: (),
-				// inlay_hint_provider: (),
-				// diagnostic_provider: (),
+				inlay_hint_provider: Some(lsp_types::OneOf::Left(true)),
+				diagnostic_provider: Some(lsp_types::DiagnosticServerCapabilities::Options(
+					lsp_types::DiagnosticOptions {
+						identifier: Some("csskit".into()),
+						inter_file_dependencies: false,
+						workspace_diagnostics: false,
+						work_done_progress_options: lsp_types::WorkDoneProgressOptions { work_done_progress: None },
+					},
+				)),
 				// inline_completion_provider: (),
 				// experimental: (),
 				..Default::default()
@@ -231,18 +793,370 @@ impl Handler for LSPService {
 		let uri = req.text_document.uri;
 		trace!("Asked for SemanticTokens for {:?}", &uri);
 		if let Some(document) = self.files.get(&uri) {
-			let data = document
-				.get_highlights()
-				.into_iter()
-				.map(|(highlight, delta_line, delta_start)| lsp_types::SemanticToken {
-					token_type: highlight.kind().bits() as u32,
-					token_modifiers_bitset: highlight.modifier().bits() as u32,
-					delta_line,
-					delta_start,
-					length: highlight.span().len(),
+			let data = document.get_semantic_tokens(None);
+			let result_id = document.semantic_tokens.lock().unwrap().store(data.clone());
+			Ok(Some(lsp_types::SemanticTokensResult::Tokens(lsp_types::SemanticTokens {
+				result_id: Some(result_id),
+				data,
+			})))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn semantic_tokens_full_delta_request(
+		&self,
+		req: lsp_types::SemanticTokensDeltaParams,
+	) -> Result<Option<lsp_types::SemanticTokensFullDeltaResult>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for SemanticTokens delta since {:?} for {:?}", &req.previous_result_id, &uri);
+		if let Some(document) = self.files.get(&uri) {
+			let data = document.get_semantic_tokens(None);
+			let mut cache = document.semantic_tokens.lock().unwrap();
+			// If the previous tokens are no longer cached the client is sent every token instead.
+			let edits =
+				cache.get(&req.previous_result_id).map(|previous| tokens::semantic_tokens_delta(previous, &data));
+			let result_id = Some(cache.store(data.clone()));
+			Ok(Some(match edits {
+				Some(edits) => lsp_types::SemanticTokensFullDeltaResult::TokensDelta(lsp_types::SemanticTokensDelta {
+					result_id,
+					edits,
+				}),
+				None => lsp_types::SemanticTokensFullDeltaResult::Tokens(lsp_types::SemanticTokens { result_id, data }),
+			}))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn semantic_tokens_range_request(
+		&self,
+		req: lsp_types::SemanticTokensRangeParams,
+	) -> Result<Option<lsp_types::SemanticTokensRangeResult>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for SemanticTokens in {:?} for {:?}", &req.range, &uri);
+		if let Some(document) = self.files.get(&uri) {
+			let data = document.get_semantic_tokens(Some(req.range));
+			Ok(Some(lsp_types::SemanticTokensRangeResult::Tokens(lsp_types::SemanticTokens { result_id: None, data })))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn document_symbol_request(
+		&self,
+		req: lsp_types::DocumentSymbolParams,
+	) -> Result<Option<lsp_types::DocumentSymbolResponse>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for DocumentSymbols for {:?}", &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(Some(lsp_types::DocumentSymbolResponse::Nested(document.get_document_symbols())))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn document_color(
+		&self,
+		req: lsp_types::DocumentColorParams,
+	) -> Result<Vec<lsp_types::ColorInformation>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for DocumentColors for {:?}", &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(document.get_document_colors())
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn color_presentation_request(
+		&self,
+		req: lsp_types::ColorPresentationParams,
+	) -> Result<Vec<lsp_types::ColorPresentation>, ErrorCode> {
+		trace!("Asked for ColorPresentations of {:?}", &req.color);
+		Ok(colors::presentations(req.color, req.range))
+	}
+
+	#[instrument]
+	fn inlay_hint_request(
+		&self,
+		req: lsp_types::InlayHintParams,
+	) -> Result<Option<Vec<lsp_types::InlayHint>>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for InlayHints in {:?} for {:?}", &req.range, &uri);
+		if let Some(document) = self.files.get(&uri) {
+			let settings = self.settings_for_path(workspace::uri_to_path(&uri).as_deref()).inlay_hints;
+			Ok(Some(document.get_inlay_hints(req.range, settings)))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn document_link_request(
+		&self,
+		req: lsp_types::DocumentLinkParams,
+	) -> Result<Option<Vec<lsp_types::DocumentLink>>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for DocumentLinks for {:?}", &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(Some(document.get_document_links()))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn document_link_resolve(&self, req: lsp_types::DocumentLink) -> Result<lsp_types::DocumentLink, ErrorCode> {
+		trace!("Asked to resolve DocumentLink {:?}", &req.data);
+		Ok(links::resolve_document_link(req))
+	}
+
+	#[instrument]
+	fn signature_help_request(
+		&self,
+		req: lsp_types::SignatureHelpParams,
+	) -> Result<Option<lsp_types::SignatureHelp>, ErrorCode> {
+		let uri = req.text_document_position_params.text_document.uri;
+		let position = req.text_document_position_params.position;
+		trace!("Asked for SignatureHelp at {:?} in {:?}", &position, &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(document.get_signature_help(position))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn folding_range_request(
+		&self,
+		req: lsp_types::FoldingRangeParams,
+	) -> Result<Option<Vec<lsp_types::FoldingRange>>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for FoldingRanges for {:?}", &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(Some(document.get_folding_ranges()))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn selection_range_request(
+		&self,
+		req: lsp_types::SelectionRangeParams,
+	) -> Result<Option<Vec<lsp_types::SelectionRange>>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for SelectionRanges at {:?} in {:?}", &req.positions, &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(Some(document.get_selection_ranges(req.positions)))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn document_diagnostic_request(
+		&self,
+		req: lsp_types::DocumentDiagnosticParams,
+	) -> Result<lsp_types::DocumentDiagnosticReportResult, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for Diagnostics for {:?}", &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(lsp_types::DocumentDiagnosticReportResult::Report(lsp_types::DocumentDiagnosticReport::Full(
+				lsp_types::RelatedFullDocumentDiagnosticReport {
+					related_documents: None,
+					full_document_diagnostic_report: lsp_types::FullDocumentDiagnosticReport {
+						result_id: None,
+						items: document.get_diagnostics(),
+					},
+				},
+			)))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn code_action_request(
+		&self,
+		req: lsp_types::CodeActionParams,
+	) -> Result<Option<lsp_types::CodeActionResponse>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked for CodeActions at {:?} in {:?}", &req.range, &uri);
+		let Some(document) = self.files.get(&uri) else {
+			return Err(ErrorCode::InternalError);
+		};
+		let only = req.context.only.unwrap_or_default();
+		let wanted = |kind: &lsp_types::CodeActionKind| {
+			only.is_empty()
+				|| only.iter().any(|only| {
+					kind.as_str() == only.as_str() || kind.as_str().starts_with(&format!("{}.", only.as_str()))
 				})
-				.collect();
-			Ok(Some(lsp_types::SemanticTokensResult::Tokens(lsp_types::SemanticTokens { result_id: None, data })))
+		};
+		let mut code_actions = vec![];
+		if wanted(&lsp_types::CodeActionKind::QUICKFIX) {
+			code_actions.extend(document.get_code_actions(req.range));
+		}
+		// Source actions are returned without edits, which are computed in code_action_resolve_request.
+		for (kind, title) in actions::SOURCE_ACTIONS {
+			if wanted(&kind) {
+				code_actions.push(lsp_types::CodeActionOrCommand::CodeAction(lsp_types::CodeAction {
+					title: title.into(),
+					kind: Some(kind),
+					data: Some(serde_json::json!({ "uri": uri.as_str() })),
+					..Default::default()
+				}));
+			}
+		}
+		Ok(Some(code_actions))
+	}
+
+	#[instrument]
+	fn code_action_resolve_request(&self, req: lsp_types::CodeAction) -> Result<lsp_types::CodeAction, ErrorCode> {
+		trace!("Asked to resolve CodeAction {:?}", &req.title);
+		let (Some(kind), Some(data)) = (&req.kind, &req.data) else {
+			return Ok(req);
+		};
+		let uri = data
+			.get("uri")
+			.and_then(|uri| uri.as_str())
+			.and_then(|uri| uri.parse::<Uri>().ok())
+			.ok_or(ErrorCode::InvalidParams)?;
+		if let Some(document) = self.files.get(&uri) {
+			let edits = document.get_source_action_edits(kind.clone());
+			Ok(lsp_types::CodeAction {
+				edit: Some(lsp_types::WorkspaceEdit {
+					changes: Some(std::collections::HashMap::from([(uri.clone(), edits)])),
+					..Default::default()
+				}),
+				..req
+			})
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn workspace_symbol_request(
+		&self,
+		req: lsp_types::WorkspaceSymbolParams,
+	) -> Result<Option<lsp_types::WorkspaceSymbolResponse>, ErrorCode> {
+		trace!("Asked for WorkspaceSymbols matching {:?}", &req.query);
+		Ok(Some(lsp_types::WorkspaceSymbolResponse::Nested(self.index.search(&req.query))))
+	}
+
+	#[instrument]
+	fn goto_definition(
+		&self,
+		req: lsp_types::GotoDefinitionParams,
+	) -> Result<lsp_types::GotoDefinitionResponse, ErrorCode> {
+		let uri = req.text_document_position_params.text_document.uri;
+		let position = req.text_document_position_params.position;
+		trace!("Asked for Definition of {:?} in {:?}", &position, &uri);
+		let definitions = self
+			.index
+			.symbol_at(&uri, position)
+			.map(|symbol| self.index.definitions(symbol.namespace, &symbol.name))
+			.unwrap_or_default();
+		Ok(lsp_types::GotoDefinitionResponse::Array(definitions))
+	}
+
+	#[instrument]
+	fn references(&self, req: lsp_types::ReferenceParams) -> Result<Option<Vec<lsp_types::Location>>, ErrorCode> {
+		let uri = req.text_document_position.text_document.uri;
+		let position = req.text_document_position.position;
+		trace!("Asked for References of {:?} in {:?}", &position, &uri);
+		Ok(self
+			.index
+			.symbol_at(&uri, position)
+			.map(|symbol| self.index.references(symbol.namespace, &symbol.name, req.context.include_declaration)))
+	}
+
+	#[instrument]
+	fn linked_editing_range(
+		&self,
+		req: lsp_types::LinkedEditingRangeParams,
+	) -> Result<Option<lsp_types::LinkedEditingRanges>, ErrorCode> {
+		let uri = req.text_document_position_params.text_document.uri;
+		let position = req.text_document_position_params.position;
+		trace!("Asked for LinkedEditingRanges at {:?} in {:?}", &position, &uri);
+		Ok(self.index.linked_ranges(&uri, position).map(|(namespace, ranges)| lsp_types::LinkedEditingRanges {
+			ranges,
+			word_pattern: namespace.word_pattern().map(String::from),
+		}))
+	}
+
+	#[instrument]
+	fn prepare_rename_request(
+		&self,
+		req: lsp_types::TextDocumentPositionParams,
+	) -> Result<Option<lsp_types::PrepareRenameResponse>, ErrorCode> {
+		trace!("Asked to PrepareRename {:?} in {:?}", &req.position, &req.text_document.uri);
+		Ok(self.index.symbol_at(&req.text_document.uri, req.position).map(|symbol| {
+			lsp_types::PrepareRenameResponse::RangeWithPlaceholder { range: symbol.range, placeholder: symbol.name }
+		}))
+	}
+
+	#[instrument]
+	fn rename(&self, req: lsp_types::RenameParams) -> Result<Option<lsp_types::WorkspaceEdit>, ErrorCode> {
+		let uri = req.text_document_position.text_document.uri;
+		let position = req.text_document_position.position;
+		trace!("Asked to Rename {:?} in {:?} to {:?}", &position, &uri, &req.new_name);
+		let Some(symbol) = self.index.symbol_at(&uri, position) else {
+			return Ok(None);
+		};
+		if !symbol.namespace.is_valid_name(&req.new_name) {
+			return Err(ErrorCode::InvalidParams);
+		}
+		let changes = self.index.rename(symbol.namespace, &symbol.name, &req.new_name);
+		Ok(Some(lsp_types::WorkspaceEdit { changes: Some(changes), ..Default::default() }))
+	}
+
+	#[instrument]
+	fn formatting(
+		&self,
+		req: lsp_types::DocumentFormattingParams,
+	) -> Result<Option<Vec<lsp_types::TextEdit>>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked to Format {:?}", &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(Some(document.get_format_edits(None)))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn range_formatting(
+		&self,
+		req: lsp_types::DocumentRangeFormattingParams,
+	) -> Result<Option<Vec<lsp_types::TextEdit>>, ErrorCode> {
+		let uri = req.text_document.uri;
+		trace!("Asked to Format {:?} in {:?}", &req.range, &uri);
+		if let Some(document) = self.files.get(&uri) {
+			Ok(Some(document.get_format_edits(Some(req.range))))
+		} else {
+			Err(ErrorCode::InternalError)
+		}
+	}
+
+	#[instrument]
+	fn on_type_formatting(
+		&self,
+		req: lsp_types::DocumentOnTypeFormattingParams,
+	) -> Result<Option<Vec<lsp_types::TextEdit>>, ErrorCode> {
+		let uri = req.text_document_position.text_document.uri;
+		let position = req.text_document_position.position;
+		trace!("Asked to Format {:?} in {:?} after typing {:?}", &position, &uri, &req.ch);
+		if let Some(document) = self.files.get(&uri) {
+			// Only the rule which was just typed into is formatted.
+			Ok(Some(document.get_format_edits(Some(lsp_types::Range::new(position, position)))))
 		} else {
 			Err(ErrorCode::InternalError)
 		}
@@ -256,11 +1170,106 @@ impl Handler for LSPService {
 		Ok(None)
 	}
 
+	#[instrument]
+	fn on_initialized(&self, _req: lsp_types::InitializedParams) {
+		let folders = self.workspace_folders.read().unwrap().clone();
+		trace!("Indexing workspace folders {:?}", &folders);
+		self.index_folders(&folders);
+		self.register_file_watchers();
+		self.request_configuration(&folders);
+	}
+
+	#[instrument]
+	fn on_did_change_configuration(&self, req: lsp_types::DidChangeConfigurationParams) {
+		trace!("Configuration changed to {:?}", &req.settings);
+		if let Some(settings) = Settings::from_value(req.settings) {
+			*self.settings.write().unwrap() = settings;
+			self.reconfigure();
+		}
+		// Clients which support `workspace/configuration` commonly send no settings, expecting them to be pulled.
+		let folders = self.workspace_folders.read().unwrap().clone();
+		self.request_configuration(&folders);
+	}
+
+	#[instrument]
+	fn on_did_change_workspace_folders(&self, req: lsp_types::DidChangeWorkspaceFoldersParams) {
+		let added: Vec<PathBuf> =
+			req.event.added.iter().filter_map(|folder| workspace::uri_to_path(&folder.uri)).collect();
+		let removed: Vec<PathBuf> =
+			req.event.removed.iter().filter_map(|folder| workspace::uri_to_path(&folder.uri)).collect();
+		trace!("Workspace folders changed, added {:?} removed {:?}", &added, &removed);
+		{
+			let mut folders = self.workspace_folders.write().unwrap();
+			folders.retain(|folder| !removed.contains(folder));
+			folders.extend(added.iter().cloned());
+		}
+		self.configs.write().unwrap().retain(|folder, _| !removed.contains(folder));
+		self.folder_settings.write().unwrap().retain(|folder, _| !removed.contains(folder));
+		self.load_configs(&added);
+		self.request_configuration(&added);
+		self.index.retain(|uri| {
+			self.files.contains_key(uri) || workspace::uri_to_path(uri).is_none_or(|path| self.is_in_workspace(&path))
+		});
+		self.index_folders(&added);
+	}
+
+	#[instrument]
+	fn on_did_change_watched_files(&self, req: lsp_types::DidChangeWatchedFilesParams) {
+		// A config file changing can change how every stylesheet is parsed & which are ignored, so everything is reloaded.
+		let is_config_file = |event: &lsp_types::FileEvent| {
+			workspace::uri_to_path(&event.uri).is_some_and(|path| csskit_config::is_config_file(&path))
+		};
+		if req.changes.iter().any(is_config_file) {
+			let folders = self.workspace_folders.read().unwrap().clone();
+			trace!("Config file changed, reloading config for {:?}", &folders);
+			self.load_configs(&folders);
+			self.reconfigure();
+			self.index_folders(&folders);
+		}
+		let mut changed = vec![];
+		for event in req.changes {
+			// Open documents are indexed from their (possibly unsaved) contents, so changes on disk are ignored.
+			if self.files.contains_key(&event.uri) {
+				continue;
+			}
+			let Some(path) = workspace::uri_to_path(&event.uri).filter(|path| workspace::is_stylesheet(path)) else {
+				continue;
+			};
+			trace!("Watched file {:?} changed ({:?})", &event.uri, event.typ);
+			if event.typ == lsp_types::FileChangeType::DELETED {
+				self.index.remove(&event.uri);
+			} else {
+				let config = self.config_for_path(Some(&path));
+				if !config.is_ignored(&path) {
+					changed.push((path, config.features()));
+				}
+			}
+		}
+		let files = self.files.clone();
+		workspace::index_in_background(self.index.clone(), changed, move |uri| files.contains_key(uri));
+	}
+
+	#[instrument]
+	fn on_did_close_text_document(&self, req: lsp_types::DidCloseTextDocumentParams) {
+		let uri = req.text_document.uri;
+		trace!("Closing document {:?}", &uri);
+		// Dropping the File drops its sender, which ends the document's thread.
+		self.files.remove(&uri);
+		// Any unsaved changes were discarded, so the index falls back to the file on disk, if it's part of the workspace.
+		match workspace::uri_to_path(&uri).filter(|path| path.is_file() && self.is_in_workspace(path)) {
+			Some(path) => {
+				let features = self.config_for_path(Some(&path)).features();
+				workspace::index_file(&self.index, uri, &path, features)
+			}
+			None => self.index.remove(&uri),
+		}
+	}
+
 	#[instrument]
 	fn on_did_open_text_document(&self, req: lsp_types::DidOpenTextDocumentParams) {
 		let uri = req.text_document.uri;
 		let source_text = req.text_document.text;
-		let mut doc = File::new();
+		let mut doc = File::new(uri.clone(), self.index.clone(), self.config_for(&uri));
 		let mut rope = doc.content.clone();
 		rope.remove(0..);
 		rope.insert(0, &source_text);
@@ -276,21 +1285,13 @@ impl Handler for LSPService {
 		if let Some(mut file) = self.files.clone().get_mut(&uri) {
 			let mut rope = file.content.clone();
 			for change in changes {
-				let range = if let Some(range) = change.range {
-					rope.try_line_to_char(range.start.line as usize).map_or_else(
-						|_| (0, None),
-						|start| {
-							rope.try_line_to_char(range.end.line as usize).map_or_else(
-								|_| (start + range.start.character as usize, None),
-								|end| {
-									(start + range.start.character as usize, Some(end + range.end.character as usize))
-								},
-							)
-						},
-					)
-				} else {
-					(0, None)
-				};
+				let range = change
+					.range
+					.and_then(|range| {
+						let start = position::position_to_char(&rope, range.start)?;
+						Some((start, position::position_to_char(&rope, range.end)))
+					})
+					.unwrap_or((0, None));
 				match range {
 					(start, None) => {
 						rope.try_remove(start..).ok();
@@ -0,0 +1,155 @@
+use css_ast::StyleSheet;
+use csskit_lint::{Edit, Lint, physical_to_logical, sort_declarations};
+use lsp_types::{
+	CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, DiagnosticSeverity, NumberOrString, Range, TextEdit,
+	Uri, WorkspaceEdit,
+};
+use miette::Severity;
+use ropey::Rope;
+use std::collections::HashMap;
+
+use super::position::span_to_range;
+
+pub(crate) const SORT_DECLARATIONS: CodeActionKind = CodeActionKind::new("source.sortDeclarations");
+pub(crate) const CONVERT_TO_LOGICAL: CodeActionKind = CodeActionKind::new("source.convertToLogical");
+
+/// The source actions offered for every document. Computing their edits means walking the whole document, so they're
+/// only computed once the client asks to resolve the action.
+pub(crate) const SOURCE_ACTIONS: [(CodeActionKind, &str); 3] = [
+	(CodeActionKind::SOURCE_FIX_ALL, "Fix all auto-fixable problems"),
+	(SORT_DECLARATIONS, "Sort declarations"),
+	(CONVERT_TO_LOGICAL, "Convert physical properties to logical properties"),
+];
+
+fn severity(severity: Option<Severity>) -> DiagnosticSeverity {
+	match severity {
+		Some(Severity::Advice) => DiagnosticSeverity::HINT,
+		Some(Severity::Warning) => DiagnosticSeverity::WARNING,
+		Some(Severity::Error) | None => DiagnosticSeverity::ERROR,
+	}
+}
+
+/// Converts each parse error & [Lint] into an LSP [Diagnostic].
+pub(crate) fn diagnostics(rope: &Rope, errors: &[miette::Error], lints: &[Lint]) -> Vec<Diagnostic> {
+	let errors = errors.iter().map(|error| {
+		let span = error.labels().and_then(|mut labels| labels.next()).map_or((0, 0), |l| (l.offset(), l.len()));
+		let start = css_lexer::SourceOffset(span.0 as u32);
+		let end = css_lexer::SourceOffset((span.0 + span.1) as u32);
+		Diagnostic {
+			range: span_to_range(rope, css_lexer::Span::new(start, end)),
+			severity: Some(severity(error.severity())),
+			code: error.code().map(|code| NumberOrString::String(code.to_string())),
+			source: Some("csskit".into()),
+			message: error.to_string(),
+			..Default::default()
+		}
+	});
+	let lints = lints.iter().map(|lint| lint_diagnostic(rope, lint));
+	errors.chain(lints).collect()
+}
+
+fn lint_diagnostic(rope: &Rope, lint: &Lint) -> Diagnostic {
+	Diagnostic {
+		range: span_to_range(rope, lint.span),
+		severity: Some(severity(Some(lint.severity))),
+		code: Some(NumberOrString::String(lint.rule.to_string())),
+		source: Some("csskit".into()),
+		message: lint.message.clone(),
+		..Default::default()
+	}
+}
+
+pub(crate) fn text_edits(rope: &Rope, edits: &[Edit]) -> Vec<TextEdit> {
+	edits.iter().map(|edit| TextEdit::new(span_to_range(rope, edit.span), edit.text.clone())).collect()
+}
+
+fn intersects(a: Range, b: Range) -> bool {
+	a.start <= b.end && b.start <= a.end
+}
+
+/// Returns a quick fix for each fixable [Lint] which intersects the given [Range].
+pub(crate) fn quick_fixes(uri: &Uri, rope: &Rope, lints: &[Lint], range: Range) -> Vec<CodeActionOrCommand> {
+	lints
+		.iter()
+		.filter_map(|lint| {
+			let fix = lint.fix.as_ref()?;
+			let diagnostic = lint_diagnostic(rope, lint);
+			if !intersects(diagnostic.range, range) {
+				return None;
+			}
+			Some(CodeActionOrCommand::CodeAction(CodeAction {
+				title: fix.title.clone(),
+				kind: Some(CodeActionKind::QUICKFIX),
+				diagnostics: Some(vec![diagnostic]),
+				edit: Some(WorkspaceEdit {
+					changes: Some(HashMap::from([(uri.clone(), text_edits(rope, &fix.edits))])),
+					..Default::default()
+				}),
+				is_preferred: Some(true),
+				..Default::default()
+			}))
+		})
+		.collect()
+}
+
+/// Computes the edits of a source action, which must be one of [SOURCE_ACTIONS].
+pub(crate) fn source_action_edits(
+	kind: &CodeActionKind,
+	source: &str,
+	stylesheet: &StyleSheet,
+	lints: Vec<Lint>,
+) -> Vec<Edit> {
+	if *kind == CodeActionKind::SOURCE_FIX_ALL {
+		lints.into_iter().filter_map(|lint| lint.fix).flat_map(|fix| fix.edits).collect()
+	} else if *kind == SORT_DECLARATIONS {
+		sort_declarations(source, stylesheet)
+	} else if *kind == CONVERT_TO_LOGICAL {
+		physical_to_logical(source, stylesheet)
+	} else {
+		vec![]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_parse::Parser;
+	use lsp_types::Position;
+	use std::str::FromStr;
+
+	#[test]
+	fn test_diagnostics() {
+		let source = "a:before{word-wrap:normal}\nb{color:}";
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let lints = csskit_lint::lint(source, result.output.as_ref().unwrap());
+		let diagnostics = diagnostics(&Rope::from_str(source), &result.errors, &lints);
+		let codes: Vec<_> = diagnostics.iter().filter_map(|d| d.code.clone()).collect();
+		assert!(codes.contains(&NumberOrString::String("legacy-pseudo-element".into())));
+		assert!(codes.contains(&NumberOrString::String("deprecated-property".into())));
+		let deprecated = diagnostics.iter().find(|d| d.message.contains("word-wrap")).unwrap();
+		assert_eq!(deprecated.range, Range::new(Position::new(0, 9), Position::new(0, 18)));
+		assert_eq!(deprecated.severity, Some(DiagnosticSeverity::WARNING));
+	}
+
+	#[test]
+	fn test_quick_fixes() {
+		let source = "a:before{word-wrap:normal}";
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let lints = csskit_lint::lint(source, result.output.as_ref().unwrap());
+		let uri = Uri::from_str("file:///a.css").unwrap();
+		let rope = Rope::from_str(source);
+		let actions = quick_fixes(&uri, &rope, &lints, Range::new(Position::new(0, 10), Position::new(0, 10)));
+		assert_eq!(actions.len(), 1);
+		let CodeActionOrCommand::CodeAction(action) = &actions[0] else { panic!("expected a CodeAction") };
+		assert_eq!(action.title, "Replace `word-wrap` with `overflow-wrap`");
+		let edits = &action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri];
+		assert_eq!(
+			edits,
+			&vec![TextEdit::new(Range::new(Position::new(0, 9), Position::new(0, 18)), "overflow-wrap".into())]
+		);
+		assert_eq!(quick_fixes(&uri, &rope, &lints, Range::new(Position::new(0, 0), Position::new(0, 30))).len(), 2);
+	}
+}
@@ -0,0 +1,181 @@
+use bumpalo::Bump;
+use css_ast::{Color, Srgba, Visit, property_accepts_color};
+use css_lexer::{Cursor, Kind, SourceOffset, Span, ToSpan};
+use css_parse::{CursorCollector, Declaration, DeclarationValue, Parser};
+use lsp_types::{ColorInformation, ColorPresentation, Range, TextEdit};
+use ropey::Rope;
+
+use super::position::span_to_range;
+
+/// A [Visit] pass which finds every [Color] within declaration values (including custom properties), alongside the
+/// color it resolves to. Colors which cannot be resolved without knowing where they're used (e.g. `currentcolor`) are
+/// skipped, as are values of properties whose grammar doesn't accept a color, so `gold` in `animation: gold 1s` (an
+/// animation name) is left alone.
+#[derive(Default)]
+pub(crate) struct ColorCollector<'s> {
+	source: &'s str,
+	colors: Vec<(Span, Srgba)>,
+}
+
+impl<'s> ColorCollector<'s> {
+	pub fn new(source: &'s str) -> Self {
+		Self { source, colors: vec![] }
+	}
+
+	pub fn into_colors(self, rope: &Rope) -> Vec<ColorInformation> {
+		self.colors
+			.into_iter()
+			.map(|(span, color)| ColorInformation {
+				range: span_to_range(rope, span),
+				color: lsp_types::Color { red: color.red, green: color.green, blue: color.blue, alpha: color.alpha },
+			})
+			.collect()
+	}
+
+	// Declaration values are not all typed (for example custom properties, or properties whose values can't be parsed
+	// yet), so rather than walking the value each token which could begin a color is re-parsed as one.
+	fn parse_color(&self, offset: SourceOffset) -> Option<(Span, Srgba)> {
+		let source = &self.source[offset.0 as usize..];
+		let bump = Bump::default();
+		let color = Parser::new(&bump, source).parse::<Color>().ok()?;
+		let srgba = color.to_srgba(source)?;
+		let span = color.to_span();
+		Some((Span::new(SourceOffset(offset.0 + span.start().0), SourceOffset(offset.0 + span.end().0)), srgba))
+	}
+}
+
+impl Visit for ColorCollector<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		let name = Cursor::from(declaration.name).str_slice(self.source).to_ascii_lowercase();
+		if property_accepts_color(&name) == Some(false) {
+			return;
+		}
+		let mut cursors = CursorCollector::default();
+		declaration.value.to_cursors(&mut cursors);
+		let mut end = SourceOffset(0);
+		for c in cursors.0 {
+			if c.offset() < end || !matches!(c.token().kind(), Kind::Hash | Kind::Ident | Kind::Function) {
+				continue;
+			}
+			if let Some((span, color)) = self.parse_color(c.offset()) {
+				end = span.end();
+				self.colors.push((span, color));
+			}
+		}
+	}
+}
+
+// Formats a number with at most `precision` decimal places, dropping any trailing zeros.
+pub(crate) fn number(value: f32, precision: usize) -> String {
+	let str = format!("{value:.precision$}");
+	let str = if str.contains('.') { str.trim_end_matches('0').trim_end_matches('.') } else { &str };
+	if str == "-0" { "0".into() } else { str.into() }
+}
+
+fn with_alpha(name: &str, channels: [String; 3], alpha: f32) -> String {
+	let [a, b, c] = channels;
+	if alpha < 1.0 { format!("{name}({a} {b} {c} / {})", number(alpha, 3)) } else { format!("{name}({a} {b} {c})") }
+}
+
+/// Returns each notation the given color can be written in, in order of: hex, `rgb()`, `hsl()`, `hwb()`, `lab()`,
+/// `lch()`, `oklab()` and `oklch()`. Each replaces the given [Range].
+pub(crate) fn presentations(color: lsp_types::Color, range: Range) -> Vec<ColorPresentation> {
+	let color = Srgba::new(color.red, color.green, color.blue, color.alpha);
+	let alpha = color.alpha;
+	let [r, g, b] = color.to_rgb8();
+	let [h, s, l] = color.to_hsl();
+	let [hw, w, bl] = color.to_hwb();
+	let [lab_l, lab_a, lab_b] = color.to_lab();
+	let [lch_l, lch_c, lch_h] = color.to_lch();
+	let [ok_l, ok_a, ok_b] = color.to_oklab();
+	let [okch_l, okch_c, okch_h] = color.to_oklch();
+	let percent = |v: f32| format!("{}%", number(v * 100.0, 2));
+	[
+		color.to_hex(),
+		with_alpha("rgb", [r, g, b].map(|v| v.to_string()), alpha),
+		with_alpha("hsl", [number(h, 2), percent(s), percent(l)], alpha),
+		with_alpha("hwb", [number(hw, 2), percent(w), percent(bl)], alpha),
+		with_alpha("lab", [number(lab_l, 2), number(lab_a, 2), number(lab_b, 2)], alpha),
+		with_alpha("lch", [number(lch_l, 2), number(lch_c, 2), number(lch_h, 2)], alpha),
+		with_alpha("oklab", [number(ok_l, 4), number(ok_a, 4), number(ok_b, 4)], alpha),
+		with_alpha("oklch", [percent(okch_l), number(okch_c, 4), number(okch_h, 2)], alpha),
+	]
+	.into_iter()
+	.map(|label| ColorPresentation {
+		text_edit: Some(TextEdit::new(range, label.clone())),
+		label,
+		additional_text_edits: None,
+	})
+	.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_ast::{StyleSheet, Visitable};
+	use lsp_types::Position;
+
+	fn colors(source: &str) -> Vec<(String, [u8; 4])> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut collector = ColorCollector::new(source);
+		result.output.unwrap().accept(&mut collector);
+		collector
+			.colors
+			.into_iter()
+			.map(|(span, c)| {
+				let [r, g, b] = c.to_rgb8();
+				(span.span_contents(source).contents().to_owned(), [r, g, b, (c.alpha * 255.0).round() as u8])
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_document_colors() {
+		assert_eq!(
+			colors("a{color:#3366ff;background:url(a.png) red;border:1px solid rgb(0 0 0/50%)}"),
+			vec![
+				("#3366ff".into(), [0x33, 0x66, 0xff, 255]),
+				("red".into(), [255, 0, 0, 255]),
+				("rgb(0 0 0/50%)".into(), [0, 0, 0, 128])
+			]
+		);
+		assert_eq!(
+			colors(":root{--brand:oklch(62.8% 0.2577 29.23);--main:#main}\nb{color:currentcolor;fill:var(--x, lime)}"),
+			vec![("oklch(62.8% 0.2577 29.23)".into(), [255, 0, 0, 255]), ("lime".into(), [0, 255, 0, 255])]
+		);
+	}
+
+	#[test]
+	fn test_non_color_values() {
+		assert_eq!(
+			colors("@keyframes red{to{opacity:1}}a{animation:gold 1s;animation-name:red;font-family:Tomato,serif}"),
+			vec![]
+		);
+		assert_eq!(colors("a{animation:gold 1s;color:gold}"), vec![("gold".into(), [255, 215, 0, 255])]);
+	}
+
+	#[test]
+	fn test_presentations() {
+		let range = Range::new(Position::new(0, 8), Position::new(0, 15));
+		let color = lsp_types::Color { red: 0.2, green: 0.4, blue: 1.0, alpha: 1.0 };
+		let labels: Vec<_> = presentations(color, range).into_iter().map(|p| p.label).collect();
+		assert_eq!(
+			labels,
+			vec![
+				"#36f",
+				"rgb(51 102 255)",
+				"hsl(225 100% 60%)",
+				"hwb(225 20% 0%)",
+				"lab(47.4 26.93 -82.47)",
+				"lch(47.4 86.76 288.08)",
+				"oklab(0.5726 -0.0192 -0.233)",
+				"oklch(57.26% 0.2338 265.28)",
+			]
+		);
+		let color = lsp_types::Color { red: 0.0, green: 0.0, blue: 0.0, alpha: 0.5 };
+		let presentation = presentations(color, range).swap_remove(1);
+		assert_eq!(presentation.label, "rgb(0 0 0 / 0.5)");
+		assert_eq!(presentation.text_edit, Some(TextEdit::new(range, "rgb(0 0 0 / 0.5)".into())));
+	}
+}
@@ -0,0 +1,140 @@
+use css_ast::StyleSheet;
+use css_lexer::{Cursor, SourceOffset, Span, ToSpan};
+use css_parse::{CursorPrettyWriteSink, CursorTriviaSink, ToCursors};
+use lsp_types::{Range, TextEdit};
+use ropey::Rope;
+use similar::{DiffOp, TextDiff};
+use std::time::Duration;
+
+use super::position::char_to_position;
+
+/// Pretty prints the top level rules of the stylesheet which intersect the given [Span] (or all rules, if no [Span] is
+/// given) with each level of nesting indented by `indent`, returning the formatted text alongside the [Span] of the
+/// source that it replaces.
+pub(crate) fn format(
+	source: &str,
+	stylesheet: &StyleSheet,
+	trivia: &[Cursor],
+	selection: Option<Span>,
+	indent: &str,
+) -> Option<(Span, String)> {
+	let rules: Vec<_> = stylesheet
+		.rules
+		.iter()
+		.filter(|rule| {
+			let span = rule.to_span();
+			selection.is_none_or(|selection| span.start() <= selection.end() && selection.start() <= span.end())
+		})
+		.collect();
+	let span = match selection {
+		None => Span::new(SourceOffset(0), SourceOffset(source.len() as u32)),
+		Some(_) => rules.first()?.to_span() + rules.last()?.to_span(),
+	};
+	let trivia: Vec<_> = trivia.iter().copied().filter(|c| span.contains(c.span())).collect();
+	let mut formatted = String::new();
+	let mut pretty = CursorPrettyWriteSink::new(source, &mut formatted).with_indent(indent);
+	let mut sink = CursorTriviaSink::new(&trivia, &mut pretty);
+	for rule in rules {
+		rule.to_cursors(&mut sink);
+	}
+	sink.finish();
+	pretty.finish().ok()?;
+	// A selection replaces just the rules, leaving the whitespace around them as-is.
+	if selection.is_some() {
+		formatted.truncate(formatted.trim_end().len());
+	}
+	Some((span, formatted))
+}
+
+/// Diffs the `formatted` text against the `span` of the document it replaces, returning the smallest set of
+/// [TextEdits][TextEdit] which turn one into the other.
+pub(crate) fn minimal_edits(rope: &Rope, span: Span, formatted: &str) -> Vec<TextEdit> {
+	let original = rope.byte_slice(span.start().0 as usize..span.end().0 as usize).to_string();
+	let diff = TextDiff::configure().timeout(Duration::from_millis(500)).diff_chars(original.as_str(), formatted);
+	let base = rope.byte_to_char(span.start().0 as usize);
+	let new_chars: Vec<char> = formatted.chars().collect();
+	let mut edits: Vec<(usize, usize, String)> = vec![];
+	let mut last_equal = true;
+	for op in diff.ops() {
+		if let DiffOp::Equal { .. } = op {
+			last_equal = true;
+			continue;
+		}
+		let (old, new) = (op.old_range(), op.new_range());
+		let text: String = new_chars[new.clone()].iter().collect();
+		match edits.last_mut() {
+			Some((_, end, last_text)) if !last_equal => {
+				*end = base + old.end;
+				last_text.push_str(&text);
+			}
+			_ => edits.push((base + old.start, base + old.end, text)),
+		}
+		last_equal = false;
+	}
+	edits
+		.into_iter()
+		.map(|(start, end, text)| {
+			TextEdit::new(Range::new(char_to_position(rope, start), char_to_position(rope, end)), text)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::service::position::position_to_char;
+	use bumpalo::Bump;
+	use css_parse::Parser;
+	use lsp_types::Position;
+
+	fn apply(source: &str, edits: Vec<TextEdit>) -> String {
+		let mut rope = Rope::from_str(source);
+		for edit in edits.into_iter().rev() {
+			let start = position_to_char(&rope, edit.range.start).unwrap();
+			let end = position_to_char(&rope, edit.range.end).unwrap();
+			rope.remove(start..end);
+			rope.insert(start, &edit.new_text);
+		}
+		rope.to_string()
+	}
+
+	fn format_source(source: &str, selection: Option<Span>) -> String {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let rope = Rope::from_str(source);
+		let (span, formatted) =
+			format(source, result.output.as_ref().unwrap(), &result.trivia, selection, "\t").unwrap();
+		apply(source, minimal_edits(&rope, span, &formatted))
+	}
+
+	#[test]
+	fn test_format_document() {
+		assert_eq!(format_source("a{color:red}\n/* b */\nb { }", None), "a {\n\tcolor: red;\n}\n\n/* b */\nb {}\n");
+	}
+
+	#[test]
+	fn test_format_custom_property_block() {
+		let formatted = format_source("a{--x:{a:b};--y:1px}", None);
+		assert_eq!(formatted, "a {\n\t--x: {a:b};\n\t--y: 1px;\n}\n");
+		assert_eq!(format_source(&formatted, None), formatted);
+	}
+
+	#[test]
+	fn test_format_selection() {
+		let source = "a{color:red}\n\nb{color:blue}\n\nc{color:green}\n";
+		let selection = Span::new(SourceOffset(16), SourceOffset(17));
+		assert_eq!(
+			format_source(source, Some(selection)),
+			"a{color:red}\n\nb {\n\tcolor: blue;\n}\n\nc{color:green}\n"
+		);
+	}
+
+	#[test]
+	fn test_minimal_edits() {
+		let source = "a {\n\tcolor:red;\n}\n";
+		let rope = Rope::from_str(source);
+		let span = Span::new(SourceOffset(0), SourceOffset(source.len() as u32));
+		let edits = minimal_edits(&rope, span, "a {\n\tcolor: red;\n}\n");
+		assert_eq!(edits, vec![TextEdit::new(Range::new(Position::new(1, 7), Position::new(1, 7)), " ".into())]);
+	}
+}
@@ -0,0 +1,225 @@
+use bumpalo::Bump;
+use css_ast::{Color, Specificity, StyleRule, ToSpecificity, Visit};
+use css_lexer::{Cursor, DimensionUnit, Kind, SourceOffset, ToSpan};
+use css_parse::{CursorCollector, Declaration, DeclarationValue, Parser};
+use lsp_types::{InlayHint, InlayHintLabel, InlayHintTooltip, Range};
+use ropey::Rope;
+use serde::Deserialize;
+
+use super::{colors::number, index::Index, position::offset_to_position};
+
+// Long custom property values are truncated, so that the hint doesn't push the rest of the line out of view.
+const MAX_VALUE_LENGTH: usize = 32;
+
+/// Which kinds of [InlayHint] are shown, configured by the client with the `inlayHints` setting.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub(crate) struct InlayHintSettings {
+	/// Show the specificity of each selector.
+	pub specificity: bool,
+	/// Show the value of `var()` references to custom properties which have a single static definition.
+	pub variables: bool,
+	/// Show the pixel equivalent of `rem` & `em` lengths.
+	pub pixels: bool,
+	/// Show the resolved color of named colors, such as `rebeccapurple`.
+	pub colors: bool,
+	/// The font size, in pixels, used to compute the pixel equivalents of `rem` & `em` lengths.
+	pub root_font_size: f32,
+}
+
+impl Default for InlayHintSettings {
+	fn default() -> Self {
+		Self { specificity: true, variables: true, pixels: true, colors: true, root_font_size: 16.0 }
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum HintLabel {
+	Specificity(Specificity),
+	Variable(String),
+	Pixels(f32),
+	Color(String),
+}
+
+/// A [Visit] pass which finds each [InlayHint] to show in a document, as configured by [InlayHintSettings].
+///
+/// The pixel equivalent of an `em` length assumes its element's font size is the root font size, as the real one
+/// depends on the document the stylesheet is applied to.
+#[derive(Default)]
+pub(crate) struct InlayHintCollector<'s> {
+	source: &'s str,
+	settings: InlayHintSettings,
+	hints: Vec<(SourceOffset, HintLabel)>,
+}
+
+impl<'s> InlayHintCollector<'s> {
+	pub fn new(source: &'s str, settings: InlayHintSettings) -> Self {
+		Self { source, settings, hints: vec![] }
+	}
+
+	/// Returns the hints which are positioned within the [Range], resolving `var()` references against the [Index].
+	pub fn into_hints(self, rope: &Rope, index: &Index, range: Range) -> Vec<InlayHint> {
+		let mut hints = self.hints;
+		hints.sort_by_key(|(offset, _)| *offset);
+		hints
+			.into_iter()
+			.filter_map(|(offset, label)| {
+				let position = offset_to_position(rope, offset);
+				if position < range.start || range.end < position {
+					return None;
+				}
+				let (label, tooltip) = match label {
+					HintLabel::Specificity(specificity) => {
+						(specificity.to_string(), Some("Specificity (ids, classes, types)"))
+					}
+					HintLabel::Variable(name) => (format!("= {}", truncate(&index.static_value(&name)?)), None),
+					HintLabel::Pixels(pixels) => (format!("= {}px", number(pixels, 2)), None),
+					HintLabel::Color(hex) => (format!("= {hex}"), None),
+				};
+				Some(InlayHint {
+					position,
+					label: InlayHintLabel::String(label),
+					kind: None,
+					text_edits: None,
+					tooltip: tooltip.map(|tooltip| InlayHintTooltip::String(tooltip.into())),
+					padding_left: Some(true),
+					padding_right: None,
+					data: None,
+				})
+			})
+			.collect()
+	}
+
+	fn named_color(&self, c: Cursor) -> Option<String> {
+		let source = &self.source[c.offset().0 as usize..c.end_offset().0 as usize];
+		let bump = Bump::default();
+		match Parser::new(&bump, source).parse_entirely::<Color>().output? {
+			color @ Color::Named(_) => color.to_srgba(source).map(|color| color.to_hex()),
+			_ => None,
+		}
+	}
+}
+
+fn truncate(value: &str) -> String {
+	let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+	if value.chars().count() > MAX_VALUE_LENGTH {
+		format!("{}…", value.chars().take(MAX_VALUE_LENGTH - 1).collect::<String>())
+	} else {
+		value
+	}
+}
+
+impl Visit for InlayHintCollector<'_> {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		if self.settings.specificity {
+			for (selector, _) in &rule.0.prelude.0 {
+				self.hints.push((selector.to_span().end(), HintLabel::Specificity(selector.specificity())));
+			}
+		}
+	}
+
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		let mut cursors = CursorCollector::default();
+		declaration.value.to_cursors(&mut cursors);
+		// Each open function, alongside the custom property it references if it's a `var()`.
+		let mut functions: Vec<Option<String>> = vec![];
+		for (i, c) in cursors.0.iter().copied().enumerate() {
+			match c.token().kind() {
+				Kind::Function | Kind::LeftParen => functions.push(None),
+				Kind::RightParen => {
+					if let Some(Some(name)) = functions.pop() {
+						self.hints.push((c.end_offset(), HintLabel::Variable(name)));
+					}
+				}
+				Kind::Ident if i > 0 && cursors.0[i - 1] == Kind::Function => {
+					let function = cursors.0[i - 1].str_slice(self.source);
+					if self.settings.variables && c.token().is_dashed_ident() && function.eq_ignore_ascii_case("var(") {
+						if let Some(var) = functions.last_mut() {
+							*var = Some(c.str_slice(self.source).into());
+						}
+					}
+				}
+				Kind::Ident if self.settings.colors => {
+					if let Some(hex) = self.named_color(c) {
+						self.hints.push((c.end_offset(), HintLabel::Color(hex)));
+					}
+				}
+				Kind::Dimension if self.settings.pixels => {
+					if matches!(c.token().dimension_unit(), DimensionUnit::Rem | DimensionUnit::Em) {
+						let pixels = c.token().value() * self.settings.root_font_size;
+						self.hints.push((c.end_offset(), HintLabel::Pixels(pixels)));
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use css_ast::{StyleSheet, Visitable};
+	use lsp_types::Position;
+
+	fn hints(source: &str, settings: InlayHintSettings) -> Vec<(u32, String)> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let rope = Rope::from_str(source);
+		let index = Index::default();
+		let mut collector = super::super::index::SymbolCollector::new(source);
+		result.output.as_ref().unwrap().accept(&mut collector);
+		index.update("file:///a.css".parse().unwrap(), collector.into_symbols(&rope));
+		let mut hints = InlayHintCollector::new(source, settings);
+		result.output.unwrap().accept(&mut hints);
+		let range = Range::new(Position::new(0, 0), Position::new(u32::MAX, 0));
+		hints
+			.into_hints(&rope, &index, range)
+			.into_iter()
+			.map(|hint| {
+				let InlayHintLabel::String(label) = hint.label else { panic!("expected a string label") };
+				(hint.position.character, label)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_specificity() {
+		let settings = InlayHintSettings { variables: false, pixels: false, colors: false, ..Default::default() };
+		assert_eq!(
+			hints("#a .b, c{d{}}", settings),
+			vec![(5, "(1, 1, 0)".into()), (8, "(0, 0, 1)".into()), (10, "(0, 0, 1)".into())]
+		);
+	}
+
+	#[test]
+	fn test_values() {
+		let settings = InlayHintSettings { specificity: false, ..Default::default() };
+		assert_eq!(
+			hints(":root{--a:1.5rem;--b:var(--a)}a{margin:var(--a) 2em;color:var(--b, red)}", settings),
+			vec![
+				(16, "= 24px".into()),
+				(29, "= 1.5rem".into()),
+				(47, "= 1.5rem".into()),
+				(51, "= 32px".into()),
+				(70, "= #f00".into())
+			]
+		);
+		let settings =
+			InlayHintSettings { root_font_size: 10.0, colors: false, specificity: false, ..Default::default() };
+		assert_eq!(hints("a{padding:.5em 2px}", settings), vec![(14, "= 5px".into())]);
+	}
+
+	#[test]
+	fn test_range() {
+		let source = "a{color:red}\nb{color:blue}";
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut hints = InlayHintCollector::new(source, InlayHintSettings::default());
+		result.output.unwrap().accept(&mut hints);
+		let range = Range::new(Position::new(1, 0), Position::new(1, 20));
+		let hints = hints.into_hints(&Rope::from_str(source), &Index::default(), range);
+		let positions: Vec<_> = hints.into_iter().map(|hint| hint.position).collect();
+		assert_eq!(positions, vec![Position::new(1, 1), Position::new(1, 12)]);
+	}
+}
@@ -0,0 +1,609 @@
+use css_ast::{Class, ContainerRule, CounterStyleName, KeyframesName, LayerName, PropertyPrelude, Visit};
+use css_lexer::{Cursor, Kind, Lexer, Span, ToSpan};
+use css_parse::{CursorCollector, Declaration, DeclarationValue};
+use dashmap::DashMap;
+use lsp_types::{Location, OneOf, Position, Range, SymbolKind, TextEdit, Uri, WorkspaceSymbol};
+use ropey::Rope;
+use std::collections::HashMap;
+
+use super::{
+	position::span_to_range,
+	symbols::{fuzzy_match, name_span},
+};
+
+/// Names only refer to one another within the same namespace; a `@keyframes foo` is unrelated to a
+/// `container-name: foo`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum SymbolNamespace {
+	CustomProperty,
+	Keyframes,
+	Layer,
+	Container,
+	Anchor,
+	CounterStyle,
+	Class,
+}
+
+impl SymbolNamespace {
+	pub fn symbol_kind(&self) -> SymbolKind {
+		match self {
+			Self::CustomProperty => SymbolKind::VARIABLE,
+			Self::Keyframes => SymbolKind::EVENT,
+			Self::Layer => SymbolKind::MODULE,
+			Self::Container => SymbolKind::NAMESPACE,
+			Self::Anchor => SymbolKind::KEY,
+			Self::CounterStyle => SymbolKind::ENUM,
+			Self::Class => SymbolKind::CLASS,
+		}
+	}
+
+	/// Checks if `name` can be used as a name in this namespace. The name is tokenized as CSS, so it may contain
+	/// escape sequences (e.g. `foo\.bar`), but it must form a single identifier (or a dot separated list of
+	/// identifiers for layers), which must be a `<dashed-ident>` for custom properties and anchors.
+	pub fn is_valid_name(&self, name: &str) -> bool {
+		let is_ident = |name: &str| {
+			let token = Lexer::new(name).advance();
+			token == Kind::Ident && token.len() as usize == name.len()
+		};
+		match self {
+			Self::CustomProperty | Self::Anchor => is_ident(name) && name.starts_with("--"),
+			Self::Layer => name.split('.').all(is_ident),
+			Self::Keyframes | Self::Container | Self::CounterStyle => {
+				is_ident(name) && !RESERVED_NAMES.iter().any(|reserved| name.eq_ignore_ascii_case(reserved))
+			}
+			Self::Class => is_ident(name),
+		}
+	}
+
+	/// A regular expression matching the names which can be typed while linked editing names of this namespace, or
+	/// [None] if names of this namespace aren't linked.
+	pub fn word_pattern(&self) -> Option<&'static str> {
+		match self {
+			Self::CustomProperty => Some(r"--[-_a-zA-Z0-9\u0080-\uffff]*"),
+			Self::Keyframes => Some(r"-?[_a-zA-Z\u0080-\uffff][-_a-zA-Z0-9\u0080-\uffff]*"),
+			_ => None,
+		}
+	}
+}
+
+// Keywords which cannot be used as the name of a keyframes, container or counter style.
+const RESERVED_NAMES: [&str; 7] = ["none", "default", "initial", "inherit", "unset", "revert", "revert-layer"];
+
+/// A name found in a document, which is either the definition of that name (such as `--foo: red` or
+/// `@keyframes foo`), or a reference to it (such as `var(--foo)` or `animation-name: foo`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IndexedSymbol {
+	pub name: String,
+	pub namespace: SymbolNamespace,
+	pub definition: bool,
+	pub span: Span,
+	pub range: Range,
+	/// The value of a custom property declaration, such as `red` for `--foo: red`.
+	pub value: Option<String>,
+}
+
+impl IndexedSymbol {
+	fn contains(&self, position: Position) -> bool {
+		self.range.start <= position && position <= self.range.end
+	}
+
+	fn is(&self, namespace: SymbolNamespace, name: &str) -> bool {
+		self.namespace == namespace && self.name == name
+	}
+}
+
+/// The project wide index of every [IndexedSymbol], keyed by the document it was found in. Each document's thread
+/// replaces its own entry after every parse.
+#[derive(Debug, Default)]
+pub(crate) struct Index(DashMap<Uri, Vec<IndexedSymbol>>);
+
+impl Index {
+	pub fn update(&self, uri: Uri, symbols: Vec<IndexedSymbol>) {
+		self.0.insert(uri, symbols);
+	}
+
+	/// Removes the symbols of a document, for example because the file was deleted.
+	pub fn remove(&self, uri: &Uri) {
+		self.0.remove(uri);
+	}
+
+	/// Removes the symbols of every document where `keep` returns false.
+	pub fn retain(&self, keep: impl Fn(&Uri) -> bool) {
+		self.0.retain(|uri, _| keep(uri));
+	}
+
+	/// Finds the symbol (definition or reference) under the given [Position].
+	pub fn symbol_at(&self, uri: &Uri, position: Position) -> Option<IndexedSymbol> {
+		self.0.get(uri)?.iter().find(|symbol| symbol.contains(position)).cloned()
+	}
+
+	/// Finds every occurrence within the same document of the name under the [Position], if it can be linked edited
+	/// (see [SymbolNamespace::word_pattern]): a `@keyframes` name and the animations which use it, or a custom property
+	/// (from `@property` or a declaration) and its `var()` references.
+	pub fn linked_ranges(&self, uri: &Uri, position: Position) -> Option<(SymbolNamespace, Vec<Range>)> {
+		let symbols = self.0.get(uri)?;
+		let symbol = symbols.iter().find(|symbol| symbol.contains(position))?;
+		symbol.namespace.word_pattern()?;
+		let ranges =
+			symbols.iter().filter(|other| other.is(symbol.namespace, &symbol.name)).map(|other| other.range).collect();
+		Some((symbol.namespace, ranges))
+	}
+
+	/// Resolves a custom property to its value, if it's declared exactly once across all documents and its value does
+	/// not itself depend on another custom property.
+	pub fn static_value(&self, name: &str) -> Option<String> {
+		let mut values = vec![];
+		for entry in self.0.iter() {
+			values.extend(
+				entry
+					.value()
+					.iter()
+					.filter(|symbol| symbol.definition && symbol.is(SymbolNamespace::CustomProperty, name))
+					.filter_map(|symbol| symbol.value.clone()),
+			);
+		}
+		match values.as_slice() {
+			[value] if !value.to_ascii_lowercase().contains("var(") => values.pop(),
+			_ => None,
+		}
+	}
+
+	/// Finds the [Locations][Location] of every definition of the given name, across all documents.
+	pub fn definitions(&self, namespace: SymbolNamespace, name: &str) -> Vec<Location> {
+		self.locations(|symbol| symbol.definition && symbol.is(namespace, name))
+	}
+
+	/// Finds the [Locations][Location] of every reference to the given name, across all documents, optionally
+	/// including the definitions.
+	pub fn references(&self, namespace: SymbolNamespace, name: &str, include_definitions: bool) -> Vec<Location> {
+		self.locations(|symbol| (include_definitions || !symbol.definition) && symbol.is(namespace, name))
+	}
+
+	/// Creates the [TextEdits][TextEdit] which rename every definition and reference of the given name, across all
+	/// documents.
+	pub fn rename(&self, namespace: SymbolNamespace, name: &str, new_name: &str) -> HashMap<Uri, Vec<TextEdit>> {
+		let mut changes = HashMap::new();
+		for entry in self.0.iter() {
+			let edits: Vec<_> = entry
+				.value()
+				.iter()
+				.filter(|symbol| symbol.is(namespace, name))
+				.map(|symbol| TextEdit::new(symbol.range, new_name.into()))
+				.collect();
+			if !edits.is_empty() {
+				changes.insert(entry.key().clone(), edits);
+			}
+		}
+		changes
+	}
+
+	/// Finds every definition, across all documents, whose name matches the query.
+	pub fn search(&self, query: &str) -> Vec<WorkspaceSymbol> {
+		let mut symbols = vec![];
+		for entry in self.0.iter() {
+			for symbol in entry.value().iter().filter(|symbol| symbol.definition && fuzzy_match(query, &symbol.name)) {
+				symbols.push(WorkspaceSymbol {
+					name: symbol.name.clone(),
+					kind: symbol.namespace.symbol_kind(),
+					tags: None,
+					container_name: None,
+					location: OneOf::Left(Location::new(entry.key().clone(), symbol.range)),
+					data: None,
+				});
+			}
+		}
+		symbols
+	}
+
+	fn locations(&self, predicate: impl Fn(&IndexedSymbol) -> bool) -> Vec<Location> {
+		let mut locations = vec![];
+		for entry in self.0.iter() {
+			for symbol in entry.value().iter().filter(|symbol| predicate(symbol)) {
+				locations.push(Location::new(entry.key().clone(), symbol.range));
+			}
+		}
+		locations
+	}
+}
+
+// Keywords which can appear in the `animation` shorthand, and so cannot be keyframes names.
+const ANIMATION_KEYWORDS: [&str; 22] = [
+	"none",
+	"ease",
+	"ease-in",
+	"ease-out",
+	"ease-in-out",
+	"linear",
+	"step-start",
+	"step-end",
+	"infinite",
+	"normal",
+	"reverse",
+	"alternate",
+	"alternate-reverse",
+	"forwards",
+	"backwards",
+	"both",
+	"running",
+	"paused",
+	"auto",
+	"replace",
+	"add",
+	"accumulate",
+];
+
+// Keywords which every property accepts, and so cannot be names.
+const CSS_WIDE_KEYWORDS: [&str; 5] = ["initial", "inherit", "unset", "revert", "revert-layer"];
+
+// Keywords which can appear in the `list-style` shorthand or name a predefined counter style, and so aren't references
+// to an `@counter-style` rule. https://drafts.csswg.org/css-counter-styles-3/#predefined-counters
+const LIST_STYLE_KEYWORDS: [&str; 47] = [
+	"none",
+	"inside",
+	"outside",
+	"decimal",
+	"decimal-leading-zero",
+	"arabic-indic",
+	"armenian",
+	"upper-armenian",
+	"lower-armenian",
+	"bengali",
+	"cambodian",
+	"khmer",
+	"cjk-decimal",
+	"devanagari",
+	"georgian",
+	"gujarati",
+	"gurmukhi",
+	"hebrew",
+	"kannada",
+	"lao",
+	"malayalam",
+	"mongolian",
+	"myanmar",
+	"oriya",
+	"persian",
+	"lower-roman",
+	"upper-roman",
+	"tamil",
+	"telugu",
+	"thai",
+	"tibetan",
+	"lower-alpha",
+	"lower-latin",
+	"upper-alpha",
+	"upper-latin",
+	"lower-greek",
+	"hiragana",
+	"hiragana-iroha",
+	"katakana",
+	"katakana-iroha",
+	"disc",
+	"circle",
+	"square",
+	"disclosure-open",
+	"disclosure-closed",
+	"cjk-earthly-branch",
+	"cjk-heavenly-stem",
+];
+
+/// A [Visit] pass which collects every [IndexedSymbol] in a document.
+///
+/// Definitions come from custom property declarations, `@property` preludes, `@keyframes` names, `@layer` names,
+/// `@counter-style` names, and the values of `container-name`, `container` & `anchor-name`. References come from
+/// `var()`, `anchor()`, `anchor-size()`, `counter()` & `counters()` functions, the values of `animation-name`,
+/// `animation`, `position-anchor`, `list-style-type` & `list-style`, the names in `@container` preludes, and class
+/// selectors (which have no definition).
+#[derive(Default)]
+pub(crate) struct SymbolCollector<'s> {
+	source: &'s str,
+	symbols: Vec<IndexedSymbol>,
+}
+
+impl<'s> SymbolCollector<'s> {
+	pub fn new(source: &'s str) -> Self {
+		Self { source, symbols: vec![] }
+	}
+
+	/// Returns the collected symbols, with their [Ranges][Range] resolved against the document.
+	pub fn into_symbols(self, rope: &Rope) -> Vec<IndexedSymbol> {
+		self.symbols
+			.into_iter()
+			.map(|symbol| IndexedSymbol { range: span_to_range(rope, symbol.span), ..symbol })
+			.collect()
+	}
+
+	fn push(&mut self, c: Cursor, namespace: SymbolNamespace, definition: bool) {
+		let span = name_span(c);
+		self.push_span(span.span_contents(self.source).contents().into(), span, namespace, definition);
+	}
+
+	fn push_span(&mut self, name: String, span: Span, namespace: SymbolNamespace, definition: bool) {
+		self.symbols.push(IndexedSymbol { name, namespace, definition, span, range: Range::default(), value: None });
+	}
+
+	fn collect_value(&mut self, property: &str, cursors: Vec<Cursor>) {
+		// Each open function, alongside the index of the argument currently being collected.
+		let mut functions: Vec<(&str, usize)> = vec![];
+		for (i, c) in cursors.iter().copied().enumerate() {
+			match c.token().kind() {
+				Kind::Function | Kind::LeftParen => {
+					let name = if c == Kind::Function { c.str_slice(self.source).trim_end_matches('(') } else { "" };
+					functions.push((name, 0));
+					continue;
+				}
+				Kind::RightParen => {
+					functions.pop();
+					continue;
+				}
+				Kind::Comma => {
+					if let Some((_, arg)) = functions.last_mut() {
+						*arg += 1;
+					}
+					continue;
+				}
+				_ => {}
+			}
+			let first_arg = i > 0 && cursors[i - 1] == Kind::Function;
+			match functions.last() {
+				Some((function, _)) if first_arg && c == Kind::Ident && c.token().is_dashed_ident() => {
+					if function.eq_ignore_ascii_case("var") {
+						self.push(c, SymbolNamespace::CustomProperty, false);
+					} else if function.eq_ignore_ascii_case("anchor") || function.eq_ignore_ascii_case("anchor-size") {
+						self.push(c, SymbolNamespace::Anchor, false);
+					}
+				}
+				Some((function, arg)) if c == Kind::Ident && *arg > 0 => {
+					let predefined = LIST_STYLE_KEYWORDS.iter().any(|kw| c.eq_ignore_ascii_case(self.source, kw));
+					if !predefined
+						&& ((function.eq_ignore_ascii_case("counter") && *arg == 1)
+							|| (function.eq_ignore_ascii_case("counters") && *arg == 2))
+					{
+						self.push(c, SymbolNamespace::CounterStyle, false);
+					}
+				}
+				Some(_) => {}
+				None => self.collect_property_value(property, c),
+			}
+		}
+	}
+
+	fn collect_property_value(&mut self, property: &str, c: Cursor) {
+		let is_keyword = |keywords: &[&str]| keywords.iter().any(|kw| c.eq_ignore_ascii_case(self.source, kw));
+		let is_name = c == Kind::Ident && !is_keyword(&["none"]) && !is_keyword(&CSS_WIDE_KEYWORDS);
+		let is_dashed = c == Kind::Ident && c.token().is_dashed_ident();
+		match property {
+			"container-name" | "container" if is_name => self.push(c, SymbolNamespace::Container, true),
+			"anchor-name" if is_dashed => self.push(c, SymbolNamespace::Anchor, true),
+			"position-anchor" if is_dashed => self.push(c, SymbolNamespace::Anchor, false),
+			"animation-name" if is_name || c == Kind::String => self.push(c, SymbolNamespace::Keyframes, false),
+			"animation" if c == Kind::String => self.push(c, SymbolNamespace::Keyframes, false),
+			"animation" if is_name && !is_keyword(&ANIMATION_KEYWORDS) => {
+				self.push(c, SymbolNamespace::Keyframes, false)
+			}
+			"list-style-type" | "list-style" if is_name && !is_keyword(&LIST_STYLE_KEYWORDS) => {
+				self.push(c, SymbolNamespace::CounterStyle, false)
+			}
+			_ => {}
+		}
+	}
+}
+
+impl Visit for SymbolCollector<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		let name: Cursor = declaration.name.into();
+		if declaration.name.is_dashed_ident() {
+			self.push(name, SymbolNamespace::CustomProperty, true);
+			let value = declaration.value.to_span().span_contents(self.source).contents().trim();
+			if let Some(symbol) = self.symbols.last_mut() {
+				symbol.value = Some(value.into());
+			}
+		}
+		let property = name.str_slice(self.source).to_ascii_lowercase();
+		let mut cursors = CursorCollector::default();
+		declaration.value.to_cursors(&mut cursors);
+		let mut values = cursors.0;
+		// The `container` shorthand is `<'container-name'> [ / <'container-type'> ]?`
+		if property == "container" {
+			if let Some(slash) = values.iter().position(|c| *c == '/') {
+				values.truncate(slash);
+			}
+		}
+		self.collect_value(&property, values);
+	}
+
+	fn visit_property_prelude(&mut self, prelude: &PropertyPrelude) {
+		let span = prelude.to_span();
+		self.push_span(span.span_contents(self.source).contents().into(), span, SymbolNamespace::CustomProperty, true);
+	}
+
+	fn visit_keyframes_name(&mut self, name: &KeyframesName) {
+		self.push((*name).into(), SymbolNamespace::Keyframes, true);
+	}
+
+	fn visit_layer_name<'a>(&mut self, name: &LayerName<'a>) {
+		let span = name.to_span();
+		self.push_span(span.span_contents(self.source).contents().into(), span, SymbolNamespace::Layer, true);
+	}
+
+	fn visit_counter_style_name(&mut self, name: &CounterStyleName) {
+		self.push((*name).into(), SymbolNamespace::CounterStyle, true);
+	}
+
+	fn visit_class(&mut self, class: &Class) {
+		self.push(class.name.into(), SymbolNamespace::Class, false);
+	}
+
+	fn visit_container_rule<'a>(&mut self, rule: &ContainerRule<'a>) {
+		for (condition, _) in &rule.0.prelude.0 {
+			if let Some(name) = condition.name {
+				self.push(name.into(), SymbolNamespace::Container, false);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_ast::{StyleSheet, Visitable};
+	use css_parse::Parser;
+
+	fn collect(source: &str) -> Vec<(String, SymbolNamespace, bool)> {
+		let bump = Bump::default();
+		let rope = Rope::from_str(source);
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut collector = SymbolCollector::new(source);
+		result.output.unwrap().accept(&mut collector);
+		collector.into_symbols(&rope).into_iter().map(|s| (s.name, s.namespace, s.definition)).collect()
+	}
+
+	#[test]
+	fn test_definitions() {
+		use SymbolNamespace::*;
+		assert_eq!(
+			collect(
+				":root{--brand:red}@property --size{syntax:'*'}@keyframes \"fade\"{}@layer a.b, c;@counter-style thumbs{}main{container:sidebar / inline-size;anchor-name:--tip}"
+			),
+			vec![
+				("--brand".into(), CustomProperty, true),
+				("--size".into(), CustomProperty, true),
+				("fade".into(), Keyframes, true),
+				("a.b".into(), Layer, true),
+				("c".into(), Layer, true),
+				("thumbs".into(), CounterStyle, true),
+				("sidebar".into(), Container, true),
+				("--tip".into(), Anchor, true),
+			]
+		);
+	}
+
+	#[test]
+	fn test_references() {
+		use SymbolNamespace::*;
+		assert_eq!(
+			collect(
+				"a{--b:var(--a);color:var(--c, var(--d));animation:spin 1s linear infinite;list-style:thumbs;position-anchor:--tip;top:anchor(--tip bottom)}b{color:rgb(0 0 255);anchor-name:1px}@container sidebar (width > 1px){}"
+			),
+			vec![
+				("--b".into(), CustomProperty, true),
+				("--a".into(), CustomProperty, false),
+				("--c".into(), CustomProperty, false),
+				("--d".into(), CustomProperty, false),
+				("spin".into(), Keyframes, false),
+				("thumbs".into(), CounterStyle, false),
+				("--tip".into(), Anchor, false),
+				("--tip".into(), Anchor, false),
+				("sidebar".into(), Container, false),
+			]
+		);
+	}
+
+	#[test]
+	fn test_keywords() {
+		assert_eq!(
+			collect(
+				"a{animation-name:inherit;animation:unset;list-style:square inside thumbs;list-style-type:disc;content:counter(c, lower-roman)}"
+			),
+			vec![("thumbs".into(), SymbolNamespace::CounterStyle, false)]
+		);
+	}
+
+	#[test]
+	fn test_index() {
+		let index = Index::default();
+		let a: Uri = "file:///a.css".parse().unwrap();
+		let b: Uri = "file:///b.css".parse().unwrap();
+		let symbol = |definition, line, start| IndexedSymbol {
+			name: "--a".into(),
+			namespace: SymbolNamespace::CustomProperty,
+			definition,
+			span: Span::ZERO,
+			range: Range::new(Position::new(line, start), Position::new(line, start + 3)),
+			value: definition.then(|| "red".into()),
+		};
+		index.update(a.clone(), vec![symbol(true, 0, 6)]);
+		index.update(b.clone(), vec![symbol(false, 1, 12)]);
+		let found = index.symbol_at(&b, Position::new(1, 15)).unwrap();
+		assert!(!found.definition);
+		assert_eq!(
+			index.definitions(found.namespace, &found.name),
+			vec![Location::new(a.clone(), symbol(true, 0, 6).range)]
+		);
+		assert_eq!(index.references(found.namespace, &found.name, true).len(), 2);
+		assert_eq!(index.references(found.namespace, &found.name, false).len(), 1);
+		assert_eq!(index.search("a").len(), 1);
+		let changes = index.rename(found.namespace, &found.name, "--b");
+		assert_eq!(changes[&a], vec![TextEdit::new(symbol(true, 0, 6).range, "--b".into())]);
+		assert_eq!(changes[&b], vec![TextEdit::new(symbol(false, 1, 12).range, "--b".into())]);
+		assert_eq!(index.static_value("--a"), Some("red".into()));
+		index.update(b.clone(), vec![symbol(true, 1, 12)]);
+		assert_eq!(index.static_value("--a"), None);
+	}
+
+	#[test]
+	fn test_rename_spans() {
+		let source = "@keyframes \"fade\"{}.card{}.card:hover{}";
+		let bump = Bump::default();
+		let rope = Rope::from_str(source);
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut collector = SymbolCollector::new(source);
+		result.output.unwrap().accept(&mut collector);
+		let symbols = collector.into_symbols(&rope);
+		let spans: Vec<_> = symbols.iter().map(|s| (s.namespace, s.span.span_contents(source).contents())).collect();
+		assert_eq!(
+			spans,
+			vec![
+				(SymbolNamespace::Keyframes, "fade"),
+				(SymbolNamespace::Class, "card"),
+				(SymbolNamespace::Class, "card")
+			]
+		);
+		assert_eq!(symbols[0].range, Range::new(Position::new(0, 12), Position::new(0, 16)));
+	}
+
+	#[test]
+	fn test_linked_ranges() {
+		let source =
+			"@keyframes fade{}@property --x{syntax:'*'}a{animation:fade 1s;color:var(--x);--y:1}b{animation-name:fade}";
+		let uri: Uri = "file:///a.css".parse().unwrap();
+		let symbols = |source: &str| {
+			let bump = Bump::default();
+			let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+			let mut collector = SymbolCollector::new(source);
+			result.output.unwrap().accept(&mut collector);
+			collector.into_symbols(&Rope::from_str(source))
+		};
+		let index = Index::default();
+		index.update(uri.clone(), symbols(source));
+		// Other documents are never linked.
+		index.update("file:///b.css".parse().unwrap(), symbols("c{animation:fade 1s}"));
+		let range = |start, end| Range::new(Position::new(0, start), Position::new(0, end));
+		assert_eq!(
+			index.linked_ranges(&uri, Position::new(0, 54)),
+			Some((SymbolNamespace::Keyframes, vec![range(11, 15), range(54, 58), range(100, 104)]))
+		);
+		assert_eq!(
+			index.linked_ranges(&uri, Position::new(0, 28)),
+			Some((SymbolNamespace::CustomProperty, vec![range(27, 30), range(72, 75)]))
+		);
+		assert_eq!(index.linked_ranges(&uri, Position::new(0, 1)), None);
+	}
+
+	#[test]
+	fn test_valid_names() {
+		use SymbolNamespace::*;
+		assert!(CustomProperty.is_valid_name("--brand-color"));
+		assert!(!CustomProperty.is_valid_name("brand"));
+		assert!(!CustomProperty.is_valid_name("--a b"));
+		assert!(Layer.is_valid_name("base.reset"));
+		assert!(!Layer.is_valid_name("base..reset"));
+		assert!(Keyframes.is_valid_name("fade-in"));
+		assert!(!Keyframes.is_valid_name("none"));
+		assert!(!Keyframes.is_valid_name("1fade"));
+		assert!(Class.is_valid_name("foo\\.bar"));
+		assert!(!Class.is_valid_name("foo.bar"));
+		assert!(!Class.is_valid_name(""));
+	}
+}
@@ -0,0 +1,95 @@
+use csskit_lint::UrlReference;
+use lsp_types::{DocumentLink, Uri};
+use ropey::Rope;
+use serde_json::{Value, json};
+use std::str::FromStr;
+
+use super::{
+	position::span_to_range,
+	workspace::{path_to_uri, uri_to_path},
+};
+
+/// Converts each [UrlReference] into a [DocumentLink]. Links to other origins are returned with their target, while
+/// those relative to the document carry the target in their `data` to be checked when the link is resolved. URLs which
+/// cannot be resolved (such as `data:` URLs, or relative URLs in an unsaved document) are skipped.
+pub(crate) fn document_links(uri: &Uri, rope: &Rope, urls: Vec<UrlReference>) -> Vec<DocumentLink> {
+	let dir = uri_to_path(uri).and_then(|path| path.parent().map(|dir| dir.to_path_buf()));
+	urls.into_iter()
+		.filter_map(|url| {
+			let range = span_to_range(rope, url.span);
+			if url.href.starts_with("https://") || url.href.starts_with("http://") {
+				let target = Uri::from_str(&url.href).ok()?;
+				return Some(DocumentLink { range, target: Some(target), tooltip: None, data: None });
+			}
+			let target = path_to_uri(&url.resolve(dir.as_ref()?)?)?;
+			Some(DocumentLink { range, target: None, tooltip: None, data: Some(json!({ "target": target.as_str() })) })
+		})
+		.collect()
+}
+
+/// Resolves a [DocumentLink] returned by [document_links], setting its target if the file it refers to exists.
+pub(crate) fn resolve_document_link(link: DocumentLink) -> DocumentLink {
+	let Some(target) = link.data.as_ref().and_then(|data| data.get("target")).and_then(Value::as_str) else {
+		return link;
+	};
+	let Some(uri) = Uri::from_str(target).ok() else {
+		return link;
+	};
+	match uri_to_path(&uri) {
+		Some(path) if path.exists() => DocumentLink { target: Some(uri), ..link },
+		Some(path) => DocumentLink { tooltip: Some(format!("{} does not exist", path.display())), ..link },
+		None => link,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_ast::StyleSheet;
+	use css_parse::Parser;
+	use lsp_types::{Position, Range};
+	use std::fs;
+
+	fn links(uri: &Uri, source: &str) -> Vec<DocumentLink> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let urls = csskit_lint::find_urls(source, result.output.as_ref().unwrap());
+		document_links(uri, &Rope::from_str(source), urls)
+	}
+
+	#[test]
+	fn test_document_links() {
+		let uri = Uri::from_str("file:///site/css/main.css").unwrap();
+		let found = links(
+			&uri,
+			"@import 'reset.css';\na{background:url(../img/a.png),url(data:,x),url(https://example.com/b.png)}",
+		);
+		assert_eq!(found.len(), 3);
+		assert_eq!(found[0].range, Range::new(Position::new(0, 8), Position::new(0, 19)));
+		assert_eq!(found[0].data, Some(json!({ "target": "file:///site/css/reset.css" })));
+		assert_eq!(found[1].data, Some(json!({ "target": "file:///site/img/a.png" })));
+		assert_eq!(found[2].target, Some(Uri::from_str("https://example.com/b.png").unwrap()));
+		let untitled = Uri::from_str("untitled:Untitled-1").unwrap();
+		assert_eq!(links(&untitled, "a{background:url(a.png)}"), vec![]);
+	}
+
+	#[test]
+	fn test_resolve_document_link() {
+		let dir = std::env::temp_dir().join(format!("csskit_lsp_links_{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+		fs::write(dir.join("a.png"), "").unwrap();
+		let link = |name: &str| DocumentLink {
+			range: Range::default(),
+			target: None,
+			tooltip: None,
+			data: Some(json!({ "target": path_to_uri(&dir.join(name)).unwrap().as_str() })),
+		};
+		let found = resolve_document_link(link("a.png"));
+		let missing = resolve_document_link(link("b.png"));
+		fs::remove_dir_all(&dir).unwrap();
+		assert_eq!(found.target, path_to_uri(&dir.join("a.png")));
+		assert_eq!(missing.target, None);
+		assert!(missing.tooltip.unwrap().ends_with("b.png does not exist"));
+	}
+}
@@ -0,0 +1,72 @@
+use css_lexer::{SourceOffset, Span};
+use lsp_types::{Position, Range};
+use ropey::Rope;
+
+// LSP positions count columns in UTF-16 code units (the only encoding every client supports), while ropes are indexed
+// by char, so characters outside of the Basic Multilingual Plane (such as most emoji) take up two columns.
+
+/// Converts a char index into an LSP [Position] (a zero-indexed line, and a column counted in UTF-16 code units).
+pub(crate) fn char_to_position(rope: &Rope, char: usize) -> Position {
+	let char = char.min(rope.len_chars());
+	let line = rope.char_to_line(char);
+	let column = rope.char_to_utf16_cu(char) - rope.char_to_utf16_cu(rope.line_to_char(line));
+	Position::new(line as u32, column as u32)
+}
+
+/// Converts an LSP [Position] into a char index, returning [None] if the line is outside of the document. Columns past
+/// the end of the line are clamped to it.
+pub(crate) fn position_to_char(rope: &Rope, position: Position) -> Option<usize> {
+	let line_start = rope.try_line_to_char(position.line as usize).ok()?;
+	let line_len = rope.get_line(position.line as usize).map_or(0, |line| line.len_utf16_cu());
+	let line_start_cu = rope.char_to_utf16_cu(line_start);
+	Some(rope.utf16_cu_to_char(line_start_cu + (position.character as usize).min(line_len)))
+}
+
+/// Converts a byte [SourceOffset] into an LSP [Position].
+pub(crate) fn offset_to_position(rope: &Rope, offset: SourceOffset) -> Position {
+	char_to_position(rope, rope.try_byte_to_char(offset.0 as usize).unwrap_or_else(|_| rope.len_chars()))
+}
+
+/// Converts a [Span] into an LSP [Range].
+pub(crate) fn span_to_range(rope: &Rope, span: Span) -> Range {
+	Range::new(offset_to_position(rope, span.start()), offset_to_position(rope, span.end()))
+}
+
+/// Converts an LSP [Position] into a byte [SourceOffset], returning [None] if the position is outside of the document.
+pub(crate) fn position_to_offset(rope: &Rope, position: Position) -> Option<SourceOffset> {
+	let char = position_to_char(rope, position)?;
+	rope.try_char_to_byte(char).ok().map(|byte| SourceOffset(byte as u32))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_offset_to_position() {
+		let rope = Rope::from_str("a {\n\tcolor: red;\n}\n");
+		let position = offset_to_position(&rope, SourceOffset(5));
+		assert_eq!(position, Position::new(1, 1));
+		assert_eq!(offset_to_position(&rope, SourceOffset(99)), Position::new(3, 0));
+		assert_eq!(position_to_offset(&rope, position), Some(SourceOffset(5)));
+		assert_eq!(position_to_offset(&rope, Position::new(9, 0)), None);
+	}
+
+	#[test]
+	fn test_multibyte() {
+		let rope = Rope::from_str(".é{}\n.b{}");
+		assert_eq!(offset_to_position(&rope, SourceOffset(3)), Position::new(0, 2));
+		assert_eq!(position_to_offset(&rope, Position::new(0, 2)), Some(SourceOffset(3)));
+		assert_eq!(span_to_range(&rope, Span::new(SourceOffset(6), SourceOffset(10))).start, Position::new(1, 0));
+	}
+
+	#[test]
+	fn test_utf16() {
+		// `🎨` is four bytes, one char, and two UTF-16 code units.
+		let rope = Rope::from_str("a{content:'🎨';color:red}");
+		assert_eq!(offset_to_position(&rope, SourceOffset(16)), Position::new(0, 14));
+		assert_eq!(position_to_offset(&rope, Position::new(0, 14)), Some(SourceOffset(16)));
+		assert_eq!(char_to_position(&rope, 13), Position::new(0, 14));
+		assert_eq!(position_to_char(&rope, Position::new(0, 99)), Some(rope.len_chars()));
+	}
+}
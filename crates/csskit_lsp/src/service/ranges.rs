@@ -0,0 +1,239 @@
+use css_ast::{
+	ContainerRule, CounterStyleRule, DocumentRule, FontFaceRule, Keyframe, KeyframesRule, LayerRule, MarginRule,
+	MediaRule, MozDocumentRule, NodePath, PageRule, PropertyRule, StyleRule, StyleSheet, SupportsRule, UnknownAtRule,
+	UnknownQualifiedRule, Visit, Visitable, WebkitKeyframesRule,
+};
+use css_lexer::{Cursor, Kind, SourceOffset, Span, ToSpan};
+use css_parse::{CursorCollector, ToCursors};
+use lsp_types::{FoldingRange, FoldingRangeKind, SelectionRange};
+use ropey::Rope;
+
+use super::position::span_to_range;
+
+/// A [Visit] pass which collects the [Span] of every rule, so that each rule's block can be folded.
+#[derive(Default)]
+pub(crate) struct FoldingCollector(Vec<Span>);
+
+impl FoldingCollector {
+	pub fn into_ranges(self, rope: &Rope, trivia: &[Cursor]) -> Vec<FoldingRange> {
+		let comments =
+			trivia.iter().filter(|c| **c == Kind::Comment).map(|c| (c.span(), Some(FoldingRangeKind::Comment)));
+		let mut ranges: Vec<_> = self
+			.0
+			.into_iter()
+			.map(|span| (span, None))
+			.chain(comments)
+			.filter_map(|(span, kind)| {
+				let start_line = rope.byte_to_line(span.start().0 as usize) as u32;
+				let end_line = rope.byte_to_line(span.end().0 as usize) as u32;
+				// Blocks keep their closing curly visible, while comments fold entirely.
+				let end_line = if kind.is_none() { end_line.saturating_sub(1) } else { end_line };
+				(end_line > start_line).then_some(FoldingRange {
+					start_line,
+					end_line,
+					kind,
+					start_character: None,
+					end_character: None,
+					collapsed_text: None,
+				})
+			})
+			.collect();
+		ranges.sort_by_key(|range| (range.start_line, range.end_line));
+		ranges
+	}
+}
+
+impl Visit for FoldingCollector {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_media_rule<'a>(&mut self, rule: &MediaRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_container_rule<'a>(&mut self, rule: &ContainerRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_supports_rule<'a>(&mut self, rule: &SupportsRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_layer_rule<'a>(&mut self, rule: &LayerRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_keyframes_rule<'a>(&mut self, rule: &KeyframesRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_webkit_keyframes_rule<'a>(&mut self, rule: &WebkitKeyframesRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_keyframe<'a>(&mut self, rule: &Keyframe<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_font_face_rule<'a>(&mut self, rule: &FontFaceRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_page_rule<'a>(&mut self, rule: &PageRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_margin_rule<'a>(&mut self, rule: &MarginRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_property_rule<'a>(&mut self, rule: &PropertyRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_counter_style_rule<'a>(&mut self, rule: &CounterStyleRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_document_rule<'a>(&mut self, rule: &DocumentRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_moz_document_rule<'a>(&mut self, rule: &MozDocumentRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_unknown_at_rule<'a>(&mut self, rule: &UnknownAtRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+
+	fn visit_unknown_qualified_rule<'a>(&mut self, rule: &UnknownQualifiedRule<'a>) {
+		self.0.push(rule.to_span());
+	}
+}
+
+fn contains(span: Span, offset: SourceOffset) -> bool {
+	span.start() <= offset && offset <= span.end()
+}
+
+/// Returns the [Spans][Span] of each node which contains `offset`, from the innermost (the token at the offset) to the
+/// outermost: the token, any nested function or parenthesised component, the declaration, the block, the rule, and
+/// each parent rule.
+pub(crate) fn selection_spans(stylesheet: &StyleSheet, offset: SourceOffset) -> Vec<Span> {
+	let mut path = NodePath::new(offset);
+	stylesheet.accept(&mut path);
+	let mut spans: Vec<Span> = path.nodes().iter().map(|(_, span)| *span).collect();
+	// Tokens & their enclosing groups (functions, parenthesis, blocks) aren't all nodes in the AST, so they are found
+	// from the cursors of the stylesheet instead.
+	let mut cursors = CursorCollector::default();
+	stylesheet.to_cursors(&mut cursors);
+	let mut open = vec![];
+	for c in cursors.0 {
+		match c.token().kind() {
+			Kind::Function | Kind::LeftParen | Kind::LeftSquare | Kind::LeftCurly => open.push(c),
+			Kind::RightParen | Kind::RightSquare | Kind::RightCurly => {
+				if let Some(start) = open.pop() {
+					let span = start.span() + c.span();
+					if contains(span, offset) {
+						spans.push(span);
+					}
+				}
+			}
+			_ => {}
+		}
+		if c.offset() <= offset && offset < c.end_offset() {
+			spans.push(c.span());
+		}
+	}
+	spans.sort_by_key(|span| (span.end().0 - span.start().0, span.start()));
+	spans.dedup();
+	let mut selection: Vec<Span> = vec![];
+	for span in spans {
+		if selection.last().is_none_or(|last| span.contains(*last)) {
+			selection.push(span);
+		}
+	}
+	selection
+}
+
+/// Builds a [SelectionRange] from the given [Spans][Span], which must be ordered from innermost to outermost.
+pub(crate) fn selection_range(rope: &Rope, spans: Vec<Span>) -> Option<SelectionRange> {
+	spans.into_iter().rev().fold(None, |parent, span| {
+		Some(SelectionRange { range: span_to_range(rope, span), parent: parent.map(Box::new) })
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_parse::Parser;
+
+	fn folds(source: &str) -> Vec<(u32, u32, Option<FoldingRangeKind>)> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut collector = FoldingCollector::default();
+		result.output.as_ref().unwrap().accept(&mut collector);
+		collector
+			.into_ranges(&Rope::from_str(source), &result.trivia)
+			.into_iter()
+			.map(|range| (range.start_line, range.end_line, range.kind))
+			.collect()
+	}
+
+	fn selections(source: &str, offset: u32) -> Vec<&str> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let spans = selection_spans(result.output.as_ref().unwrap(), SourceOffset(offset));
+		spans.into_iter().map(|span| span.span_contents(source).contents()).collect()
+	}
+
+	#[test]
+	fn test_folding_ranges() {
+		assert_eq!(
+			folds("/*\n * comment\n */\n@media print {\n\ta {\n\t\tcolor: red;\n\t}\n\tb { color: blue }\n}\nc {}\n"),
+			vec![(0, 2, Some(FoldingRangeKind::Comment)), (3, 7, None), (4, 5, None)]
+		);
+	}
+
+	#[test]
+	fn test_selection_ranges() {
+		let source = "@media print {\n\ta {\n\t\tcolor: rgb(0 0 0);\n\t}\n}";
+		// The offset of the first `0` within rgb()
+		let offset = source.find("0 0 0").unwrap() as u32;
+		assert_eq!(
+			selections(source, offset),
+			vec![
+				"0",
+				"rgb(0 0 0)",
+				"color: rgb(0 0 0);",
+				"{\n\t\tcolor: rgb(0 0 0);\n\t}",
+				"a {\n\t\tcolor: rgb(0 0 0);\n\t}",
+				"{\n\ta {\n\t\tcolor: rgb(0 0 0);\n\t}\n}",
+				"@media print {\n\ta {\n\t\tcolor: rgb(0 0 0);\n\t}\n}",
+			]
+		);
+	}
+
+	#[test]
+	fn test_selection_range_nesting() {
+		let source = "a{color:red}";
+		let rope = Rope::from_str(source);
+		let range = selection_range(&rope, vec![Span::new(SourceOffset(8), SourceOffset(11))]).unwrap();
+		assert_eq!(range.range.start.character, 8);
+		assert!(range.parent.is_none());
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let spans = selection_spans(result.output.as_ref().unwrap(), SourceOffset(9));
+		let mut range = selection_range(&rope, spans).unwrap();
+		let mut depth = 1;
+		while let Some(parent) = range.parent {
+			assert!(parent.range.start <= range.range.start && range.range.end <= parent.range.end);
+			range = *parent;
+			depth += 1;
+		}
+		// `red`, `color:red`, `{color:red}` and the rule (which spans the whole stylesheet).
+		assert_eq!(depth, 4);
+	}
+}
@@ -0,0 +1,60 @@
+use csskit_config::Config;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::hints::InlayHintSettings;
+
+/// The settings a client can configure, either with the `initializationOptions` of the `initialize` request or by
+/// sending `workspace/didChangeConfiguration`, which apply to every workspace folder. Clients which support
+/// `workspace/configuration` are also asked for the `csskit` settings of each workspace folder, which replace those for
+/// the folder. Settings which are missing keep their default value.
+///
+/// Alongside the editor specific settings, any of the keys of a `csskit.toml` [Config] (such as `lint` or `format`) can
+/// be given, which override the config file of each workspace folder.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub(crate) struct Settings {
+	pub inlay_hints: InlayHintSettings,
+	#[serde(flatten)]
+	pub config: Config,
+}
+
+impl Settings {
+	/// Reads the settings from a JSON value, which may be nested under a `csskit` key, as editors commonly send the
+	/// settings of every language server at once.
+	pub fn from_value(value: Value) -> Option<Self> {
+		let value = match value {
+			Value::Object(mut map) if map.contains_key("csskit") => map.remove("csskit")?,
+			value => value,
+		};
+		serde_json::from_value(value).ok()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn test_from_value() {
+		assert_eq!(Settings::from_value(json!({})), Some(Settings::default()));
+		let settings =
+			Settings::from_value(json!({ "csskit": { "inlayHints": { "colors": false, "rootFontSize": 10 } } }));
+		let inlay_hints = settings.unwrap().inlay_hints;
+		assert!(!inlay_hints.colors);
+		assert!(inlay_hints.specificity);
+		assert_eq!(inlay_hints.root_font_size, 10.0);
+		assert_eq!(Settings::from_value(json!({ "inlayHints": { "pixels": "yes" } })), None);
+	}
+
+	#[test]
+	fn test_config_overrides() {
+		let settings =
+			Settings::from_value(json!({ "csskit": { "lint": { "missing-file": "off" }, "format": { "indent": 2 } } }));
+		let config = settings.unwrap().config;
+		assert_eq!(config.lint.level(csskit_lint::Rule::MissingFile), Some(csskit_config::RuleLevel::Off));
+		assert_eq!(config.format.indent, Some(csskit_config::Indent::Spaces(2)));
+		assert_eq!(Settings::from_value(json!({ "lint": { "no-such-rule": "off" } })), None);
+	}
+}
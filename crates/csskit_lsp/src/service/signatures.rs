@@ -0,0 +1,592 @@
+use css_lexer::{Cursor, Feature, Kind, Lexer, Token};
+use lsp_types::{
+	Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation,
+};
+
+/// What comes before an argument of a function: the start of a new comma separated argument, the next of a space
+/// separated list of components, or the `/` which usually precedes an alpha value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Separator {
+	Comma,
+	Space,
+	Slash,
+}
+
+use Separator::*;
+
+#[derive(Debug)]
+struct Parameter {
+	separator: Separator,
+	grammar: &'static str,
+	documentation: &'static str,
+	// The parameter may be given any number of times, for example each color stop in a gradient.
+	repeats: bool,
+}
+
+const fn param(separator: Separator, grammar: &'static str, documentation: &'static str) -> Parameter {
+	Parameter { separator, grammar, documentation, repeats: false }
+}
+
+const fn repeating(separator: Separator, grammar: &'static str, documentation: &'static str) -> Parameter {
+	Parameter { separator, grammar, documentation, repeats: true }
+}
+
+#[derive(Debug)]
+struct Signature {
+	name: &'static str,
+	documentation: &'static str,
+	parameters: &'static [Parameter],
+}
+
+impl Signature {
+	/// Builds the label (the function's grammar), alongside the offsets of each parameter within it.
+	fn label(&self) -> (String, Vec<[u32; 2]>) {
+		let mut label = format!("{}( ", self.name);
+		let mut offsets = vec![];
+		for (i, parameter) in self.parameters.iter().enumerate() {
+			if i > 0 {
+				label.push_str(match parameter.separator {
+					Comma => ", ",
+					Space => " ",
+					Slash => " / ",
+				});
+			}
+			let start = label.len() as u32;
+			label.push_str(parameter.grammar);
+			offsets.push([start, label.len() as u32]);
+		}
+		label.push_str(" )");
+		(label, offsets)
+	}
+
+	/// Finds the parameter after the given separators, or [None] if they don't fit this signature (for example a comma
+	/// in a function whose arguments are only space separated).
+	fn active_parameter(&self, separators: &[Separator]) -> Option<usize> {
+		let mut active = 0;
+		for separator in separators {
+			let rest = self.parameters.get(active + 1..).unwrap_or_default();
+			match (separator, rest.iter().position(|parameter| parameter.separator == *separator)) {
+				// Space separated components only move to the very next parameter, otherwise they're part of this one
+				// (such as `to left` in a gradient direction).
+				(Space, Some(0)) => active += 1,
+				(Space, _) => {}
+				(_, Some(i)) => active += i + 1,
+				(_, None) if self.parameters[active].repeats && self.parameters[active].separator == *separator => {}
+				(_, None) => return None,
+			}
+		}
+		Some(active)
+	}
+
+	fn information(&self) -> SignatureInformation {
+		let (label, offsets) = self.label();
+		SignatureInformation {
+			label,
+			documentation: Some(Documentation::MarkupContent(MarkupContent {
+				kind: MarkupKind::Markdown,
+				value: self.documentation.into(),
+			})),
+			parameters: Some(
+				self.parameters
+					.iter()
+					.zip(offsets)
+					.map(|(parameter, offsets)| ParameterInformation {
+						label: ParameterLabel::LabelOffsets(offsets),
+						documentation: Some(Documentation::String(parameter.documentation.into())),
+					})
+					.collect(),
+			),
+			active_parameter: None,
+		}
+	}
+}
+
+const ALPHA: &str = "The opacity, from 0 (transparent) to 1 (opaque), or a percentage.";
+const HUE: &str = "The hue angle, where a unitless number is taken as degrees.";
+
+const MODERN_RGB: &[Parameter] = &[
+	param(Space, "[ <number> | <percentage> | none ]", "The red channel, from 0 to 255 (or 0% to 100%)."),
+	param(Space, "[ <number> | <percentage> | none ]", "The green channel, from 0 to 255 (or 0% to 100%)."),
+	param(Space, "[ <number> | <percentage> | none ]", "The blue channel, from 0 to 255 (or 0% to 100%)."),
+	param(Slash, "[ <alpha-value> | none ]?", ALPHA),
+];
+const LEGACY_RGB: &[Parameter] = &[
+	param(Comma, "[ <number> | <percentage> ]", "The red channel, from 0 to 255 (or 0% to 100%)."),
+	param(Comma, "[ <number> | <percentage> ]", "The green channel, from 0 to 255 (or 0% to 100%)."),
+	param(Comma, "[ <number> | <percentage> ]", "The blue channel, from 0 to 255 (or 0% to 100%)."),
+	param(Comma, "<alpha-value>?", ALPHA),
+];
+const MODERN_HSL: &[Parameter] = &[
+	param(Space, "[ <hue> | none ]", HUE),
+	param(Space, "[ <percentage> | <number> | none ]", "The saturation, from 0% (grey) to 100% (fully saturated)."),
+	param(Space, "[ <percentage> | <number> | none ]", "The lightness, from 0% (black) to 100% (white)."),
+	param(Slash, "[ <alpha-value> | none ]?", ALPHA),
+];
+const LEGACY_HSL: &[Parameter] = &[
+	param(Comma, "<hue>", HUE),
+	param(Comma, "<percentage>", "The saturation, from 0% (grey) to 100% (fully saturated)."),
+	param(Comma, "<percentage>", "The lightness, from 0% (black) to 100% (white)."),
+	param(Comma, "<alpha-value>?", ALPHA),
+];
+const HWB: &[Parameter] = &[
+	param(Space, "[ <hue> | none ]", HUE),
+	param(Space, "[ <percentage> | <number> | none ]", "The amount of white to mix in, from 0% to 100%."),
+	param(Space, "[ <percentage> | <number> | none ]", "The amount of black to mix in, from 0% to 100%."),
+	param(Slash, "[ <alpha-value> | none ]?", ALPHA),
+];
+const LAB: &[Parameter] = &[
+	param(Space, "[ <percentage> | <number> | none ]", "The perceived lightness."),
+	param(
+		Space,
+		"[ <percentage> | <number> | none ]",
+		"The `a` axis: the distance along the green (-) to red (+) axis.",
+	),
+	param(
+		Space,
+		"[ <percentage> | <number> | none ]",
+		"The `b` axis: the distance along the blue (-) to yellow (+) axis.",
+	),
+	param(Slash, "[ <alpha-value> | none ]?", ALPHA),
+];
+const LCH: &[Parameter] = &[
+	param(Space, "[ <percentage> | <number> | none ]", "The perceived lightness."),
+	param(Space, "[ <percentage> | <number> | none ]", "The chroma: the amount of color."),
+	param(Space, "[ <hue> | none ]", HUE),
+	param(Slash, "[ <alpha-value> | none ]?", ALPHA),
+];
+const COLOR: &[Parameter] = &[
+	param(
+		Space,
+		"<colorspace>",
+		"The color space: `srgb`, `srgb-linear`, `display-p3`, `a98-rgb`, `prophoto-rgb`, `rec2020`, `xyz`, `xyz-d50` or `xyz-d65`.",
+	),
+	param(Space, "[ <number> | <percentage> | none ]", "The first channel of the color space, such as red or x."),
+	param(Space, "[ <number> | <percentage> | none ]", "The second channel of the color space, such as green or y."),
+	param(Space, "[ <number> | <percentage> | none ]", "The third channel of the color space, such as blue or z."),
+	param(Slash, "[ <alpha-value> | none ]?", ALPHA),
+];
+
+const LENGTH_PERCENTAGE_X: Parameter = param(Comma, "<length-percentage>", "The distance along the x axis.");
+const LENGTH_PERCENTAGE_Y: Parameter = param(Comma, "<length-percentage>", "The distance along the y axis.");
+const SCALE_X: Parameter = param(Comma, "<number>", "The scale factor along the x axis.");
+const SCALE_Y: Parameter = param(Comma, "<number>", "The scale factor along the y axis.");
+const ANGLE_X: Parameter = param(Comma, "[ <angle> | <zero> ]", "The angle to skew along the x axis.");
+const ANGLE_Y: Parameter = param(Comma, "[ <angle> | <zero> ]", "The angle to skew along the y axis.");
+
+const COLOR_STOPS: Parameter = repeating(
+	Comma,
+	"<color-stop-list>",
+	"The colors of the gradient, each optionally followed by the position(s) it applies from, for example `red 20%`.",
+);
+const LINEAR_GRADIENT: &[Parameter] = &[
+	param(
+		Comma,
+		"[ <angle> | to <side-or-corner> ]?",
+		"The direction of the gradient line, as an angle or towards a side or corner. Defaults to `to bottom`.",
+	),
+	COLOR_STOPS,
+];
+const RADIAL_GRADIENT: &[Parameter] = &[
+	param(
+		Comma,
+		"[ <radial-shape> || <radial-size> ]? [ at <position> ]?",
+		"The shape (`circle` or `ellipse`) and size of the gradient, and the position of its center.",
+	),
+	COLOR_STOPS,
+];
+const CONIC_GRADIENT: &[Parameter] = &[
+	param(
+		Comma,
+		"[ from <angle> ]? [ at <position> ]?",
+		"The angle the gradient starts from, and the position of its center.",
+	),
+	COLOR_STOPS,
+];
+
+const SIGNATURES: &[Signature] = &[
+	// https://drafts.csswg.org/css-color-4/#rgb-functions
+	Signature { name: "rgb", documentation: "A color in the sRGB color space.", parameters: MODERN_RGB },
+	Signature {
+		name: "rgb",
+		documentation: "A color in the sRGB color space (legacy syntax).",
+		parameters: LEGACY_RGB,
+	},
+	Signature { name: "rgba", documentation: "A color in the sRGB color space.", parameters: MODERN_RGB },
+	Signature {
+		name: "rgba",
+		documentation: "A color in the sRGB color space (legacy syntax).",
+		parameters: LEGACY_RGB,
+	},
+	// https://drafts.csswg.org/css-color-4/#the-hsl-notation
+	Signature { name: "hsl", documentation: "A color as a hue, saturation and lightness.", parameters: MODERN_HSL },
+	Signature {
+		name: "hsl",
+		documentation: "A color as a hue, saturation and lightness (legacy syntax).",
+		parameters: LEGACY_HSL,
+	},
+	Signature { name: "hsla", documentation: "A color as a hue, saturation and lightness.", parameters: MODERN_HSL },
+	Signature {
+		name: "hsla",
+		documentation: "A color as a hue, saturation and lightness (legacy syntax).",
+		parameters: LEGACY_HSL,
+	},
+	// https://drafts.csswg.org/css-color-4/#the-hwb-notation
+	Signature { name: "hwb", documentation: "A color as a hue, with an amount of white and black.", parameters: HWB },
+	// https://drafts.csswg.org/css-color-4/#specifying-lab-lch
+	Signature { name: "lab", documentation: "A color in the CIE Lab color space.", parameters: LAB },
+	Signature { name: "lch", documentation: "A color in the CIE LCH color space.", parameters: LCH },
+	Signature { name: "oklab", documentation: "A color in the Oklab color space.", parameters: LAB },
+	Signature { name: "oklch", documentation: "A color in the Oklch color space.", parameters: LCH },
+	// https://drafts.csswg.org/css-color-4/#color-function
+	Signature { name: "color", documentation: "A color in a predefined color space.", parameters: COLOR },
+	// https://drafts.csswg.org/css-transforms-1/#two-d-transform-functions
+	Signature {
+		name: "matrix",
+		documentation: "A 2D transformation matrix, of the six values `a b c d tx ty`.",
+		parameters: &[
+			param(Comma, "<number>", "`a`: the horizontal scale."),
+			param(Comma, "<number>", "`b`: the vertical skew."),
+			param(Comma, "<number>", "`c`: the horizontal skew."),
+			param(Comma, "<number>", "`d`: the vertical scale."),
+			param(Comma, "<number>", "`tx`: the horizontal translation."),
+			param(Comma, "<number>", "`ty`: the vertical translation."),
+		],
+	},
+	Signature {
+		name: "translate",
+		documentation: "Moves an element by an offset.",
+		parameters: &[
+			LENGTH_PERCENTAGE_X,
+			param(Comma, "<length-percentage>?", "The distance along the y axis. Defaults to 0."),
+		],
+	},
+	Signature {
+		name: "translateX",
+		documentation: "Moves an element horizontally.",
+		parameters: &[LENGTH_PERCENTAGE_X],
+	},
+	Signature { name: "translateY", documentation: "Moves an element vertically.", parameters: &[LENGTH_PERCENTAGE_Y] },
+	Signature {
+		name: "scale",
+		documentation: "Resizes an element.",
+		parameters: &[
+			SCALE_X,
+			param(Comma, "<number>?", "The scale factor along the y axis. Defaults to the x factor."),
+		],
+	},
+	Signature { name: "scaleX", documentation: "Resizes an element horizontally.", parameters: &[SCALE_X] },
+	Signature { name: "scaleY", documentation: "Resizes an element vertically.", parameters: &[SCALE_Y] },
+	Signature {
+		name: "rotate",
+		documentation: "Rotates an element around its transform origin.",
+		parameters: &[param(Comma, "[ <angle> | <zero> ]", "The angle to rotate by, clockwise.")],
+	},
+	Signature {
+		name: "skew",
+		documentation: "Skews an element.",
+		parameters: &[
+			ANGLE_X,
+			param(Comma, "[ <angle> | <zero> ]?", "The angle to skew along the y axis. Defaults to 0."),
+		],
+	},
+	Signature { name: "skewX", documentation: "Skews an element horizontally.", parameters: &[ANGLE_X] },
+	Signature { name: "skewY", documentation: "Skews an element vertically.", parameters: &[ANGLE_Y] },
+	// https://drafts.csswg.org/css-easing-2/#easing-functions
+	Signature {
+		name: "linear",
+		documentation: "An easing function which interpolates linearly between each of the points.",
+		parameters: &[repeating(
+			Comma,
+			"[ <number> && <percentage>{0,2} ]",
+			"A point of the easing: the output progress, optionally with the input progress it applies at.",
+		)],
+	},
+	Signature {
+		name: "cubic-bezier",
+		documentation: "An easing function defined by a cubic Bézier curve, from (0, 0) to (1, 1).",
+		parameters: &[
+			param(Comma, "<number [0,1]>", "The x coordinate of the first control point."),
+			param(Comma, "<number>", "The y coordinate of the first control point."),
+			param(Comma, "<number [0,1]>", "The x coordinate of the second control point."),
+			param(Comma, "<number>", "The y coordinate of the second control point."),
+		],
+	},
+	Signature {
+		name: "steps",
+		documentation: "An easing function which jumps between a number of equal steps.",
+		parameters: &[
+			param(Comma, "<integer>", "The number of steps."),
+			param(
+				Comma,
+				"<step-position>?",
+				"When the jumps happen: `jump-start`, `jump-end`, `jump-none`, `jump-both`, `start` or `end`. Defaults to `end`.",
+			),
+		],
+	},
+	// https://drafts.csswg.org/css-images-4/#gradients
+	Signature {
+		name: "linear-gradient",
+		documentation: "A gradient along a straight line.",
+		parameters: LINEAR_GRADIENT,
+	},
+	Signature {
+		name: "repeating-linear-gradient",
+		documentation: "A gradient along a straight line, repeating its color stops.",
+		parameters: LINEAR_GRADIENT,
+	},
+	Signature {
+		name: "radial-gradient",
+		documentation: "A gradient radiating out from a center point.",
+		parameters: RADIAL_GRADIENT,
+	},
+	Signature {
+		name: "repeating-radial-gradient",
+		documentation: "A gradient radiating out from a center point, repeating its color stops.",
+		parameters: RADIAL_GRADIENT,
+	},
+	Signature {
+		name: "conic-gradient",
+		documentation: "A gradient rotating around a center point.",
+		parameters: CONIC_GRADIENT,
+	},
+	Signature {
+		name: "repeating-conic-gradient",
+		documentation: "A gradient rotating around a center point, repeating its color stops.",
+		parameters: CONIC_GRADIENT,
+	},
+	// https://drafts.csswg.org/css-values-5/#attr-notation
+	Signature {
+		name: "attr",
+		documentation: "The value of an attribute of the element.",
+		parameters: &[
+			param(Space, "<attr-name>", "The name of the attribute, optionally with a namespace such as `svg|href`."),
+			param(
+				Space,
+				"<attr-type>?",
+				"How the attribute is parsed: `type(<syntax>)`, `raw-string`, or a unit such as `px`.",
+			),
+			param(Comma, "<declaration-value>?", "The fallback, used if the attribute is missing or invalid."),
+		],
+	},
+	// https://drafts.csswg.org/css-lists-3/#counter-functions
+	Signature {
+		name: "counter",
+		documentation: "The value of the innermost counter with the given name.",
+		parameters: &[
+			param(Comma, "<counter-name>", "The name of the counter."),
+			param(Comma, "<counter-style>?", "The style to format the counter with. Defaults to `decimal`."),
+		],
+	},
+	Signature {
+		name: "counters",
+		documentation: "The values of every counter with the given name, from outermost to innermost.",
+		parameters: &[
+			param(Comma, "<counter-name>", "The name of the counters."),
+			param(Comma, "<string>", "The text placed between each counter, such as `\".\"`."),
+			param(Comma, "<counter-style>?", "The style to format the counters with. Defaults to `decimal`."),
+		],
+	},
+	// https://drafts.csswg.org/css-anchor-position-1/#anchor-pos
+	Signature {
+		name: "anchor",
+		documentation: "The position of a side of an anchor element.",
+		parameters: &[
+			param(
+				Space,
+				"<anchor-name>?",
+				"The anchor, such as `--tooltip`. Defaults to the element's `position-anchor`.",
+			),
+			param(
+				Space,
+				"<anchor-side>",
+				"The side of the anchor: `top`, `left`, `right`, `bottom`, `start`, `end`, `center`, or a percentage.",
+			),
+			param(Comma, "<length-percentage>?", "The fallback, used if the anchor can't be found."),
+		],
+	},
+	// https://drafts.csswg.org/css-anchor-position-1/#anchor-size-fn
+	Signature {
+		name: "anchor-size",
+		documentation: "The size of an anchor element.",
+		parameters: &[
+			param(
+				Space,
+				"<anchor-name>?",
+				"The anchor, such as `--tooltip`. Defaults to the element's `position-anchor`.",
+			),
+			param(
+				Space,
+				"<anchor-size>?",
+				"The dimension to use: `width`, `height`, `block`, `inline`, `self-block` or `self-inline`.",
+			),
+			param(Comma, "<length-percentage>?", "The fallback, used if the anchor can't be found."),
+		],
+	},
+];
+
+// A block (function, parenthesis, square bracket or curly brace) which is open at the cursor.
+struct Frame<'a> {
+	function: Option<&'a str>,
+	separators: Vec<Separator>,
+	// A component has been seen since the last separator, so whitespace may separate it from the next.
+	after_component: bool,
+	pending_space: bool,
+}
+
+impl<'a> Frame<'a> {
+	fn new(token: Token, str: &'a str) -> Self {
+		// Only functions have a signature, the name of which is the token without its trailing `(`.
+		let function = (token == Kind::Function).then(|| str.trim_end_matches('('));
+		Self { function, separators: vec![], after_component: false, pending_space: false }
+	}
+
+	fn component(&mut self) {
+		if self.pending_space {
+			self.separators.push(Space);
+		}
+		self.pending_space = false;
+		self.after_component = true;
+	}
+
+	fn separator(&mut self, separator: Separator) {
+		self.separators.push(separator);
+		self.pending_space = false;
+		self.after_component = false;
+	}
+}
+
+/// Finds the signature of the innermost known function which the `offset` is within, highlighting the argument the
+/// offset is at.
+pub(crate) fn signature_help(source: &str, features: Feature, offset: usize) -> Option<SignatureHelp> {
+	let mut lexer = Lexer::new_with_features(source, features);
+	let mut stack: Vec<Frame> = vec![];
+	loop {
+		let start = lexer.offset();
+		let token = lexer.advance();
+		if token == Kind::Eof || start.0 as usize >= offset {
+			break;
+		}
+		match token.kind() {
+			Kind::Function | Kind::LeftParen | Kind::LeftSquare | Kind::LeftCurly => {
+				if let Some(frame) = stack.last_mut() {
+					frame.component();
+				}
+				stack.push(Frame::new(token, Cursor::new(start, token).str_slice(source)));
+			}
+			Kind::RightParen | Kind::RightSquare | Kind::RightCurly => {
+				stack.pop();
+			}
+			kind => {
+				let Some(frame) = stack.last_mut() else { continue };
+				match kind {
+					Kind::Whitespace | Kind::Comment => frame.pending_space |= frame.after_component,
+					Kind::Comma => frame.separator(Comma),
+					Kind::Delim if token.char() == Some('/') => frame.separator(Slash),
+					_ => frame.component(),
+				}
+			}
+		}
+	}
+	stack.iter_mut().rev().find_map(|frame| {
+		if frame.pending_space {
+			frame.separators.push(Space);
+		}
+		let name = frame.function?;
+		let signatures: Vec<&Signature> =
+			SIGNATURES.iter().filter(|signature| signature.name.eq_ignore_ascii_case(name)).collect();
+		if signatures.is_empty() {
+			return None;
+		}
+		// The first signature the arguments fit is active, falling back to the first signature.
+		let (active_signature, active_parameter) = signatures
+			.iter()
+			.enumerate()
+			.find_map(|(i, signature)| Some((i, signature.active_parameter(&frame.separators)?)))
+			.unwrap_or((0, signatures[0].parameters.len() - 1));
+		Some(SignatureHelp {
+			signatures: signatures.iter().map(|signature| signature.information()).collect(),
+			active_signature: Some(active_signature as u32),
+			active_parameter: Some(active_parameter as u32),
+		})
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// Returns the label of the active signature & the text of its active parameter, at the `|` in the source.
+	fn active(source: &str) -> Option<(String, String)> {
+		let offset = source.find('|').unwrap();
+		let source = source.replace('|', "");
+		let help = signature_help(&source, Feature::none(), offset)?;
+		let signature = &help.signatures[help.active_signature? as usize];
+		let ParameterLabel::LabelOffsets([start, end]) =
+			signature.parameters.as_ref()?[help.active_parameter? as usize].label
+		else {
+			return None;
+		};
+		Some((signature.label.clone(), signature.label[start as usize..end as usize].into()))
+	}
+
+	fn parameter(source: &str) -> Option<String> {
+		active(source).map(|(_, parameter)| parameter)
+	}
+
+	#[test]
+	fn test_label() {
+		let (label, parameter) = active("a{color:rgb(|)}").unwrap();
+		assert_eq!(
+			label,
+			"rgb( [ <number> | <percentage> | none ] [ <number> | <percentage> | none ] [ <number> | <percentage> | none ] / [ <alpha-value> | none ]? )"
+		);
+		assert_eq!(parameter, "[ <number> | <percentage> | none ]");
+	}
+
+	#[test]
+	fn test_space_separated() {
+		assert_eq!(parameter("a{color:oklch(50% |)}").as_deref(), Some("[ <percentage> | <number> | none ]"));
+		assert_eq!(parameter("a{color:oklch(50% 0.2 |)}").as_deref(), Some("[ <hue> | none ]"));
+		assert_eq!(parameter("a{color:oklch(50% 0.2 120 / |)}").as_deref(), Some("[ <alpha-value> | none ]?"));
+		assert_eq!(
+			parameter("a{color:color(display-p3 1 |0 0)}").as_deref(),
+			Some("[ <number> | <percentage> | none ]")
+		);
+	}
+
+	#[test]
+	fn test_comma_separated() {
+		let (label, blue) = active("a{color:rgb(255, 0, |)}").unwrap();
+		assert!(label.starts_with("rgb( [ <number> | <percentage> ], "));
+		assert_eq!(blue, "[ <number> | <percentage> ]");
+		assert_eq!(parameter("a{color:hsl(120, 50%, 50%, |)}").as_deref(), Some("<alpha-value>?"));
+		assert_eq!(parameter("a{transform:matrix(1, 0, 0, 1, |)}").as_deref(), Some("<number>"));
+		assert_eq!(parameter("a{transform:translateX(|)}").as_deref(), Some("<length-percentage>"));
+		assert_eq!(parameter("a{content:counters(item, |)}").as_deref(), Some("<string>"));
+	}
+
+	#[test]
+	fn test_mixed_and_repeating() {
+		assert_eq!(parameter("a{top:anchor(--a |)}").as_deref(), Some("<anchor-side>"));
+		assert_eq!(parameter("a{top:anchor(--a top, |)}").as_deref(), Some("<length-percentage>?"));
+		assert_eq!(parameter("a{width:attr(data-w px, |)}").as_deref(), Some("<declaration-value>?"));
+		assert_eq!(parameter("a{b:linear-gradient(to left |)}").as_deref(), Some("[ <angle> | to <side-or-corner> ]?"));
+		assert_eq!(parameter("a{b:linear-gradient(to left, red, blue 50%, |)}").as_deref(), Some("<color-stop-list>"));
+		assert_eq!(parameter("a{b:linear(0, 0.5 50%, |)}").as_deref(), Some("[ <number> && <percentage>{0,2} ]"));
+	}
+
+	#[test]
+	fn test_nesting() {
+		// The innermost known function is used, skipping those (such as calc()) without a signature.
+		assert_eq!(parameter("a{color:rgb(calc(1 + |2) 0 0)}").as_deref(), Some("[ <number> | <percentage> | none ]"));
+		assert_eq!(parameter("a{color:rgb(calc(1 + 2) |0 0)}").as_deref(), Some("[ <number> | <percentage> | none ]"));
+		assert_eq!(
+			parameter("a{b:linear-gradient(rgb(0 0 0 / |), red)}").as_deref(),
+			Some("[ <alpha-value> | none ]?")
+		);
+		assert_eq!(parameter("a{b:linear-gradient(rgb(0 0 0), |red)}").as_deref(), Some("<color-stop-list>"));
+		assert_eq!(active("a{color:rgb(0 0 0)|}"), None);
+		assert_eq!(active("a{color:calc(|)}"), None);
+		assert_eq!(active("a{color:|red}"), None);
+	}
+}
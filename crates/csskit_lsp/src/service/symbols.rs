@@ -0,0 +1,181 @@
+use css_ast::{
+	ContainerRule, FontFaceRule, KeyframesRule, LayerRule, MediaRule, PropertyRule, StyleRule, SupportsRule, Visit,
+	WebkitKeyframesRule,
+};
+use css_lexer::{Cursor, SourceOffset, Span, ToSpan};
+use lsp_types::{DocumentSymbol, SymbolKind};
+use ropey::Rope;
+use std::iter::Peekable;
+
+use super::position::span_to_range;
+
+/// Returns the contents of `span` with all runs of whitespace collapsed into a single space, suitable for displaying
+/// (multi-line) selectors or preludes on one line.
+pub(crate) fn display_text(source: &str, span: Span) -> String {
+	span.span_contents(source).contents().split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Returns the [Span] of the name within an Ident or String [Cursor], excluding any surrounding quotes, so that
+/// replacing the contents of this [Span] renames it.
+pub(crate) fn name_span(c: Cursor) -> Span {
+	let token = c.token();
+	Span::new(SourceOffset(c.offset().0 + token.leading_len()), SourceOffset(c.end_offset().0 - token.trailing_len()))
+}
+
+#[derive(Debug, Clone)]
+struct OutlineEntry {
+	name: String,
+	kind: SymbolKind,
+	span: Span,
+	selection_span: Span,
+}
+
+/// A [Visit] pass which builds the outline of a document: each at-rule which contains other rules or declarations is
+/// a container, and each style rule is named after its selector list. Entries are recorded flat and nested afterwards
+/// by their [Spans][Span], as the visitor has no notion of leaving a node.
+#[derive(Default)]
+pub(crate) struct DocumentOutline<'s> {
+	source: &'s str,
+	entries: Vec<OutlineEntry>,
+}
+
+impl<'s> DocumentOutline<'s> {
+	pub fn new(source: &'s str) -> Self {
+		Self { source, entries: vec![] }
+	}
+
+	fn push_at_rule(&mut self, name: Cursor, prelude: Span, span: Span, kind: SymbolKind) {
+		let keyword = name.str_slice(self.source);
+		let (name, selection_span) = if prelude.is_empty() {
+			(keyword.to_string(), name.span())
+		} else {
+			(format!("{} {}", keyword, display_text(self.source, prelude)), name.span() + prelude)
+		};
+		self.entries.push(OutlineEntry { name, kind, span, selection_span });
+	}
+
+	/// Nests the collected entries and converts them into [DocumentSymbols][DocumentSymbol].
+	pub fn into_symbols(mut self, rope: &Rope) -> Vec<DocumentSymbol> {
+		self.entries.sort_by(|a, b| a.span.start().cmp(&b.span.start()).then(b.span.end().cmp(&a.span.end())));
+		nest(&mut self.entries.into_iter().peekable(), None, rope)
+	}
+}
+
+fn nest(
+	entries: &mut Peekable<impl Iterator<Item = OutlineEntry>>,
+	parent: Option<Span>,
+	rope: &Rope,
+) -> Vec<DocumentSymbol> {
+	let mut symbols = vec![];
+	while let Some(entry) = entries.next_if(|entry| parent.is_none_or(|parent| parent.contains(entry.span))) {
+		let children = nest(entries, Some(entry.span), rope);
+		#[allow(deprecated)]
+		symbols.push(DocumentSymbol {
+			name: entry.name,
+			detail: None,
+			kind: entry.kind,
+			tags: None,
+			deprecated: None,
+			range: span_to_range(rope, entry.span),
+			selection_range: span_to_range(rope, entry.selection_span),
+			children: if children.is_empty() { None } else { Some(children) },
+		});
+	}
+	symbols
+}
+
+impl Visit for DocumentOutline<'_> {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		let prelude = rule.0.prelude.to_span();
+		let name = display_text(self.source, prelude);
+		self.entries.push(OutlineEntry {
+			name,
+			kind: SymbolKind::CLASS,
+			span: rule.to_span(),
+			selection_span: prelude,
+		});
+	}
+
+	fn visit_media_rule<'a>(&mut self, rule: &MediaRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::NAMESPACE);
+	}
+
+	fn visit_container_rule<'a>(&mut self, rule: &ContainerRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::NAMESPACE);
+	}
+
+	fn visit_supports_rule<'a>(&mut self, rule: &SupportsRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::NAMESPACE);
+	}
+
+	fn visit_layer_rule<'a>(&mut self, rule: &LayerRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::MODULE);
+	}
+
+	fn visit_keyframes_rule<'a>(&mut self, rule: &KeyframesRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::EVENT);
+	}
+
+	fn visit_webkit_keyframes_rule<'a>(&mut self, rule: &WebkitKeyframesRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::EVENT);
+	}
+
+	fn visit_property_rule<'a>(&mut self, rule: &PropertyRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), rule.0.prelude.to_span(), rule.to_span(), SymbolKind::PROPERTY);
+	}
+
+	fn visit_font_face_rule<'a>(&mut self, rule: &FontFaceRule<'a>) {
+		self.push_at_rule(rule.0.name.into(), Span::ZERO, rule.to_span(), SymbolKind::OBJECT);
+	}
+}
+
+/// Checks if each character of `query` appears in `name`, in order, ignoring case. An empty query matches everything.
+pub(crate) fn fuzzy_match(query: &str, name: &str) -> bool {
+	let mut chars = name.chars().flat_map(char::to_lowercase);
+	query.chars().flat_map(char::to_lowercase).all(|q| chars.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_ast::{StyleSheet, Visitable};
+	use css_parse::Parser;
+
+	fn outline(source: &str) -> Vec<DocumentSymbol> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut outline = DocumentOutline::new(source);
+		result.output.unwrap().accept(&mut outline);
+		outline.into_symbols(&Rope::from_str(source))
+	}
+
+	#[test]
+	fn test_outline_nesting() {
+		let symbols = outline("@media screen {\n  .a,\n  .b { color: red; .c {} }\n}\n@layer base;\nbody {}");
+		assert_eq!(symbols.len(), 3);
+		assert_eq!(symbols[0].name, "@media screen");
+		assert_eq!(symbols[0].kind, SymbolKind::NAMESPACE);
+		let media_children = symbols[0].children.as_ref().unwrap();
+		assert_eq!(media_children.len(), 1);
+		assert_eq!(media_children[0].name, ".a, .b");
+		assert_eq!(media_children[0].children.as_ref().unwrap()[0].name, ".c");
+		assert_eq!(symbols[1].name, "@layer base");
+		assert_eq!(symbols[2].name, "body");
+		assert_eq!(symbols[2].range.start.line, 5);
+	}
+
+	#[test]
+	fn test_outline_at_rules() {
+		let symbols = outline("@keyframes spin{to{rotate:1turn}}@font-face{font-family:x}@property --x{syntax:'*'}");
+		let names: Vec<_> = symbols.iter().map(|s| s.name.as_str()).collect();
+		assert_eq!(names, vec!["@keyframes spin", "@font-face", "@property --x"]);
+	}
+
+	#[test]
+	fn test_fuzzy_match() {
+		assert!(fuzzy_match("", "--brand"));
+		assert!(fuzzy_match("brd", "--Brand"));
+		assert!(!fuzzy_match("dnarb", "--brand"));
+	}
+}
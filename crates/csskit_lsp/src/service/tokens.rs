@@ -0,0 +1,148 @@
+use csskit_highlight::Highlight;
+use lsp_types::{Range, SemanticToken, SemanticTokensEdit};
+use ropey::Rope;
+
+use super::position::offset_to_position;
+
+/// Encodes the [Highlights][Highlight] as LSP [SemanticTokens][SemanticToken], each relative to the previous token.
+/// When a [Range] is given only the tokens which start within it are included.
+pub(crate) fn semantic_tokens<'h>(
+	rope: &Rope,
+	highlights: impl Iterator<Item = &'h Highlight>,
+	range: Option<Range>,
+) -> Vec<SemanticToken> {
+	let mut highlights: Vec<&Highlight> = highlights.collect();
+	highlights.sort_by_key(|highlight| highlight.span());
+	let mut line = 0;
+	let mut start = 0;
+	highlights
+		.into_iter()
+		.filter_map(|highlight| {
+			let span = highlight.span();
+			let position = offset_to_position(rope, span.start());
+			if range.is_some_and(|range| position < range.start || range.end <= position) {
+				return None;
+			}
+			let length = offset_to_position(rope, span.end()).character.saturating_sub(position.character);
+			let delta_line = position.line - line;
+			let delta_start = if delta_line == 0 { position.character - start } else { position.character };
+			line = position.line;
+			start = position.character;
+			Some(SemanticToken {
+				delta_line,
+				delta_start,
+				length,
+				token_type: highlight.kind().bits() as u32,
+				token_modifiers_bitset: highlight.modifier().bits() as u32,
+			})
+		})
+		.collect()
+}
+
+/// Computes the edit which turns the `previous` tokens into the `next` tokens, by finding the tokens which are the same
+/// at the start & end of both. Edits are counted in integers, of which each token has five.
+pub(crate) fn semantic_tokens_delta(previous: &[SemanticToken], next: &[SemanticToken]) -> Vec<SemanticTokensEdit> {
+	let prefix = previous.iter().zip(next).take_while(|(a, b)| a == b).count();
+	let suffix = previous[prefix..].iter().rev().zip(next[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+	let deleted = previous.len() - prefix - suffix;
+	let inserted = &next[prefix..next.len() - suffix];
+	if deleted == 0 && inserted.is_empty() {
+		return vec![];
+	}
+	vec![SemanticTokensEdit {
+		start: prefix as u32 * 5,
+		delete_count: deleted as u32 * 5,
+		data: (!inserted.is_empty()).then(|| inserted.to_vec()),
+	}]
+}
+
+/// The tokens last sent to the client for a document, so that the next request can respond with just the changes.
+#[derive(Debug, Default)]
+pub(crate) struct SemanticTokensCache {
+	result_id: u64,
+	tokens: Vec<SemanticToken>,
+}
+
+impl SemanticTokensCache {
+	/// Stores the tokens, returning the result id they can be referred to by.
+	pub fn store(&mut self, tokens: Vec<SemanticToken>) -> String {
+		self.result_id += 1;
+		self.tokens = tokens;
+		self.result_id.to_string()
+	}
+
+	/// Returns the cached tokens if they match the given result id.
+	pub fn get(&self, result_id: &str) -> Option<&[SemanticToken]> {
+		(self.result_id.to_string() == result_id).then_some(&self.tokens)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bumpalo::Bump;
+	use css_ast::{StyleSheet, Visitable};
+	use css_parse::Parser;
+	use csskit_highlight::TokenHighlighter;
+	use lsp_types::Position;
+
+	fn tokens(source: &str, range: Option<Range>) -> Vec<[u32; 3]> {
+		let bump = Bump::default();
+		let result = Parser::new(&bump, source).parse_entirely::<StyleSheet>();
+		let mut highlighter = TokenHighlighter::new();
+		result.output.unwrap().accept(&mut highlighter);
+		semantic_tokens(&Rope::from_str(source), highlighter.highlights(), range)
+			.into_iter()
+			.map(|t| [t.delta_line, t.delta_start, t.length])
+			.collect()
+	}
+
+	fn token(delta_start: u32) -> SemanticToken {
+		SemanticToken { delta_line: 0, delta_start, length: 1, token_type: 0, token_modifiers_bitset: 0 }
+	}
+
+	#[test]
+	fn test_semantic_tokens() {
+		// `a`, `{`, `color`, `:`, `red`, `}`
+		assert_eq!(
+			tokens("a {\n\tcolor: red;\n}", None),
+			vec![[0, 0, 1], [0, 2, 1], [1, 1, 5], [0, 5, 1], [0, 2, 3], [1, 0, 1]]
+		);
+		let range = Range::new(Position::new(1, 0), Position::new(2, 0));
+		assert_eq!(tokens("a {\n\tcolor: red;\n}", Some(range)), vec![[1, 1, 5], [0, 5, 1], [0, 2, 3]]);
+	}
+
+	#[test]
+	fn test_semantic_tokens_utf16() {
+		// `🎨` is two UTF-16 code units long, so the `{` starts at the fifth.
+		assert_eq!(tokens(".🎨 { color: red }", None), vec![[0, 4, 1], [0, 2, 5], [0, 5, 1], [0, 2, 3], [0, 4, 1]]);
+	}
+
+	#[test]
+	fn test_semantic_tokens_delta() {
+		let previous = [token(0), token(1), token(2), token(3)];
+		assert_eq!(semantic_tokens_delta(&previous, &previous), vec![]);
+		assert_eq!(
+			semantic_tokens_delta(&previous, &[token(0), token(9), token(2), token(3)]),
+			vec![SemanticTokensEdit { start: 5, delete_count: 5, data: Some(vec![token(9)]) }]
+		);
+		assert_eq!(
+			semantic_tokens_delta(&previous, &[token(0), token(3)]),
+			vec![SemanticTokensEdit { start: 5, delete_count: 10, data: None }]
+		);
+		assert_eq!(
+			semantic_tokens_delta(&previous[..2], &previous),
+			vec![SemanticTokensEdit { start: 10, delete_count: 0, data: Some(vec![token(2), token(3)]) }]
+		);
+	}
+
+	#[test]
+	fn test_semantic_tokens_cache() {
+		let mut cache = SemanticTokensCache::default();
+		let id = cache.store(vec![token(1)]);
+		assert_eq!(cache.get(&id), Some(&[token(1)][..]));
+		let next = cache.store(vec![token(2)]);
+		assert_ne!(id, next);
+		assert_eq!(cache.get(&id), None);
+	}
+}
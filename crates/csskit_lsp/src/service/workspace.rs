@@ -0,0 +1,165 @@
+use bumpalo::Bump;
+use crossbeam_channel::unbounded;
+use css_ast::{StyleSheet, Visitable};
+use css_parse::{Feature, Parser};
+use lsp_types::Uri;
+use ropey::Rope;
+use std::{
+	fs,
+	path::{Path, PathBuf},
+	str::FromStr,
+	sync::Arc,
+	thread::Builder,
+};
+use tracing::{trace, warn};
+
+use super::index::{Index, SymbolCollector};
+
+// Directories which are never searched for stylesheets, as they hold dependencies or build output.
+const IGNORED_DIRECTORIES: [&str; 3] = ["node_modules", "target", "dist"];
+
+// Indexing is mostly IO bound, so only a handful of threads are needed.
+const MAX_INDEX_THREADS: usize = 4;
+
+/// Recursively finds every `.css` file within the directory, skipping hidden directories (such as `.git`) and those
+/// which typically hold dependencies or build output.
+pub(crate) fn discover(dir: &Path) -> Vec<PathBuf> {
+	let mut paths = vec![];
+	let mut dirs = vec![dir.to_path_buf()];
+	while let Some(dir) = dirs.pop() {
+		let Ok(entries) = fs::read_dir(&dir) else {
+			continue;
+		};
+		for entry in entries.flatten() {
+			let path = entry.path();
+			let name = entry.file_name();
+			let name = name.to_string_lossy();
+			let Ok(file_type) = entry.file_type() else {
+				continue;
+			};
+			if file_type.is_dir() {
+				if !name.starts_with('.') && !IGNORED_DIRECTORIES.contains(&name.as_ref()) {
+					dirs.push(path);
+				}
+			} else if is_stylesheet(&path) {
+				paths.push(path);
+			}
+		}
+	}
+	paths.sort();
+	paths
+}
+
+pub(crate) fn is_stylesheet(path: &Path) -> bool {
+	path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("css"))
+}
+
+/// Converts a `file://` [Uri] into a [PathBuf], decoding any percent encoded characters.
+pub(crate) fn uri_to_path(uri: &Uri) -> Option<PathBuf> {
+	let path = uri.as_str().strip_prefix("file://")?;
+	let mut bytes = vec![];
+	let mut iter = path.bytes();
+	while let Some(byte) = iter.next() {
+		if byte == b'%' {
+			let hex = [iter.next()?, iter.next()?];
+			bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+		} else {
+			bytes.push(byte);
+		}
+	}
+	String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+/// Converts an absolute [Path] into a `file://` [Uri], percent encoding any characters which are not allowed in a
+/// URI path.
+pub(crate) fn path_to_uri(path: &Path) -> Option<Uri> {
+	let mut uri = String::from("file://");
+	for byte in path.to_str()?.bytes() {
+		if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+			uri.push(byte as char);
+		} else {
+			uri.push_str(&format!("%{byte:02X}"));
+		}
+	}
+	Uri::from_str(&uri).ok()
+}
+
+/// Parses the stylesheet at the [Path], replacing its symbols in the [Index]. Unlike opened documents, which keep a
+/// parsed tree around on their own thread, only the symbols of these files are kept.
+pub(crate) fn index_file(index: &Index, uri: Uri, path: &Path, features: Feature) {
+	let Ok(source) = fs::read_to_string(path) else {
+		warn!("Could not read {:?} to index it", path);
+		return;
+	};
+	let bump = Bump::default();
+	let result = Parser::new_with_features(&bump, &source, features).parse_entirely::<StyleSheet>();
+	let mut collector = SymbolCollector::new(&source);
+	if let Some(stylesheet) = &result.output {
+		stylesheet.accept(&mut collector);
+	}
+	index.update(uri, collector.into_symbols(&Rope::from_str(&source)));
+}
+
+/// Indexes each of the paths, parsed with their [Features][Feature], on a pool of background threads. Paths where `skip`
+/// returns true (for example because the document is open, and so is indexed from its unsaved contents instead) are left
+/// alone.
+pub(crate) fn index_in_background(
+	index: Arc<Index>,
+	paths: Vec<(PathBuf, Feature)>,
+	skip: impl Fn(&Uri) -> bool + Send + Sync + 'static,
+) {
+	if paths.is_empty() {
+		return;
+	}
+	let (sender, receiver) = unbounded::<(PathBuf, Feature)>();
+	let threads = std::thread::available_parallelism().map_or(1, |n| n.get()).min(MAX_INDEX_THREADS).min(paths.len());
+	let skip = Arc::new(skip);
+	for _ in 0..threads {
+		let receiver = receiver.clone();
+		let index = index.clone();
+		let skip = skip.clone();
+		Builder::new()
+			.name("LspWorkspaceIndexer".into())
+			.spawn(move || {
+				while let Ok((path, features)) = receiver.recv() {
+					let Some(uri) = path_to_uri(&path) else { continue };
+					if !skip(&uri) {
+						trace!("Indexing {:?}", &uri);
+						index_file(&index, uri, &path, features);
+					}
+				}
+			})
+			.expect("Failed to create workspace indexer");
+	}
+	for path in paths {
+		sender.send(path).ok();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_uri_to_path() {
+		let path = Path::new("/home/me/my project/a%b.css");
+		let uri = path_to_uri(path).unwrap();
+		assert_eq!(uri.as_str(), "file:///home/me/my%20project/a%25b.css");
+		assert_eq!(uri_to_path(&uri).as_deref(), Some(path));
+		assert_eq!(uri_to_path(&Uri::from_str("untitled:Untitled-1").unwrap()), None);
+	}
+
+	#[test]
+	fn test_discover() {
+		let dir = std::env::temp_dir().join(format!("csskit_lsp_discover_{}", std::process::id()));
+		for path in ["a.css", "b.txt", "nested/c.CSS", "node_modules/d.css", ".git/e.css"] {
+			let path = dir.join(path);
+			fs::create_dir_all(path.parent().unwrap()).unwrap();
+			fs::write(path, "a{}").unwrap();
+		}
+		let found: Vec<_> =
+			discover(&dir).into_iter().map(|path| path.strip_prefix(&dir).unwrap().to_owned()).collect();
+		fs::remove_dir_all(&dir).unwrap();
+		assert_eq!(found, vec![PathBuf::from("a.css"), PathBuf::from("nested/c.CSS")]);
+	}
+}
@@ -0,0 +1,197 @@
+use crossbeam_channel::{Receiver, Sender};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::{fs, path::Path, time::Duration};
+
+use crate::{Id, LSPService, Message, Notification, Request, Response, Server};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A single message sent by the client in a fixture. Each fixture is a JSON array of these, for example:
+///
+/// ```json
+/// [
+///   { "request": "initialize", "params": { "capabilities": {} } },
+///   { "notify": "textDocument/didOpen", "params": { ... } },
+///   { "request": "textDocument/completion", "params": { ... }, "expect": null }
+/// ]
+/// ```
+///
+/// Requests wait for their response, which must equal `expect` if it is given. Requests which the server sends to the
+/// client are answered with a step such as `{ "respond": "workspace/configuration", "result": [...] }`, which waits for
+/// the next message from the server and checks it is a request with that method. Likewise, notifications which the
+/// server sends outside of a request are waited for with `{ "receive": "window/showMessage" }`, whose params must equal
+/// `expect` if it is given. Everything the server sends is collected so it can be snapshotted, unless a request sets
+/// `"snapshot": false` (useful to keep the `initialize` capabilities out of most fixtures).
+///
+/// `$FIXTURES` is replaced with the path of the fixtures directory, for fixtures which need files on disk (such as
+/// `"file://$FIXTURES/broken_config"`), and the path is replaced back with `$FIXTURES` in received notifications.
+#[derive(Deserialize, Debug)]
+#[serde(untagged, deny_unknown_fields)]
+enum Step {
+	Request {
+		request: String,
+		#[serde(default)]
+		params: Value,
+		#[serde(default, deserialize_with = "present")]
+		expect: Option<Value>,
+		#[serde(default = "yes")]
+		snapshot: bool,
+	},
+	Notification {
+		notify: String,
+		#[serde(default)]
+		params: Value,
+	},
+	Response {
+		respond: String,
+		#[serde(default)]
+		result: Value,
+	},
+	Receive {
+		receive: String,
+		#[serde(default, deserialize_with = "present")]
+		expect: Option<Value>,
+	},
+}
+
+// Distinguishes `"expect": null` from a missing `expect`.
+fn present<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+	Value::deserialize(deserializer).map(Some)
+}
+
+fn yes() -> bool {
+	true
+}
+
+/// A client talking to an in-process [LSPService] over [Server::raw_channels].
+pub(crate) struct TestClient {
+	// The server's handler thread only lives as long as its channels.
+	#[allow(dead_code)]
+	server: Server,
+	sender: Sender<Message>,
+	receiver: Receiver<Message>,
+	next_id: i32,
+}
+
+impl TestClient {
+	pub fn new() -> Self {
+		let server = Server::new(LSPService::new("0.0.0"));
+		let (sender, receiver) = server.raw_channels();
+		Self { server, sender, receiver, next_id: 1 }
+	}
+
+	/// Sends a request, returning every message the server sent up to and including its response.
+	pub fn request(&mut self, method: &str, params: Value) -> Vec<Message> {
+		let id: Id = self.next_id.into();
+		self.next_id += 1;
+		self.send(Message::Request(Request { id: id.clone(), method: method.into(), params }));
+		let mut messages = vec![];
+		loop {
+			let message = self
+				.receiver
+				.recv_timeout(TIMEOUT)
+				.unwrap_or_else(|_| panic!("no response to {method} within {TIMEOUT:?}"));
+			let done = matches!(&message, Message::Response(Response::Ok(r, _) | Response::Err(r, ..)) if *r == id);
+			messages.push(message);
+			if done {
+				return messages;
+			}
+		}
+	}
+
+	/// Waits for the server to send a request with the method, and responds to it with the result.
+	pub fn respond(&mut self, method: &str, result: Value) -> Message {
+		let message =
+			self.receiver.recv_timeout(TIMEOUT).unwrap_or_else(|_| panic!("no {method} request within {TIMEOUT:?}"));
+		match &message {
+			Message::Request(request) if request.method == method => {
+				self.send(Message::Response(Response::Ok(request.id.clone(), result)));
+			}
+			other => panic!("expected a {method} request, got {other:?}"),
+		}
+		message
+	}
+
+	/// Waits for the server to send a notification with the method.
+	pub fn receive(&mut self, method: &str) -> Notification {
+		let message = self
+			.receiver
+			.recv_timeout(TIMEOUT)
+			.unwrap_or_else(|_| panic!("no {method} notification within {TIMEOUT:?}"));
+		match message {
+			Message::Notification(notification) if notification.method == method => notification,
+			other => panic!("expected a {method} notification, got {other:?}"),
+		}
+	}
+
+	pub fn notify(&mut self, method: &str, params: Value) {
+		self.send(Message::Notification(Notification { method: method.into(), params }));
+	}
+
+	fn send(&self, message: Message) {
+		self.sender.send_timeout(message, TIMEOUT).expect("server stopped accepting messages");
+	}
+}
+
+impl Drop for TestClient {
+	fn drop(&mut self) {
+		self.sender
+			.send_timeout(Message::Notification(Notification { method: "exit".into(), params: Value::Null }), TIMEOUT)
+			.ok();
+	}
+}
+
+// Replaces the fixtures directory within the params of a notification with `$FIXTURES`, so snapshots don't depend on
+// where the repository is.
+fn redact(notification: Notification, dir: &str) -> Notification {
+	let params = serde_json::to_string(&notification.params).unwrap().replace(dir, "$FIXTURES");
+	Notification { method: notification.method, params: serde_json::from_str(&params).unwrap() }
+}
+
+/// Runs the fixture at `path` against a new [TestClient], returning every message the server sent.
+pub(crate) fn run_fixture(path: &Path) -> Vec<Message> {
+	let dir = path.parent().unwrap().to_str().unwrap();
+	let source = fs::read_to_string(path).unwrap_or_else(|e| panic!("couldn't read {}: {e}", path.display()));
+	let source = source.replace("$FIXTURES", dir);
+	let steps: Vec<Step> =
+		serde_json::from_str(&source).unwrap_or_else(|e| panic!("couldn't parse {}: {e}", path.display()));
+	let mut client = TestClient::new();
+	let mut messages = vec![];
+	for step in steps {
+		match step {
+			Step::Request { request, params, expect, snapshot } => {
+				let received = client.request(&request, params);
+				if let Some(expected) = expect {
+					match received.last() {
+						Some(Message::Response(Response::Ok(_, actual))) => {
+							assert_eq!(actual, &expected, "unexpected result for {request} in {}", path.display())
+						}
+						other => panic!("expected a result for {request} in {}, got {other:?}", path.display()),
+					}
+				}
+				if snapshot {
+					messages.extend(received);
+				}
+			}
+			Step::Notification { notify, params } => client.notify(&notify, params),
+			Step::Response { respond, result } => messages.push(client.respond(&respond, result)),
+			Step::Receive { receive, expect } => {
+				let notification = redact(client.receive(&receive), dir);
+				if let Some(expected) = expect {
+					assert_eq!(notification.params, expected, "unexpected params for {receive} in {}", path.display());
+				}
+				messages.push(Message::Notification(notification));
+			}
+		}
+	}
+	messages
+}
+
+macro_rules! assert_fixture {
+	($name: literal $(,)*) => {
+		let path = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(concat!("fixtures/", $name, ".json"));
+		::insta::assert_json_snapshot!($name, $crate::test_helpers::run_fixture(&path));
+	};
+}
+pub(crate) use assert_fixture;
@@ -0,0 +1,81 @@
+use super::test_helpers::*;
+
+#[test]
+fn test_initialize() {
+	assert_fixture!("initialize");
+}
+
+#[test]
+fn test_document_lifecycle() {
+	assert_fixture!("document_lifecycle");
+}
+
+#[test]
+fn test_semantic_tokens() {
+	assert_fixture!("semantic_tokens");
+}
+
+#[test]
+fn test_diagnostics() {
+	assert_fixture!("diagnostics");
+}
+
+#[test]
+fn test_completion() {
+	assert_fixture!("completion");
+}
+
+#[test]
+fn test_formatting() {
+	assert_fixture!("formatting");
+}
+
+#[test]
+fn test_configuration() {
+	assert_fixture!("configuration");
+}
+
+#[test]
+fn test_watched_files() {
+	assert_fixture!("watched_files");
+}
+
+#[test]
+fn test_symbols() {
+	assert_fixture!("symbols");
+}
+
+#[test]
+fn test_references() {
+	assert_fixture!("references");
+}
+
+#[test]
+fn test_colors() {
+	assert_fixture!("colors");
+}
+
+#[test]
+fn test_links() {
+	assert_fixture!("links");
+}
+
+#[test]
+fn test_inlay_hints() {
+	assert_fixture!("inlay_hints");
+}
+
+#[test]
+fn test_signature_help() {
+	assert_fixture!("signature_help");
+}
+
+#[test]
+fn test_show_message() {
+	assert_fixture!("show_message");
+}
+
+#[test]
+fn test_uninitialized() {
+	assert_fixture!("uninitialized");
+}
@@ -0,0 +1,173 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+	DeriveInput, Error, Ident, Lit, LitStr, Result, Token,
+	parse::{Parse, ParseStream},
+	punctuated::Punctuated,
+};
+
+// Each of the compat attributes can be `Unknown`, which generates a `None` const.
+fn parse_unknown(input: ParseStream) -> Result<bool> {
+	if input.peek(Ident) && input.fork().parse::<Ident>()? == "Unknown" {
+		input.parse::<Ident>()?;
+		return Ok(true);
+	}
+	Ok(false)
+}
+
+fn generate_const(ast: DeriveInput, name: TokenStream, ty: TokenStream, value: TokenStream) -> TokenStream {
+	let ident = &ast.ident;
+	let generics = &ast.generics;
+	quote! {
+		#ast
+		#[automatically_derived]
+		impl #generics #ident #generics {
+			pub const #name: #ty = #value;
+		}
+	}
+}
+
+/// `#[popularity(87.038)]`: the percentage of page loads which use the property.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Popularity(pub Option<f32>);
+
+impl Parse for Popularity {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if parse_unknown(input)? {
+			return Ok(Self(None));
+		}
+		match input.parse::<Lit>()? {
+			Lit::Float(lit) => Ok(Self(Some(lit.base10_parse()?))),
+			Lit::Int(lit) => Ok(Self(Some(lit.base10_parse()?))),
+			lit => Err(Error::new(lit.span(), "expected a percentage or Unknown")),
+		}
+	}
+}
+
+pub fn generate_popularity(args: Popularity, ast: DeriveInput) -> TokenStream {
+	let value = match args.0 {
+		Some(popularity) => quote! { Some(#popularity) },
+		None => quote! { None },
+	};
+	generate_const(ast, quote! { POPULARITY }, quote! { Option<f32> }, value)
+}
+
+/// `#[caniuse("https://caniuse.com/css-grid")]`: the caniuse page for the property.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Caniuse(pub Option<String>);
+
+impl Parse for Caniuse {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if parse_unknown(input)? {
+			return Ok(Self(None));
+		}
+		Ok(Self(Some(input.parse::<LitStr>()?.value())))
+	}
+}
+
+pub fn generate_caniuse(args: Caniuse, ast: DeriveInput) -> TokenStream {
+	let value = match args.0 {
+		Some(url) => quote! { Some(#url) },
+		None => quote! { None },
+	};
+	generate_const(ast, quote! { CANIUSE }, quote! { Option<&'static str> }, value)
+}
+
+/// `#[baseline(widely)]`: the Baseline status of the property, one of `widely`, `newly` or `limited`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Baseline(pub Option<Ident>);
+
+impl Parse for Baseline {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if parse_unknown(input)? {
+			return Ok(Self(None));
+		}
+		let ident = input.parse::<Ident>()?;
+		let variant = match ident.to_string().as_str() {
+			"widely" => "Widely",
+			"newly" => "Newly",
+			"limited" => "Limited",
+			_ => Err(Error::new(ident.span(), "expected widely, newly, limited or Unknown"))?,
+		};
+		Ok(Self(Some(Ident::new(variant, ident.span()))))
+	}
+}
+
+pub fn generate_baseline(args: Baseline, ast: DeriveInput) -> TokenStream {
+	let value = match args.0 {
+		Some(variant) => quote! { Some(crate::Baseline::#variant) },
+		None => quote! { None },
+	};
+	generate_const(ast, quote! { BASELINE }, quote! { Option<crate::Baseline> }, value)
+}
+
+/// `#[versions(chrome:56,safari:15.4)]`: the first version of each browser to support the property, keyed by their
+/// [browser-compat-data](https://github.com/mdn/browser-compat-data) names. Browsers which `css_ast::Browser` doesn't
+/// model (such as `firefox_android`) are ignored.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Versions(pub Option<Vec<(Ident, u16, u16)>>);
+
+struct Version {
+	browser: Ident,
+	major: u16,
+	minor: u16,
+}
+
+impl Parse for Version {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let browser = input.parse::<Ident>()?;
+		input.parse::<Token![:]>()?;
+		let (major, minor) = match input.parse::<Lit>()? {
+			Lit::Int(lit) => (lit.base10_parse()?, 0),
+			Lit::Float(lit) => {
+				let digits = lit.base10_digits().to_owned();
+				let (major, minor) = digits.split_once('.').unwrap_or((&digits, "0"));
+				let invalid = |_| Error::new(lit.span(), "expected a version such as 15.4");
+				(major.parse().map_err(invalid)?, minor.parse().map_err(invalid)?)
+			}
+			lit => Err(Error::new(lit.span(), "expected a version such as 15.4"))?,
+		};
+		Ok(Self { browser, major, minor })
+	}
+}
+
+impl Parse for Versions {
+	fn parse(input: ParseStream) -> Result<Self> {
+		if parse_unknown(input)? {
+			return Ok(Self(None));
+		}
+		let versions = Punctuated::<Version, Token![,]>::parse_terminated(input)?;
+		Ok(Self(Some(
+			versions
+				.into_iter()
+				.filter_map(|Version { browser, major, minor }| {
+					let variant = match browser.to_string().as_str() {
+						"chrome" => "Chrome",
+						"chrome_android" => "AndroidChrome",
+						"edge" => "Edge",
+						"firefox" => "Firefox",
+						"opera" => "Opera",
+						"safari" => "Safari",
+						"safari_ios" => "IosSafari",
+						"samsunginternet_android" => "Samsung",
+						_ => return None,
+					};
+					Some((Ident::new(variant, browser.span()), major, minor))
+				})
+				.collect(),
+		)))
+	}
+}
+
+pub fn generate_versions(args: Versions, ast: DeriveInput) -> TokenStream {
+	let value = match args.0 {
+		Some(versions) => {
+			let versions = versions.iter().map(|(browser, major, minor)| {
+				quote! { (crate::Browser::#browser, crate::Version::new(#major, #minor)) }
+			});
+			quote! { Some(crate::Support::from_versions(&[#(#versions),*])) }
+		}
+		None => quote! { None },
+	};
+	generate_const(ast, quote! { VERSIONS }, quote! { Option<crate::Support> }, value)
+}
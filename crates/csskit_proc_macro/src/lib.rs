@@ -2,6 +2,7 @@
 use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
+mod compat;
 mod def;
 mod generate;
 mod initial;
@@ -99,21 +100,29 @@ pub fn visit(_args: TokenStream, input: TokenStream) -> TokenStream {
 }
 
 #[proc_macro_attribute]
-pub fn popularity(_args: TokenStream, input: TokenStream) -> TokenStream {
-	input
+pub fn popularity(args: TokenStream, input: TokenStream) -> TokenStream {
+	let args = parse_macro_input!(args as compat::Popularity);
+	let ast = parse_macro_input!(input as DeriveInput);
+	compat::generate_popularity(args, ast).into()
 }
 
 #[proc_macro_attribute]
-pub fn caniuse(_args: TokenStream, input: TokenStream) -> TokenStream {
-	input
+pub fn caniuse(args: TokenStream, input: TokenStream) -> TokenStream {
+	let args = parse_macro_input!(args as compat::Caniuse);
+	let ast = parse_macro_input!(input as DeriveInput);
+	compat::generate_caniuse(args, ast).into()
 }
 
 #[proc_macro_attribute]
-pub fn baseline(_args: TokenStream, input: TokenStream) -> TokenStream {
-	input
+pub fn baseline(args: TokenStream, input: TokenStream) -> TokenStream {
+	let args = parse_macro_input!(args as compat::Baseline);
+	let ast = parse_macro_input!(input as DeriveInput);
+	compat::generate_baseline(args, ast).into()
 }
 
 #[proc_macro_attribute]
-pub fn versions(_args: TokenStream, input: TokenStream) -> TokenStream {
-	input
+pub fn versions(args: TokenStream, input: TokenStream) -> TokenStream {
+	let args = parse_macro_input!(args as compat::Versions);
+	let ast = parse_macro_input!(input as DeriveInput);
+	compat::generate_versions(args, ast).into()
 }
@@ -705,3 +705,22 @@ fn group_with_optional_leader() {
 	let data = to_deriveinput! { enum Foo {} };
 	assert_snapshot!(syntax, data, "group_with_optional_leader");
 }
+
+#[test]
+fn test_compat_attributes_parse() {
+	use crate::compat::*;
+	assert_eq!(::syn::parse2::<Popularity>(quote! { 87.038 }).unwrap(), Popularity(Some(87.038)));
+	assert_eq!(::syn::parse2::<Popularity>(quote! { Unknown }).unwrap(), Popularity(None));
+	assert_eq!(
+		::syn::parse2::<Caniuse>(quote! { "https://caniuse.com/css-grid" }).unwrap(),
+		Caniuse(Some("https://caniuse.com/css-grid".into()))
+	);
+	assert_eq!(::syn::parse2::<Baseline>(quote! { newly }).unwrap().0.unwrap(), "Newly");
+	assert!(::syn::parse2::<Baseline>(quote! { everywhere }).is_err());
+	let versions =
+		::syn::parse2::<Versions>(quote! { chrome:61,firefox_android:36,safari_ios:15.4 }).unwrap().0.unwrap();
+	let versions: Vec<_> =
+		versions.iter().map(|(browser, major, minor)| (browser.to_string(), *major, *minor)).collect();
+	assert_eq!(versions, vec![("Chrome".into(), 61, 0), ("IosSafari".into(), 15, 4)]);
+	assert_eq!(::syn::parse2::<Versions>(quote! { Unknown }).unwrap(), Versions(None));
+}
@@ -0,0 +1,151 @@
+use bumpalo::Bump;
+use css_ast::{BrowserTarget, Color, Srgba, StyleSheet, Visit, Visitable, function_support};
+use css_lexer::{Span, ToSpan};
+use css_parse::{Block, DeclarationValue, Parse, Parser, ToCursors};
+
+use crate::{Edit, leading_whitespace, text, trimmed};
+
+// The color functions which can describe colors outside of sRGB.
+const COLOR_FUNCTIONS: &[&str] = &["color", "lab", "lch", "oklab", "oklch"];
+
+/// Returns the [Edits][Edit] which add an sRGB fallback before each declaration using a `color()`, `lab()`, `lch()`,
+/// `oklab()` or `oklch()` color which at least one of the browser targets doesn't support.
+///
+/// The fallback is a copy of the declaration with each of those colors converted to a hex color (or `rgba()` where it
+/// is translucent), clipped into the sRGB gamut. Declarations which already follow a declaration of the same property
+/// are left as they are, as are colors which can't be resolved, such as those using `var()`.
+pub fn lower_colors(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	let functions: Vec<&str> = COLOR_FUNCTIONS
+		.iter()
+		.copied()
+		.filter(|name| {
+			function_support(name).is_some_and(|support| targets.iter().any(|target| !support.supports(target)))
+		})
+		.collect();
+	if functions.is_empty() {
+		return vec![];
+	}
+	let mut visitor = LowerColors { source, functions, ..Default::default() };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct LowerColors<'s> {
+	source: &'s str,
+	// The color functions which need a fallback.
+	functions: Vec<&'static str>,
+	edits: Vec<Edit>,
+}
+
+impl LowerColors<'_> {
+	// Returns the value with each color which needs a fallback converted to sRGB, or `None` if it has none (or one can't
+	// be resolved).
+	fn fallback(&self, value: &str) -> Option<String> {
+		let bump = Bump::default();
+		let lower = value.to_ascii_lowercase();
+		let mut fallback = String::new();
+		let mut last = 0;
+		let mut i = 0;
+		while let Some(open) = lower[i..].find('(').map(|open| open + i) {
+			let start = lower[..open].rfind(|c: char| !c.is_ascii_alphanumeric() && c != '-').map_or(0, |i| i + 1);
+			if !self.functions.contains(&&lower[start..open]) {
+				i = open + 1;
+				continue;
+			}
+			let mut depth = 0;
+			let close = lower[open..].char_indices().find_map(|(j, c)| {
+				match c {
+					'(' => depth += 1,
+					')' if depth == 1 => return Some(open + j),
+					')' => depth -= 1,
+					_ => {}
+				}
+				None
+			})?;
+			let function = &value[start..=close];
+			let result = Parser::new(&bump, function).parse_entirely::<Color>();
+			if !result.errors.is_empty() {
+				return None;
+			}
+			fallback.push_str(&value[last..start]);
+			fallback.push_str(&to_srgb(result.output?.to_srgba(function)?));
+			last = close + 1;
+			i = close + 1;
+		}
+		(last > 0).then(|| format!("{fallback}{}", &value[last..]))
+	}
+}
+
+fn to_srgb(color: Srgba) -> String {
+	if color.alpha == 1.0 {
+		return color.to_hex();
+	}
+	let [r, g, b] = color.to_rgb8();
+	format!("rgba({r}, {g}, {b}, {})", (color.alpha * 1000.0).round() / 1000.0)
+}
+
+impl Visit for LowerColors<'_> {
+	fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(&mut self, block: &Block<'a, D, R>) {
+		let source = self.source;
+		let names: Vec<String> =
+			block.declarations.iter().map(|d| text(source, d.name.to_span()).to_ascii_lowercase()).collect();
+		for (i, declaration) in block.declarations.iter().enumerate() {
+			// Custom properties are never invalid when parsed, so browsers would never use a fallback for them.
+			if names[i].starts_with("--") || (i > 0 && names[i - 1] == names[i]) {
+				continue;
+			}
+			let value_span = trimmed(source, declaration.value.to_span());
+			let Some(fallback) = self.fallback(text(source, value_span)) else {
+				continue;
+			};
+			let span = declaration.to_span();
+			let end = declaration.semicolon.map_or(span.end(), |semicolon| semicolon.to_span().start());
+			let before_value = text(source, Span::new(span.start(), value_span.start()));
+			let after_value = text(source, Span::new(value_span.end(), end)).trim_end();
+			let whitespace = leading_whitespace(source, span);
+			self.edits.push(Edit::new(
+				Span::new(span.start(), span.start()),
+				format!("{before_value}{fallback}{after_value};{whitespace}"),
+			));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn lower(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 100", "firefox 115", "safari 15.4"].map(|target| target.parse().unwrap());
+		lower_colors(source, stylesheet, &targets)
+	}
+
+	fn lower_new(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 120", "firefox 120", "safari 17"].map(|target| target.parse().unwrap());
+		lower_colors(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_lower_colors() {
+		assert_transform!(
+			lower,
+			"a { color: oklch(62.8% 0.2577 29.23) }",
+			"a { color: #f00; color: oklch(62.8% 0.2577 29.23) }"
+		);
+		assert_transform!(
+			lower,
+			"a {\n\tbackground: linear-gradient(LAB(54.29% 80.82 69.91 / 50%), color(display-p3 1 0 0)) !important;\n}",
+			"a {\n\tbackground: linear-gradient(rgba(255, 0, 0, 0.5), #f00) !important;\n\tbackground: linear-gradient(LAB(54.29% 80.82 69.91 / 50%), color(display-p3 1 0 0)) !important;\n}"
+		);
+	}
+
+	#[test]
+	fn test_unlowered_colors() {
+		assert_transform!(lower, "a { color: red; color: lab(50% 40 60) }", "a { color: red; color: lab(50% 40 60) }");
+		assert_transform!(lower, "a { color: oklch(var(--l) 0.1 30) }", "a { color: oklch(var(--l) 0.1 30) }");
+		assert_transform!(lower, "a { --brand: oklch(50% 0.1 30) }", "a { --brand: oklch(50% 0.1 30) }");
+		assert_transform!(lower_new, "a { color: oklch(50% 0.1 30) }", "a { color: oklch(50% 0.1 30) }");
+	}
+}
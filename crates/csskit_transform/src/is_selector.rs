@@ -0,0 +1,150 @@
+use css_ast::{
+	BrowserTarget, Combinator, CompoundSelector, FunctionalPseudoClass, IsPseudoFunction, SelectorComponent, StyleRule,
+	StyleSheet, Support, ToSpecificity, Visit, Visitable, selector_support,
+};
+use css_lexer::{Span, ToSpan};
+
+use crate::{Edit, text, trimmed};
+
+/// Returns the [Edits][Edit] which replace each `:is()` pseudo-class in a style rule's selectors with a selector for
+/// each of its arguments (so `:is(h1, h2) a` becomes `h1 a, h2 a`), when at least one of the browser targets doesn't
+/// support it.
+///
+/// An `:is()` is only replaced where doing so matches the same elements with the same specificity: every argument must
+/// have the same specificity, arguments with combinators must be the whole of the first compound selector, and
+/// arguments starting with a type selector must start their compound selector.
+pub fn lower_is(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	let support = selector_support(":is").unwrap_or(Support::NONE);
+	if targets.iter().all(|target| support.supports(target)) {
+		return vec![];
+	}
+	let mut visitor = LowerIs { source, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct LowerIs<'s> {
+	source: &'s str,
+	edits: Vec<Edit>,
+}
+
+// The nesting selector (`&`) is parsed as a combinator, but is compounded like any other simple selector.
+fn is_combinator(component: &SelectorComponent) -> bool {
+	matches!(component, SelectorComponent::Combinator(combinator) if !matches!(combinator, Combinator::Nesting(_)))
+}
+
+impl LowerIs<'_> {
+	// Returns the selectors which a selector expands to, or `None` if it has no `:is()` which can be replaced.
+	fn expand(&self, selector: &CompoundSelector) -> Option<Vec<String>> {
+		let span = trimmed(self.source, selector.to_span());
+		let mut expanded = vec![String::new()];
+		let mut last = span.start();
+		for (i, component) in selector.0.iter().enumerate() {
+			let SelectorComponent::FunctionalPseudoClass(FunctionalPseudoClass::Is(is)) = component else {
+				continue;
+			};
+			let Some(alternatives) = self.alternatives(&selector.0, i, is) else {
+				continue;
+			};
+			let before = text(self.source, Span::new(last, component.to_span().start()));
+			expanded = expanded
+				.iter()
+				.flat_map(|prefix| alternatives.iter().map(move |alternative| format!("{prefix}{before}{alternative}")))
+				.collect();
+			last = component.to_span().end();
+		}
+		if last == span.start() {
+			return None;
+		}
+		let after = text(self.source, Span::new(last, span.end()));
+		Some(expanded.into_iter().map(|prefix| format!("{prefix}{after}")).collect())
+	}
+
+	// Returns the selectors which can replace the `:is()` at the index within a selector's components.
+	fn alternatives(&self, components: &[SelectorComponent], i: usize, is: &IsPseudoFunction) -> Option<Vec<String>> {
+		let arguments: Vec<&CompoundSelector> = (&is.value.0).into_iter().map(|(selector, _)| selector).collect();
+		let specificity = arguments.first()?.specificity();
+		let starts_compound = i == 0 || is_combinator(&components[i - 1]);
+		let first_compound = i == 0 && components.get(i + 1).is_none_or(is_combinator);
+		for argument in &arguments {
+			if argument.specificity() != specificity {
+				return None;
+			}
+			// `.a :is(.b .c)` also matches a `.c` within a `.b` which is outside of the `.a`, unlike `.a .b .c`.
+			if argument.0.iter().any(is_combinator) && !first_compound {
+				return None;
+			}
+			let type_selector = matches!(
+				argument.0.first(),
+				Some(SelectorComponent::Tag(_) | SelectorComponent::Wildcard(_) | SelectorComponent::Namespace(_))
+			);
+			if type_selector && !starts_compound {
+				return None;
+			}
+		}
+		Some(
+			arguments
+				.iter()
+				.flat_map(|argument| {
+					self.expand(argument)
+						.unwrap_or_else(|| vec![text(self.source, trimmed(self.source, argument.to_span())).to_owned()])
+				})
+				.collect(),
+		)
+	}
+}
+
+impl Visit for LowerIs<'_> {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		let mut changed = false;
+		let mut selectors: Vec<String> = vec![];
+		for (selector, _) in &rule.0.prelude.0 {
+			let expanded = self.expand(selector);
+			changed |= expanded.is_some();
+			let expanded = expanded
+				.unwrap_or_else(|| vec![text(self.source, trimmed(self.source, selector.to_span())).to_owned()]);
+			for selector in expanded {
+				if !selectors.contains(&selector) {
+					selectors.push(selector);
+				}
+			}
+		}
+		if changed {
+			self.edits.push(Edit::new(trimmed(self.source, rule.0.prelude.to_span()), selectors.join(", ")));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn lower(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 80", "firefox 60", "safari 12"].map(|target| target.parse().unwrap());
+		lower_is(source, stylesheet, &targets)
+	}
+
+	fn lower_new(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 120", "firefox 120", "safari 17"].map(|target| target.parse().unwrap());
+		lower_is(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_lower_is() {
+		assert_transform!(lower, ":is(h1, h2) a { color: red }", "h1 a, h2 a { color: red }");
+		assert_transform!(lower, ".x:is(.a, .b)::before, .y {}", ".x.a::before, .x.b::before, .y {}");
+		assert_transform!(lower, ":is(.a .b, .c > .d) .e {}", ".a .b .e, .c > .d .e {}");
+		assert_transform!(lower, ":is(:is(.a, .b), .c) {}", ".a, .b, .c {}");
+		assert_transform!(lower, ":is(.a, .b):is(.c, .d) {}", ".a.c, .a.d, .b.c, .b.d {}");
+	}
+
+	#[test]
+	fn test_unlowered_is() {
+		assert_transform!(lower, ":is(.a, #b) {}", ":is(.a, #b) {}");
+		assert_transform!(lower, ".x:is(a, b) {}", ".x:is(a, b) {}");
+		assert_transform!(lower, ".x :is(.a .b, .c .d) {}", ".x :is(.a .b, .c .d) {}");
+		assert_transform!(lower_new, ":is(h1, h2) a {}", ":is(h1, h2) a {}");
+	}
+}
@@ -1,6 +1,49 @@
 #![deny(warnings)]
+//! Transforms which rewrite a parsed [StyleSheet][css_ast::StyleSheet], for example to down-level newer syntax for
+//! older browsers. Each transform returns the [Edits][Edit] which make its changes, which can be applied to the source
+//! with [apply_edits].
 // mod reduce_initial;
 // pub use reduce_initial::*;
 
+mod colors;
+mod is_selector;
+mod logical;
+mod media_merge;
+mod media_ranges;
+mod nesting;
+mod prefixes;
+mod supports;
+
+pub use colors::*;
+pub use css_parse::{Edit, apply_edits};
+pub use is_selector::*;
+pub use logical::*;
+pub use media_merge::*;
+pub use media_ranges::*;
+pub use nesting::*;
+pub use prefixes::*;
+pub use supports::*;
+
+use css_lexer::{SourceOffset, Span};
+pub(crate) use css_parse::text;
+
+// The span of a node along with the whitespace before it, so that removing it doesn't leave a blank line.
+pub(crate) fn removal(source: &str, span: Span) -> Span {
+	let trimmed = source[..span.start().0 as usize].trim_end_matches(|c: char| c.is_ascii_whitespace());
+	Span::new(SourceOffset(trimmed.len() as u32), span.end())
+}
+
+// The span without any whitespace at its start or end.
+pub(crate) fn trimmed(source: &str, span: Span) -> Span {
+	let str = text(source, span);
+	let start = span.start().0 + (str.len() - str.trim_start().len()) as u32;
+	Span::new(SourceOffset(start), SourceOffset(start + str.trim().len() as u32))
+}
+
+// The whitespace before a node, which separates copies of it.
+pub(crate) fn leading_whitespace(source: &str, span: Span) -> &str {
+	text(source, Span::new(removal(source, span).start(), span.start()))
+}
+
 #[cfg(test)]
 pub mod test_helpers;
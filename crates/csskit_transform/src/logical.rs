@@ -0,0 +1,267 @@
+use css_ast::{
+	BrowserTarget, StyleRule, StyleSheet, Support, Visit, Visitable, property_support, to_physical_property,
+};
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{Declaration, DeclarationValue};
+
+use crate::{Edit, leading_whitespace, text, trimmed};
+
+// Flow-relative shorthands which take one value for both sides, or the start and end values, along with the physical
+// properties for each side in a horizontal, left-to-right writing mode.
+const LOGICAL_SHORTHANDS: &[(&str, &str, &str)] = &[
+	("margin-block", "margin-top", "margin-bottom"),
+	("margin-inline", "margin-left", "margin-right"),
+	("padding-block", "padding-top", "padding-bottom"),
+	("padding-inline", "padding-left", "padding-right"),
+	("inset-block", "top", "bottom"),
+	("inset-inline", "left", "right"),
+	("scroll-margin-block", "scroll-margin-top", "scroll-margin-bottom"),
+	("scroll-margin-inline", "scroll-margin-left", "scroll-margin-right"),
+	("scroll-padding-block", "scroll-padding-top", "scroll-padding-bottom"),
+	("scroll-padding-inline", "scroll-padding-left", "scroll-padding-right"),
+	("border-block-width", "border-top-width", "border-bottom-width"),
+	("border-inline-width", "border-left-width", "border-right-width"),
+	("border-block-style", "border-top-style", "border-bottom-style"),
+	("border-inline-style", "border-left-style", "border-right-style"),
+	("border-block-color", "border-top-color", "border-bottom-color"),
+	("border-inline-color", "border-left-color", "border-right-color"),
+];
+
+// Flow-relative border shorthands, which set both sides to the same value.
+const LOGICAL_BORDERS: &[(&str, &str, &str)] =
+	&[("border-block", "border-top", "border-bottom"), ("border-inline", "border-left", "border-right")];
+
+/// Returns the [Edits][Edit] which convert each flow-relative property (such as `margin-inline-start` or
+/// `padding-block`) which at least one of the browser targets doesn't support into its physical equivalent
+/// (`margin-left`, or `padding-top` & `padding-bottom`).
+///
+/// This assumes the writing mode is horizontal and left-to-right, which is the initial value, but an element can
+/// inherit another direction (such as from a `dir="rtl"` ancestor), so `csskit build` only runs this when configured
+/// to. Style rules which are known to apply elsewhere, because they set `direction` or `writing-mode` or their
+/// selector mentions `dir`, are left as they are, as are shorthands whose values use `var()`.
+pub fn lower_logical_properties(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	if targets.is_empty() {
+		return vec![];
+	}
+	let mut visitor = LowerLogicalProperties { source, targets, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct LowerLogicalProperties<'s> {
+	source: &'s str,
+	targets: &'s [BrowserTarget],
+	edits: Vec<Edit>,
+}
+
+impl LowerLogicalProperties<'_> {
+	fn unsupported(&self, name: &str) -> bool {
+		let support = property_support(name).unwrap_or(Support::NONE);
+		self.targets.iter().any(|target| !support.supports(target))
+	}
+}
+
+impl Visit for LowerLogicalProperties<'_> {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		let source = self.source;
+		let declarations = &rule.0.block.declarations;
+		let names: Vec<String> =
+			declarations.iter().map(|d| text(source, d.name.to_span()).to_ascii_lowercase()).collect();
+		if text(source, rule.0.prelude.to_span()).to_ascii_lowercase().contains("dir")
+			|| names.iter().any(|name| name == "direction" || name == "writing-mode")
+		{
+			return;
+		}
+		for (declaration, name) in declarations.iter().zip(&names) {
+			if !self.unsupported(name) {
+				continue;
+			}
+			if let Some(physical) = to_physical_property(name) {
+				self.edits.push(Edit::new(declaration.name.to_span(), physical));
+			} else if let Some((_, start, end)) = LOGICAL_SHORTHANDS.iter().find(|(logical, _, _)| logical == name) {
+				let Some(values) = components(source, declaration) else {
+					continue;
+				};
+				let longhands = match values[..] {
+					[value] => [(*start, value), (*end, value)],
+					[start_value, end_value] => [(*start, start_value), (*end, end_value)],
+					_ => continue,
+				};
+				self.edits.push(expand(source, declaration, &longhands));
+			} else if let Some((_, start, end)) = LOGICAL_BORDERS.iter().find(|(logical, _, _)| logical == name) {
+				let (value, _) = value_parts(source, declaration);
+				self.edits.push(expand(source, declaration, &[(start, value), (end, value)]));
+			}
+		}
+	}
+}
+
+/// Returns the [Edits][Edit] which expand each `inset` shorthand into the `top`, `right`, `bottom` & `left`
+/// properties, when at least one of the browser targets doesn't support it. Values which use `var()` are left as they
+/// are. The flow-relative `inset-block` & `inset-inline` shorthands are converted by [lower_logical_properties].
+pub fn expand_inset(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	let support = property_support("inset").unwrap_or(Support::NONE);
+	if targets.iter().all(|target| support.supports(target)) {
+		return vec![];
+	}
+	let mut visitor = ExpandInset { source, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct ExpandInset<'s> {
+	source: &'s str,
+	edits: Vec<Edit>,
+}
+
+impl Visit for ExpandInset<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		if !text(self.source, declaration.name.to_span()).eq_ignore_ascii_case("inset") {
+			return;
+		}
+		let Some(values) = components(self.source, declaration) else {
+			return;
+		};
+		let [top, right, bottom, left] = match values[..] {
+			[all] => [all; 4],
+			[vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+			[top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+			[top, right, bottom, left] => [top, right, bottom, left],
+			_ => return,
+		};
+		self.edits.push(expand(
+			self.source,
+			declaration,
+			&[("top", top), ("right", right), ("bottom", bottom), ("left", left)],
+		));
+	}
+}
+
+// Splits the value of a declaration into its space separated components, or returns `None` if it uses `var()`, as the
+// number of components it has isn't known.
+fn components<'s, 'a, T: DeclarationValue<'a>>(
+	source: &'s str,
+	declaration: &Declaration<'a, T>,
+) -> Option<Vec<&'s str>> {
+	let (value, _) = value_parts(source, declaration);
+	if value.to_ascii_lowercase().contains("var(") {
+		return None;
+	}
+	let mut components = vec![];
+	let (mut depth, mut start) = (0, None);
+	for (i, c) in value.char_indices() {
+		match c {
+			'(' => depth += 1,
+			')' => depth -= 1,
+			c if c.is_ascii_whitespace() && depth == 0 => {
+				if let Some(start) = start.take() {
+					components.push(&value[start..i]);
+				}
+				continue;
+			}
+			_ => {}
+		}
+		start.get_or_insert(i);
+	}
+	components.extend(start.map(|start| &value[start..]));
+	Some(components)
+}
+
+// The value of a declaration and its `!important` (if any), which the span of some values includes.
+fn value_parts<'s, 'a, T: DeclarationValue<'a>>(
+	source: &'s str,
+	declaration: &Declaration<'a, T>,
+) -> (&'s str, &'s str) {
+	let span = declaration.to_span();
+	let end = declaration.semicolon.map_or(span.end(), |semicolon| semicolon.to_span().start());
+	let value = text(source, Span::new(trimmed(source, declaration.value.to_span()).start(), end)).trim_end();
+	match value.rfind('!') {
+		Some(i) if value[i + 1..].trim().eq_ignore_ascii_case("important") => (value[..i].trim_end(), &value[i..]),
+		_ => (value, ""),
+	}
+}
+
+// Replaces a shorthand declaration with a declaration of each longhand, keeping any `!important`.
+fn expand<'a, T: DeclarationValue<'a>>(
+	source: &str,
+	declaration: &Declaration<'a, T>,
+	longhands: &[(&str, &str)],
+) -> Edit {
+	let span = declaration.to_span();
+	let end = declaration.semicolon.map_or(span.end(), |semicolon| semicolon.to_span().start());
+	let (_, important) = value_parts(source, declaration);
+	let important = if important.is_empty() { String::new() } else { format!(" {important}") };
+	let separator = match leading_whitespace(source, span) {
+		"" => " ",
+		whitespace => whitespace,
+	};
+	let declarations: Vec<String> =
+		longhands.iter().map(|(name, value)| format!("{name}: {value}{important}")).collect();
+	// The declaration without its semicolon, or any whitespace before it.
+	let end = SourceOffset(span.start().0 + text(source, Span::new(span.start(), end)).trim_end().len() as u32);
+	Edit::new(Span::new(span.start(), end), declarations.join(&format!(";{separator}")))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn lower(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 80", "firefox 60", "safari 12"].map(|target| target.parse().unwrap());
+		lower_logical_properties(source, stylesheet, &targets)
+	}
+
+	fn lower_new(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 120", "firefox 120", "safari 17"].map(|target| target.parse().unwrap());
+		lower_logical_properties(source, stylesheet, &targets)
+	}
+
+	fn expand(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 80", "firefox 60", "safari 12"].map(|target| target.parse().unwrap());
+		expand_inset(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_lower_logical_properties() {
+		assert_transform!(
+			lower,
+			"a { margin-inline-start: 1px; padding-block: 1px 2px !important; inset-inline: 0; color: red }",
+			"a { margin-left: 1px; padding-top: 1px !important; padding-bottom: 2px !important; left: 0; right: 0; color: red }"
+		);
+		assert_transform!(
+			lower,
+			"a {\n\tmargin-block: calc(1px + 2px);\n}",
+			"a {\n\tmargin-top: calc(1px + 2px);\n\tmargin-bottom: calc(1px + 2px);\n}"
+		);
+	}
+
+	#[test]
+	fn test_unknown_writing_mode() {
+		assert_transform!(
+			lower,
+			"a { direction: rtl; margin-inline-start: 1px }",
+			"a { direction: rtl; margin-inline-start: 1px }"
+		);
+		assert_transform!(
+			lower,
+			":dir(rtl) a { margin-inline-start: 1px }",
+			":dir(rtl) a { margin-inline-start: 1px }"
+		);
+		assert_transform!(lower, "a { margin-inline: var(--m) }", "a { margin-inline: var(--m) }");
+		assert_transform!(lower_new, "a { margin-inline-start: 1px }", "a { margin-inline-start: 1px }");
+	}
+
+	#[test]
+	fn test_expand_inset() {
+		assert_transform!(expand, "a { inset: 0 }", "a { top: 0; right: 0; bottom: 0; left: 0 }");
+		assert_transform!(
+			expand,
+			"a {\n\tinset: 1px auto calc(50% - 1px) !important;\n}",
+			"a {\n\ttop: 1px !important;\n\tright: auto !important;\n\tbottom: calc(50% - 1px) !important;\n\tleft: auto !important;\n}"
+		);
+		assert_transform!(expand, "a { inset: var(--inset) }", "a { inset: var(--inset) }");
+	}
+}
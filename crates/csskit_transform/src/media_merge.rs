@@ -0,0 +1,322 @@
+use css_ast::{MediaCondition, MediaRule, Rule, StyleSheet, Visit, Visitable};
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{ConditionInParens, CursorFmtSink, Declaration, DeclarationValue, ToCursors};
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+	Edit,
+	media_ranges::{Op, Ranged, split_number},
+	text,
+};
+
+/// Returns the [Edits][Edit] which merge each `@media` rule into an earlier one with the same queries, and remove
+/// duplicate queries from each query list.
+///
+/// A rule is only merged when the cascade provably stays the same: it must only contain style rules, and none of the
+/// rules it moves past may declare any of its properties (or a shorthand or longhand of one). Otherwise it is left in
+/// place, and later rules with the same queries may merge into it instead.
+pub fn merge_media_rules(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+	let rules = &stylesheet.rules;
+	let families: Vec<_> = rules.iter().map(|rule| families(source, rule)).collect();
+	let mut edits = vec![];
+	let mut targets: HashMap<String, usize> = HashMap::new();
+	// The rule each rule has been merged into.
+	let mut merged_into = vec![None; rules.len()];
+	for (i, rule) in rules.iter().enumerate() {
+		let Rule::Media(media) = rule else { continue };
+		let key = key(source, media);
+		if let Some(&target) = targets.get(&key) {
+			let Rule::Media(target_media) = &rules[target] else { unreachable!() };
+			if let (Some(close), Some(contents)) = (target_media.0.block.0.close_curly, contents(source, media)) {
+				// Rules which have been merged into the target (or before it) are no longer in between.
+				let safe = movable(media)
+					&& (target + 1..i)
+						.filter(|&r| !matches!(merged_into[r], Some(t) if t <= target))
+						.all(|r| !conflicts(&families[i], &families[r]));
+				if safe {
+					let close = close.to_span().start();
+					edits.push(Edit::new(Span::new(close, close), contents));
+					edits.push(Edit::new(Span::new(rules[i - 1].to_span().end(), rule.to_span().end()), ""));
+					merged_into[i] = Some(target);
+					continue;
+				}
+			}
+		}
+		edits.extend(dedupe_queries(source, media));
+		targets.insert(key, i);
+	}
+	edits
+}
+
+/// Returns the [Edits][Edit] which merge every `@media` rule with the same queries, and move them to the end of the
+/// stylesheet sorted "mobile-first": `min-width` queries by ascending breakpoint, then `max-width` queries by descending
+/// breakpoint, then the rest in their original order.
+///
+/// Unlike [merge_media_rules] this moves rules regardless of the cascade, so it can change which declarations apply.
+pub fn sort_media_rules(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+	struct Group<'s> {
+		key: String,
+		header: &'s str,
+		contents: String,
+		breakpoint: (u8, f64),
+	}
+	let rules = &stylesheet.rules;
+	let mut edits = vec![];
+	let mut groups: Vec<Group> = vec![];
+	let mut separator = None;
+	for (i, rule) in rules.iter().enumerate() {
+		let Rule::Media(media) = rule else { continue };
+		let Some(contents) = contents(source, media) else { continue };
+		let start = if i == 0 { rule.to_span().start() } else { rules[i - 1].to_span().end() };
+		if i > 0 && separator.is_none() {
+			separator = Some(text(source, Span::new(start, rule.to_span().start())));
+		}
+		edits.push(Edit::new(Span::new(start, rule.to_span().end()), ""));
+		let key = key(source, media);
+		if let Some(group) = groups.iter_mut().find(|group| group.key == key) {
+			group.contents.push_str(contents);
+		} else {
+			let header = text(source, Span::new(rule.to_span().start(), media.0.block.0.open_curly.to_span().end()));
+			groups.push(Group { key, header, contents: contents.into(), breakpoint: breakpoint(source, media) });
+		}
+	}
+	if groups.is_empty() {
+		return vec![];
+	}
+	groups.sort_by(|a, b| a.breakpoint.0.cmp(&b.breakpoint.0).then(a.breakpoint.1.total_cmp(&b.breakpoint.1)));
+	let separator = separator.unwrap_or(if source.contains('\n') { "\n" } else { "" });
+	// When every rule moves, there is nothing before the first group to separate it from.
+	let moved_all = edits.len() == rules.len();
+	let sorted: String = groups
+		.iter()
+		.enumerate()
+		.map(|(i, group)| {
+			let separator = if i == 0 && moved_all { "" } else { separator };
+			format!("{separator}{}{}}}", group.header, group.contents)
+		})
+		.collect();
+	let end = rules.last().map_or(SourceOffset(source.len() as u32), |rule| rule.to_span().end());
+	edits.push(Edit::new(Span::new(end, end), sorted));
+	edits
+}
+
+// The minified, lowercased text of a node, so that nodes which only differ in whitespace or case are equal.
+fn minified(source: &str, node: &impl ToCursors) -> String {
+	let mut str = String::new();
+	node.to_cursors(&mut CursorFmtSink::new(source, &mut str));
+	str.to_ascii_lowercase()
+}
+
+// The distinct queries of a rule, which rules must share to be merged.
+fn key(source: &str, media: &MediaRule) -> String {
+	let mut queries: Vec<String> = vec![];
+	for (query, _) in &media.0.prelude.0 {
+		let query = minified(source, query);
+		if !queries.contains(&query) {
+			queries.push(query);
+		}
+	}
+	queries.join(",")
+}
+
+fn dedupe_queries(source: &str, media: &MediaRule) -> Vec<Edit> {
+	let queries: Vec<_> = (&media.0.prelude.0).into_iter().map(|(query, _)| query).collect();
+	let mut seen = HashSet::new();
+	let mut edits = vec![];
+	for (i, query) in queries.iter().enumerate() {
+		if !seen.insert(minified(source, *query)) {
+			// Remove the query along with the comma before it.
+			edits.push(Edit::new(Span::new(queries[i - 1].to_span().end(), query.to_span().end()), ""));
+		}
+	}
+	edits
+}
+
+// The text inside a rule's block.
+fn contents<'s>(source: &'s str, media: &MediaRule) -> Option<&'s str> {
+	let block = &media.0.block.0;
+	Some(text(source, Span::new(block.open_curly.to_span().end(), block.close_curly?.to_span().start())))
+}
+
+// Moving anything other than style rules, such as `@keyframes` or `@font-face`, could change which one applies.
+fn movable(media: &MediaRule) -> bool {
+	let block = &media.0.block.0;
+	block.declarations.is_empty() && block.rules.iter().all(|rule| matches!(rule, Rule::Style(_)))
+}
+
+fn conflicts(a: &HashSet<String>, b: &HashSet<String>) -> bool {
+	a.contains("all") || b.contains("all") || !a.is_disjoint(b)
+}
+
+// The families of the properties declared within a rule.
+fn families(source: &str, rule: &Rule) -> HashSet<String> {
+	let mut visitor = Families { source, families: HashSet::new() };
+	rule.accept(&mut visitor);
+	visitor.families
+}
+
+#[derive(Default)]
+struct Families<'s> {
+	source: &'s str,
+	families: HashSet<String>,
+}
+
+impl Visit for Families<'_> {
+	fn visit_declaration<'a, T: DeclarationValue<'a>>(&mut self, declaration: &Declaration<'a, T>) {
+		self.families.insert(family(text(self.source, declaration.name.to_span())));
+	}
+}
+
+// Groups properties which can override one another, such as `margin` and `margin-left`, or `inset` and `top`, so that
+// properties of different families can be reordered without changing the cascade.
+fn family(name: &str) -> String {
+	let name = name.to_ascii_lowercase();
+	if name.starts_with("--") {
+		return name;
+	}
+	let unprefixed = match name.strip_prefix('-') {
+		Some(rest) => rest.split_once('-').map_or(rest, |(_, rest)| rest),
+		None => &name,
+	};
+	let first = unprefixed.split('-').next().unwrap_or_default();
+	match first {
+		"top" | "right" | "bottom" | "left" => "inset",
+		"width" | "height" | "min" | "max" | "inline" | "block" => "size",
+		"line" => "font",
+		"place" | "align" | "justify" => "align",
+		"gap" | "row" | "column" | "columns" => "grid",
+		"white" => "text",
+		"word" => "overflow",
+		"page" => "break",
+		first => first,
+	}
+	.into()
+}
+
+// Orders `min-width` queries by ascending breakpoint, then `max-width` queries by descending breakpoint, then the rest.
+fn breakpoint(source: &str, media: &MediaRule) -> (u8, f64) {
+	let Some((query, _)) = (&media.0.prelude.0).into_iter().next() else { return (2, 0.0) };
+	let features = match &query.condition {
+		Some(MediaCondition::Is(media)) => vec![media],
+		Some(MediaCondition::And(medias)) => medias.iter().map(|(media, _)| media).collect(),
+		_ => vec![],
+	};
+	let (mut min, mut max) = (None, None);
+	for media in features {
+		let ConditionInParens::Feature(feature) = media else { continue };
+		let Some(ranged) = Ranged::new(source, feature).filter(|r| r.name.eq_ignore_ascii_case("width")) else {
+			continue;
+		};
+		for (op, value) in ranged.bounds {
+			match op {
+				Op::Ge | Op::Gt => min = px(value),
+				Op::Le | Op::Lt => max = px(value),
+				Op::Eq => {}
+			}
+		}
+	}
+	match (min, max) {
+		(Some(min), None) => (0, min),
+		(None, Some(max)) => (1, -max),
+		_ => (2, 0.0),
+	}
+}
+
+fn px(value: &str) -> Option<f64> {
+	let (number, unit) = split_number(value)?;
+	Some(if unit.eq_ignore_ascii_case("em") || unit.eq_ignore_ascii_case("rem") { number * 16.0 } else { number })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	#[test]
+	fn test_merge_media_rules() {
+		assert_transform!(
+			merge_media_rules,
+			"@media (min-width:1px){a{color:red}}@media (min-width:1px){b{color:blue}}",
+			"@media (min-width:1px){a{color:red}b{color:blue}}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media (MIN-WIDTH: 1px){a{color:red}}\n@media (min-width:1px) {b{color:blue}}\n",
+			"@media (MIN-WIDTH: 1px){a{color:red}b{color:blue}}\n"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{color:red}}b{margin:0}@media screen{c{color:blue}}",
+			"@media screen{a{color:red}c{color:blue}}b{margin:0}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{color:red}}b{margin:0}@media screen{a{color:blue}}c{padding:0}@media screen{a{color:green}}",
+			"@media screen{a{color:red}a{color:blue}a{color:green}}b{margin:0}c{padding:0}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen, print, screen{a{color:red}}",
+			"@media screen, print{a{color:red}}"
+		);
+	}
+
+	#[test]
+	fn test_merge_media_rules_keeps_cascade() {
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{color:red}}b{color:blue}@media screen{a{color:green}}",
+			"@media screen{a{color:red}}b{color:blue}@media screen{a{color:green}}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{margin-left:0}}b{margin:1px}@media screen{a{margin-left:2px}}",
+			"@media screen{a{margin-left:0}}b{margin:1px}@media screen{a{margin-left:2px}}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{inset:0}}b{top:1px}@media screen{a{left:2px}}",
+			"@media screen{a{inset:0}}b{top:1px}@media screen{a{left:2px}}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{color:red}}b{all:unset}@media screen{a{margin:0}}",
+			"@media screen{a{color:red}}b{all:unset}@media screen{a{margin:0}}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media print{a{color:red}}b{color:blue}@media print{a{color:green}}c{margin:0}@media print{d{padding:0}}",
+			"@media print{a{color:red}}b{color:blue}@media print{a{color:green}d{padding:0}}c{margin:0}"
+		);
+		assert_transform!(
+			merge_media_rules,
+			"@media screen{a{color:red}}@media print{a{color:blue}}",
+			"@media screen{a{color:red}}@media print{a{color:blue}}"
+		);
+	}
+
+	#[test]
+	fn test_sort_media_rules() {
+		assert_transform!(
+			sort_media_rules,
+			"@media (max-width:600px){a{color:red}}@media (min-width:900px){b{color:red}}a{color:blue}@media (min-width:40em){c{color:red}}@media print{d{color:red}}@media (min-width:900px){e{color:red}}@media (max-width:900px){f{color:red}}",
+			"a{color:blue}@media (min-width:40em){c{color:red}}@media (min-width:900px){b{color:red}e{color:red}}@media (max-width:900px){f{color:red}}@media (max-width:600px){a{color:red}}@media print{d{color:red}}"
+		);
+		assert_transform!(
+			sort_media_rules,
+			"a{color:blue}\n@media (width >= 900px){b{color:red}}\n@media (width >= 600px){c{color:red}}\n",
+			"a{color:blue}\n@media (width >= 600px){c{color:red}}\n@media (width >= 900px){b{color:red}}\n"
+		);
+		assert_transform!(
+			sort_media_rules,
+			"@media (min-width:2px){a{color:red}}\n@media (min-width:1px){b{color:red}}",
+			"@media (min-width:1px){b{color:red}}\n@media (min-width:2px){a{color:red}}"
+		);
+		assert_transform!(
+			sort_media_rules,
+			"@media (min-width:2px){a{color:red}} @media (min-width:1px){b{color:red}}",
+			"@media (min-width:1px){b{color:red}} @media (min-width:2px){a{color:red}}"
+		);
+		assert_transform!(sort_media_rules, "a{color:blue}", "a{color:blue}");
+	}
+}
@@ -0,0 +1,405 @@
+use css_ast::{
+	AspectRatioMediaFeature, BrowserTarget, ColorIndexMediaFeature, ColorMediaFeature, DeviceAspectRatioMediaFeature,
+	DeviceHeightMediaFeature, DeviceWidthMediaFeature, HeightMediaFeature, HorizontalViewportSegmentsMediaFeature,
+	MEDIA_RANGE_SYNTAX, MediaCondition, MediaFeature, MediaInParens, MediaRule, MonochromeMediaFeature,
+	ResolutionMediaFeature, StyleSheet, VerticalViewportSegmentsMediaFeature, Visit, Visitable, WidthMediaFeature,
+};
+use css_lexer::{Kind, Lexer, Span, ToSpan};
+use css_parse::{Comparison, ConditionInParens};
+
+use crate::{Edit, text};
+
+/// Returns the [Edits][Edit] which rewrite legacy `min-`/`max-` media features into range syntax, for example
+/// `(min-width: 40em)` into `(width >= 40em)`. A `min-` and `max-` feature of the same name within the same `and`
+/// condition are merged, so `(min-width: 40em) and (max-width: 60em)` becomes `(40em <= width <= 60em)`.
+pub fn legacy_to_range(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+	let mut visitor = MediaRanges { source, to_range: true, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+/// Returns the [Edits][Edit] which rewrite media features in range syntax into the legacy `min-`/`max-` syntax, for
+/// browsers which don't support ranges. For example `(40em <= width < 60em)` becomes
+/// `(min-width: 40em) and (max-width: 59.999em)`. Exclusive comparisons of ratios can't be expressed in the legacy
+/// syntax, so they are left as they are.
+pub fn range_to_legacy(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+	let mut visitor = MediaRanges { source, to_range: false, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+/// Returns the [Edits][Edit] of [range_to_legacy] when at least one of the browser targets doesn't support the range
+/// syntax for media features.
+pub fn lower_media_ranges(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	if targets.iter().all(|target| MEDIA_RANGE_SYNTAX.supports(target)) {
+		return vec![];
+	}
+	range_to_legacy(source, stylesheet)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Op {
+	Lt,
+	Le,
+	Eq,
+	Ge,
+	Gt,
+}
+
+impl Op {
+	// Turns `value op feature` into `feature op value`.
+	fn flip(self) -> Self {
+		match self {
+			Self::Lt => Self::Gt,
+			Self::Le => Self::Ge,
+			Self::Eq => Self::Eq,
+			Self::Ge => Self::Le,
+			Self::Gt => Self::Lt,
+		}
+	}
+
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::Lt => "<",
+			Self::Le => "<=",
+			Self::Eq => "=",
+			Self::Ge => ">=",
+			Self::Gt => ">",
+		}
+	}
+}
+
+impl From<Comparison> for Op {
+	fn from(comparison: Comparison) -> Self {
+		match comparison {
+			Comparison::LessThan(_) => Self::Lt,
+			Comparison::GreaterThan(_) => Self::Gt,
+			Comparison::GreaterThanEqual(_) => Self::Ge,
+			Comparison::LessThanEqual(_) => Self::Le,
+			Comparison::Equal(_) => Self::Eq,
+		}
+	}
+}
+
+/// A ranged media feature, in any of its syntaxes.
+pub(crate) struct Ranged<'s> {
+	pub(crate) span: Span,
+	/// The name of the feature, without any `min-`/`max-` prefix.
+	pub(crate) name: &'s str,
+	pub(crate) legacy: bool,
+	/// How the feature compares to each value, so `(400px < width <= 800px)` is `[(Gt, "400px"), (Le, "800px")]`.
+	pub(crate) bounds: Vec<(Op, &'s str)>,
+	/// The smallest step between two values of the feature, used to express `<` and `>` in the legacy syntax.
+	step: Option<f64>,
+}
+
+impl<'s> Ranged<'s> {
+	pub(crate) fn new(source: &'s str, feature: &MediaFeature) -> Option<Self> {
+		macro_rules! parts {
+			($feature: ident, $f: ident) => {
+				match $f {
+					$feature::Left(_, name, comparison, value, _) => {
+						(name.to_span(), false, vec![(Op::from(*comparison), value.to_span())])
+					}
+					$feature::Right(_, value, comparison, name, _) => {
+						(name.to_span(), false, vec![(Op::from(*comparison).flip(), value.to_span())])
+					}
+					$feature::Range(_, left, left_comparison, name, right_comparison, right, _) => (
+						name.to_span(),
+						false,
+						vec![
+							(Op::from(*left_comparison).flip(), left.to_span()),
+							(Op::from(*right_comparison), right.to_span()),
+						],
+					),
+					$feature::Legacy(_, name, _, value, _) => (name.to_span(), true, vec![(Op::Eq, value.to_span())]),
+				}
+			};
+		}
+		let ((name, legacy, bounds), step) = match feature {
+			MediaFeature::AspectRatio(f) => (parts!(AspectRatioMediaFeature, f), None),
+			MediaFeature::Color(f) => (parts!(ColorMediaFeature, f), Some(1.0)),
+			MediaFeature::ColorIndex(f) => (parts!(ColorIndexMediaFeature, f), Some(1.0)),
+			MediaFeature::DeviceAspectRatio(f) => (parts!(DeviceAspectRatioMediaFeature, f), None),
+			MediaFeature::DeviceHeight(f) => (parts!(DeviceHeightMediaFeature, f), Some(0.001)),
+			MediaFeature::DeviceWidth(f) => (parts!(DeviceWidthMediaFeature, f), Some(0.001)),
+			MediaFeature::Height(f) => (parts!(HeightMediaFeature, f), Some(0.001)),
+			MediaFeature::HorizontalViewportSegments(f) => {
+				(parts!(HorizontalViewportSegmentsMediaFeature, f), Some(1.0))
+			}
+			MediaFeature::Monochrome(f) => (parts!(MonochromeMediaFeature, f), Some(1.0)),
+			MediaFeature::Resolution(f) => (parts!(ResolutionMediaFeature, f), Some(0.001)),
+			MediaFeature::VerticalViewportSegments(f) => (parts!(VerticalViewportSegmentsMediaFeature, f), Some(1.0)),
+			MediaFeature::Width(f) => (parts!(WidthMediaFeature, f), Some(0.001)),
+			_ => return None,
+		};
+		let mut name = text(source, name);
+		let mut bounds: Vec<_> = bounds.into_iter().map(|(op, value)| (op, text(source, value))).collect();
+		if legacy {
+			let prefix = name.get(..4).unwrap_or_default();
+			let op = if prefix.eq_ignore_ascii_case("min-") {
+				Op::Ge
+			} else if prefix.eq_ignore_ascii_case("max-") {
+				Op::Le
+			} else {
+				Op::Eq
+			};
+			if op != Op::Eq {
+				name = &name[4..];
+				bounds[0].0 = op;
+			}
+		}
+		Some(Self { span: feature.to_span(), name, legacy, bounds, step })
+	}
+
+	fn to_range(&self) -> String {
+		let (op, value) = self.bounds[0];
+		format!("({} {} {value})", self.name, op.as_str())
+	}
+
+	// Each bound as a legacy feature, joined with `and`. Returns `None` if a bound can't be expressed.
+	fn to_legacy(&self) -> Option<String> {
+		let mut features = vec![];
+		for &(op, value) in &self.bounds {
+			let name = self.name;
+			features.push(match op {
+				Op::Eq => format!("({name}: {value})"),
+				Op::Ge => format!("(min-{name}: {value})"),
+				Op::Le => format!("(max-{name}: {value})"),
+				Op::Gt => format!("(min-{name}: {})", step(value, self.step?)?),
+				Op::Lt => format!("(max-{name}: {})", step(value, -self.step?)?),
+			});
+		}
+		Some(features.join(" and "))
+	}
+}
+
+// Splits a value into the number at its start and its unit, so `40em` is `(40.0, "em")`.
+pub(crate) fn split_number(value: &str) -> Option<(f64, &str)> {
+	let token = Lexer::new(value).advance();
+	if !matches!(token.kind(), Kind::Number | Kind::Dimension) {
+		return None;
+	}
+	let len = token.numeric_len() as usize;
+	Some((value[..len].parse::<f64>().ok()?, &value[len..]))
+}
+
+// The number of decimal places a number is written with, taking its exponent into account, so `1.5e-3` has 4.
+fn decimal_places(number: &str) -> i32 {
+	let number = number.to_ascii_lowercase();
+	let (mantissa, exponent) = number.split_once('e').unwrap_or((&number, "0"));
+	let fraction = mantissa.split_once('.').map_or(0, |(_, fraction)| fraction.len() as i32);
+	fraction - exponent.trim_start_matches('+').parse::<i32>().unwrap_or(0)
+}
+
+// Adds `by` to the number at the start of a value, keeping its unit, so `40em` stepped by `0.001` is `40.001em`. A
+// fractional `by` is made smaller when the value is written more precisely, so `0.0001px` steps to `0.0002px`.
+fn step(value: &str, by: f64) -> Option<String> {
+	let (number, unit) = split_number(value)?;
+	let places = if by.abs() < 1.0 {
+		decimal_places(&value[..value.len() - unit.len()]).max(-by.abs().log10().round() as i32)
+	} else {
+		0
+	};
+	let number = format!("{:.*}", places as usize, number + by.signum() * 10f64.powi(-places));
+	let number = if number.contains('.') { number.trim_end_matches('0').trim_end_matches('.') } else { &number };
+	Some(format!("{number}{unit}"))
+}
+
+#[derive(Default)]
+struct MediaRanges<'s> {
+	source: &'s str,
+	to_range: bool,
+	edits: Vec<Edit>,
+}
+
+impl Visit for MediaRanges<'_> {
+	fn visit_media_rule<'a>(&mut self, rule: &MediaRule<'a>) {
+		for (query, _) in &rule.0.prelude.0 {
+			if let Some(condition) = &query.condition {
+				self.condition(condition, true);
+			}
+		}
+	}
+}
+
+impl MediaRanges<'_> {
+	// `and_ok` is whether features can be joined with `and` in place, rather than needing to be wrapped in parens.
+	fn condition(&mut self, condition: &MediaCondition, and_ok: bool) {
+		match condition {
+			MediaCondition::Is(media) => self.in_parens(media, and_ok),
+			MediaCondition::Not(_, media) => self.in_parens(media, false),
+			MediaCondition::And(medias) if self.to_range => self.merge(medias),
+			MediaCondition::And(medias) => medias.iter().for_each(|(media, _)| self.in_parens(media, true)),
+			MediaCondition::Or(medias) => medias.iter().for_each(|(media, _)| self.in_parens(media, false)),
+		}
+	}
+
+	fn in_parens(&mut self, media: &MediaInParens, and_ok: bool) {
+		match media {
+			ConditionInParens::Feature(feature) => self.feature(feature, and_ok),
+			ConditionInParens::Condition(_, condition, _) => self.condition(condition, true),
+			ConditionInParens::GeneralEnclosed(_) => {}
+		}
+	}
+
+	fn feature(&mut self, feature: &MediaFeature, and_ok: bool) {
+		let Some(ranged) = Ranged::new(self.source, feature) else { return };
+		if self.to_range {
+			if ranged.legacy && ranged.bounds[0].0 != Op::Eq {
+				self.edits.push(Edit::new(ranged.span, ranged.to_range()));
+			}
+		} else if !ranged.legacy {
+			if let Some(legacy) = ranged.to_legacy() {
+				let legacy = if ranged.bounds.len() > 1 && !and_ok { format!("({legacy})") } else { legacy };
+				self.edits.push(Edit::new(ranged.span, legacy));
+			}
+		}
+	}
+
+	// Converts the features of an `and` condition to range syntax, merging `min-` and `max-` pairs.
+	fn merge(&mut self, medias: &[(MediaInParens, Option<css_parse::ConditionKeyword>)]) {
+		let mut features = vec![];
+		for (i, (media, _)) in medias.iter().enumerate() {
+			match media {
+				ConditionInParens::Feature(feature) => {
+					if let Some(ranged) =
+						Ranged::new(self.source, feature).filter(|r| r.legacy && r.bounds[0].0 != Op::Eq)
+					{
+						features.push((i, ranged, false));
+					}
+				}
+				_ => self.in_parens(media, true),
+			}
+		}
+		for a in 0..features.len() {
+			if features[a].2 {
+				continue;
+			}
+			let pair = (a + 1..features.len()).find(|&b| {
+				!features[b].2
+					&& features[b].1.name.eq_ignore_ascii_case(features[a].1.name)
+					&& features[b].1.bounds[0].0 != features[a].1.bounds[0].0
+			});
+			let Some(b) = pair else {
+				self.edits.push(Edit::new(features[a].1.span, features[a].1.to_range()));
+				continue;
+			};
+			features[a].2 = true;
+			features[b].2 = true;
+			let (first, second) = (&features[a], &features[b]);
+			let (min, max) = if first.1.bounds[0].0 == Op::Ge { (first, second) } else { (second, first) };
+			let merged = format!("({} <= {} <= {})", min.1.bounds[0].1, first.1.name, max.1.bounds[0].1);
+			self.edits.push(Edit::new(first.1.span, merged));
+			// Remove the second feature along with the `and` before it. The second feature is never the first in the
+			// condition, and these removals don't overlap when pairs interleave.
+			let i = second.0;
+			let removal = Span::new(medias[i - 1].0.to_span().end(), medias[i].0.to_span().end());
+			self.edits.push(Edit::new(removal, ""));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn lower(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 100", "firefox 115", "safari 15.4"].map(|target| target.parse().unwrap());
+		lower_media_ranges(source, stylesheet, &targets)
+	}
+
+	fn lower_new(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 120", "firefox 120", "safari 17"].map(|target| target.parse().unwrap());
+		lower_media_ranges(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_legacy_to_range() {
+		assert_transform!(
+			legacy_to_range,
+			"@media (min-width: 40em){a{color:red}}",
+			"@media (width >= 40em){a{color:red}}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media screen and (min-width: 40em) and (max-width: 60em){}",
+			"@media screen and (40em <= width <= 60em){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media (max-width: 60em) and (hover) and (min-width: 40em){}",
+			"@media (40em <= width <= 60em) and (hover){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media (min-resolution: 2dppx), (max-aspect-ratio: 16/9), (min-color: 8){}",
+			"@media (resolution >= 2dppx), (aspect-ratio <= 16/9), (color >= 8){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media not all and (max-height: 600px){}",
+			"@media not all and (height <= 600px){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media (min-width: 1px) or (max-width: 2px){}",
+			"@media (width >= 1px) or (width <= 2px){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media ((min-width: 1px) and (max-width: 2px)) or (hover){}",
+			"@media ((1px <= width <= 2px)) or (hover){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media (min-width: 1px) and (min-height: 2px) and (max-width: 3px){}",
+			"@media (1px <= width <= 3px) and (height >= 2px){}"
+		);
+		assert_transform!(
+			legacy_to_range,
+			"@media (min-width:1px) and (min-height:2px) and (max-height:3px) and (max-width:4px){}",
+			"@media (1px <= width <= 4px) and (2px <= height <= 3px){}"
+		);
+		assert_transform!(legacy_to_range, "@media (width: 40em) and (hover){}", "@media (width: 40em) and (hover){}");
+		assert_transform!(legacy_to_range, "@media (width >= 40em){}", "@media (width >= 40em){}");
+	}
+
+	#[test]
+	fn test_range_to_legacy() {
+		assert_transform!(
+			range_to_legacy,
+			"@media (width >= 40em){a{color:red}}",
+			"@media (min-width: 40em){a{color:red}}"
+		);
+		assert_transform!(range_to_legacy, "@media (width > 40em){}", "@media (min-width: 40.001em){}");
+		assert_transform!(range_to_legacy, "@media (800px > width){}", "@media (max-width: 799.999px){}");
+		assert_transform!(
+			range_to_legacy,
+			"@media screen and (400px <= width <= 800px){}",
+			"@media screen and (min-width: 400px) and (max-width: 800px){}"
+		);
+		assert_transform!(range_to_legacy, "@media (width = 40em){}", "@media (width: 40em){}");
+		assert_transform!(range_to_legacy, "@media (color > 8){}", "@media (min-color: 9){}");
+		assert_transform!(range_to_legacy, "@media (color < 11){}", "@media (max-color: 10){}");
+		assert_transform!(range_to_legacy, "@media (width > 1e3px){}", "@media (min-width: 1000.001px){}");
+		assert_transform!(range_to_legacy, "@media (width > 0.0001px){}", "@media (min-width: 0.0002px){}");
+		assert_transform!(range_to_legacy, "@media (width < 1.5e-3em){}", "@media (max-width: 0.0014em){}");
+		assert_transform!(range_to_legacy, "@media (resolution >= 2x){}", "@media (min-resolution: 2x){}");
+		assert_transform!(range_to_legacy, "@media (aspect-ratio >= 16/9){}", "@media (min-aspect-ratio: 16/9){}");
+		assert_transform!(range_to_legacy, "@media (aspect-ratio > 16/9){}", "@media (aspect-ratio > 16/9){}");
+		assert_transform!(
+			range_to_legacy,
+			"@media (hover) or (400px <= width <= 800px){}",
+			"@media (hover) or ((min-width: 400px) and (max-width: 800px)){}"
+		);
+		assert_transform!(range_to_legacy, "@media (min-width: 40em){}", "@media (min-width: 40em){}");
+	}
+
+	#[test]
+	fn test_lower_media_ranges() {
+		assert_transform!(lower, "@media(width>1px){a{color:red}}", "@media(min-width: 1.001px){a{color:red}}");
+		assert_transform!(lower_new, "@media(width>1px){a{color:red}}", "@media(width>1px){a{color:red}}");
+	}
+}
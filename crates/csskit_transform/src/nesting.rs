@@ -0,0 +1,295 @@
+use bumpalo::Bump;
+use css_ast::{
+	BrowserTarget, CompoundSelector, MediaRule, NestedGroupRule, Rule, Specificity, StyleRule, StyleSheet, StyleValue,
+	Support, ToSpecificity, Visit, Visitable, selector_support,
+};
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{Block, Parse, Parser, ToCursors};
+
+use crate::{Edit, apply_edits, leading_whitespace, removal, text, trimmed};
+
+/// Returns the [Edits][Edit] which flatten nested style rules into top level rules, when at least one of the browser
+/// targets doesn't support [CSS Nesting](https://drafts.csswg.org/css-nesting/).
+///
+/// Each nesting selector (`&`) is replaced with the selectors of the parent rule, and nested selectors without one are
+/// made descendants of it. A parent rule with several selectors of the same specificity is expanded into every
+/// combination. Otherwise the parent selectors are wrapped in `:is()`, which (like `&`) takes the specificity of its
+/// most specific selector, and rules are left as they are if a browser target doesn't support `:is()`. `@media` rules
+/// nested in a style rule are moved outside of it. Rules containing anything else, such as a nested `@supports` rule,
+/// are left as they are.
+pub fn flatten_nesting(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	let support = selector_support("&").unwrap_or(Support::NONE);
+	if targets.iter().all(|target| support.supports(target)) {
+		return vec![];
+	}
+	let is = selector_support(":is").unwrap_or(Support::NONE);
+	let supports_is = targets.iter().all(|target| is.supports(target));
+	let mut visitor = FlattenNesting { source, supports_is, ..Default::default() };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct FlattenNesting<'s> {
+	source: &'s str,
+	// Every browser target supports `:is()`, so parent selectors can be grouped with it.
+	supports_is: bool,
+	edits: Vec<Edit>,
+	// Rules which contain nested rules, whether or not they could be flattened.
+	parents: Vec<Span>,
+}
+
+// A rule nested within a style rule which can be flattened.
+enum Nested<'r, 'a> {
+	Style(&'r StyleRule<'a>),
+	Media(&'r MediaRule<'a>),
+}
+
+trait NestedRule<'a> {
+	fn nested(&self) -> Option<Nested<'_, 'a>>;
+}
+
+impl<'a> NestedRule<'a> for NestedGroupRule<'a> {
+	fn nested(&self) -> Option<Nested<'_, 'a>> {
+		match self {
+			Self::Style(rule) => Some(Nested::Style(rule)),
+			Self::Media(rule) => Some(Nested::Media(rule)),
+			_ => None,
+		}
+	}
+}
+
+impl<'a> NestedRule<'a> for Rule<'a> {
+	fn nested(&self) -> Option<Nested<'_, 'a>> {
+		match self {
+			Self::Style(rule) => Some(Nested::Style(rule)),
+			Self::Media(rule) => Some(Nested::Media(rule)),
+			_ => None,
+		}
+	}
+}
+
+impl FlattenNesting<'_> {
+	// Pushes the flattened rules of a block onto `rules`, given the selectors it applies to and the `@media` rules it is
+	// within. Returns `None` if anything within the block can't be flattened.
+	fn flatten_block<'a, R: Parse<'a> + ToCursors + ToSpan + NestedRule<'a>>(
+		&self,
+		selectors: &[String],
+		media: &[&str],
+		block: &Block<'a, StyleValue<'a>, R>,
+		rules: &mut Vec<String>,
+	) -> Option<()> {
+		if !block.declarations.is_empty() {
+			// The block itself, with its nested rules removed.
+			let span = block.to_span();
+			let start = span.start().0;
+			let removals: Vec<Edit> = block
+				.rules
+				.iter()
+				.map(|rule| {
+					let removal = removal(self.source, rule.to_span());
+					Edit::new(
+						Span::new(SourceOffset(removal.start().0 - start), SourceOffset(removal.end().0 - start)),
+						"",
+					)
+				})
+				.collect();
+			let declarations = apply_edits(text(self.source, span), &removals);
+			let rule = format!("{} {declarations}", selectors.join(", "));
+			rules.push(media.iter().rev().fold(rule, |rule, query| format!("@media {query} {{ {rule} }}")));
+		}
+		for rule in &block.rules {
+			match rule.nested()? {
+				Nested::Style(rule) => {
+					let grouped;
+					let parents = if equal_specificity(selectors) {
+						selectors
+					} else if self.supports_is {
+						grouped = [format!(":is({})", selectors.join(", "))];
+						&grouped[..]
+					} else {
+						return None;
+					};
+					let resolved: Vec<String> = (&rule.0.prelude.0)
+						.into_iter()
+						.flat_map(|(selector, _)| {
+							resolve(parents, text(self.source, trimmed(self.source, selector.to_span())))
+						})
+						.collect();
+					self.flatten_block(&resolved, media, &rule.0.block, rules)?;
+				}
+				Nested::Media(rule) => {
+					let query = text(self.source, trimmed(self.source, rule.0.prelude.to_span()));
+					let media = [media, &[query]].concat();
+					self.flatten_block(selectors, &media, &rule.0.block.0, rules)?;
+				}
+			}
+		}
+		Some(())
+	}
+}
+
+// Returns true if every selector has the same specificity, so that expanding a nesting selector into each of them
+// keeps the specificity it would have had.
+fn equal_specificity(selectors: &[String]) -> bool {
+	let specificity = |selector: &str| -> Option<Specificity> {
+		let bump = Bump::default();
+		Parser::new(&bump, selector).parse_entirely::<CompoundSelector>().output.map(|selector| selector.specificity())
+	};
+	let Some((first, rest)) = selectors.split_first() else {
+		return true;
+	};
+	let first = specificity(first);
+	first.is_some() && rest.iter().all(|selector| specificity(selector) == first)
+}
+
+// Returns true if a selector has a combinator outside of any parentheses, such as `.a .b` (but not `:is(.a .b)`).
+fn is_complex(selector: &str) -> bool {
+	let mut depth = 0;
+	selector.chars().any(|c| {
+		match c {
+			'(' => depth += 1,
+			')' => depth -= 1,
+			_ => {}
+		}
+		depth == 0 && (c.is_ascii_whitespace() || matches!(c, '>' | '+' | '~'))
+	})
+}
+
+// Replaces each nesting selector in a nested selector with each of the parent selectors, or makes the nested selector
+// a descendant of them if it has none.
+fn resolve(parents: &[String], selector: &str) -> Vec<String> {
+	let mut quote = None;
+	let nesting: Vec<usize> = selector
+		.char_indices()
+		.filter(|(_, c)| match (quote, c) {
+			(Some(q), c) if q == *c => {
+				quote = None;
+				false
+			}
+			(None, '"' | '\'') => {
+				quote = Some(*c);
+				false
+			}
+			(None, '&') => true,
+			_ => false,
+		})
+		.map(|(i, _)| i)
+		.collect();
+	if nesting.is_empty() {
+		return parents.iter().map(|parent| format!("{parent} {selector}")).collect();
+	}
+	let mut resolved = vec![String::new()];
+	let mut last = 0;
+	for i in nesting {
+		let before = &selector[last..i];
+		// A complex parent selector (such as `.a .b`) compounded with other selectors (as in `.c&`) must stay grouped.
+		let compounded =
+			selector[..i].ends_with(|c: char| !c.is_ascii_whitespace() && !matches!(c, '>' | '+' | '~' | '(' | ','));
+		resolved = resolved
+			.iter()
+			.flat_map(|prefix| {
+				parents.iter().map(move |parent| {
+					if compounded && is_complex(parent) {
+						format!("{prefix}{before}:is({parent})")
+					} else {
+						format!("{prefix}{before}{parent}")
+					}
+				})
+			})
+			.collect();
+		last = i + 1;
+	}
+	resolved.into_iter().map(|prefix| format!("{prefix}{}", &selector[last..])).collect()
+}
+
+impl Visit for FlattenNesting<'_> {
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		let span = rule.to_span();
+		if rule.0.block.rules.is_empty() || self.parents.iter().any(|parent| parent.contains(span)) {
+			return;
+		}
+		self.parents.push(span);
+		let selectors: Vec<String> = (&rule.0.prelude.0)
+			.into_iter()
+			.map(|(selector, _)| text(self.source, trimmed(self.source, selector.to_span())).to_owned())
+			.collect();
+		let mut rules = vec![];
+		if self.flatten_block(&selectors, &[], &rule.0.block, &mut rules).is_none() {
+			return;
+		}
+		let whitespace = match leading_whitespace(self.source, span) {
+			"" => "\n",
+			whitespace => whitespace,
+		};
+		self.edits.push(Edit::new(span, rules.join(whitespace)));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn flatten(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 100", "firefox 115", "safari 15.4"].map(|target| target.parse().unwrap());
+		flatten_nesting(source, stylesheet, &targets)
+	}
+
+	fn flatten_old(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 80", "firefox 60", "safari 12"].map(|target| target.parse().unwrap());
+		flatten_nesting(source, stylesheet, &targets)
+	}
+
+	fn flatten_new(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 120", "firefox 120", "safari 17"].map(|target| target.parse().unwrap());
+		flatten_nesting(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_flatten_nesting() {
+		assert_transform!(
+			flatten,
+			".a {\n\tcolor: red;\n\t.b {\n\t\tcolor: blue;\n\t}\n\t&:hover { color: green }\n}",
+			".a {\n\tcolor: red;\n}\n.a .b {\n\t\tcolor: blue;\n\t}\n.a:hover { color: green }"
+		);
+		assert_transform!(
+			flatten,
+			"a{} .a, .b { > .c { d & { color: red } } }",
+			"a{} d .a > .c, d .b > .c { color: red }"
+		);
+		assert_transform!(flatten, ".a .b { .c& { color: red } }", ".c:is(.a .b) { color: red }");
+		assert_transform!(flatten, ".a { color: red }", ".a { color: red }");
+	}
+
+	#[test]
+	fn test_flatten_nested_media() {
+		assert_transform!(
+			flatten,
+			".a { @media (width > 1px) { color: red; .b { color: blue } } }",
+			"@media (width > 1px) { .a { color: red; } }\n@media (width > 1px) { .a .b { color: blue } }"
+		);
+	}
+
+	#[test]
+	fn test_unflattened() {
+		assert_transform!(
+			flatten,
+			".a { @supports (display: grid) { .b {} } }",
+			".a { @supports (display: grid) { .b {} } }"
+		);
+		assert_transform!(flatten_new, ".a { .b { color: red } }", ".a { .b { color: red } }");
+		assert_transform!(flatten_old, "#a, .b { .c { color: red } }", "#a, .b { .c { color: red } }");
+	}
+
+	#[test]
+	fn test_flatten_mixed_specificity() {
+		assert_transform!(
+			flatten,
+			"#a, .b { color: red; & .c { color: blue } }",
+			"#a, .b { color: red; }\n:is(#a, .b) .c { color: blue }"
+		);
+		assert_transform!(flatten, "#a, .b { .c& { color: red } }", ".c:is(#a, .b) { color: red }");
+		assert_transform!(flatten_old, ".a, .b { .c { color: red } }", ".a .c, .b .c { color: red }");
+	}
+}
@@ -0,0 +1,326 @@
+use css_ast::{
+	BrowserTarget, KeyframesRule, Prefixed, SelectorComponent, StyleRule, StyleSheet, Support, Visit, Visitable,
+	WebkitKeyframesRule, at_rule_support, property_prefixes, property_support, selector_prefixes, selector_support,
+	unprefixed_at_rule, unprefixed_property, unprefixed_selector, unprefixed_value, value_prefixes, value_support,
+};
+use css_lexer::{SourceOffset, Span, ToSpan};
+use css_parse::{Block, DeclarationValue, Parse, ToCursors};
+
+use crate::{Edit, apply_edits, leading_whitespace, removal, text, trimmed};
+
+/// Returns the [Edits][Edit] which add the vendor prefixed properties, values, selectors and `@keyframes` rules which
+/// at least one of the browser targets still needs, and which remove those which none of them need.
+///
+/// Prefixed declarations are added before the standard declaration, and prefixed style rules and `@-webkit-keyframes`
+/// rules before the standard rule. A prefixed declaration or `@-webkit-keyframes` rule which isn't needed is removed if
+/// the standard one is also present, or renamed to the standard one if not. Likewise a style rule where every selector
+/// uses a prefixed pseudo which isn't needed is removed if a rule with the standard selectors is also present, or has
+/// its pseudos renamed to the standard ones if not. Nothing is changed when there are no targets.
+pub fn autoprefix(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	if targets.is_empty() {
+		return vec![];
+	}
+	let mut visitor = Autoprefix { source, targets, ..Default::default() };
+	stylesheet.accept(&mut visitor);
+	let Autoprefix { mut edits, mut copies, keyframes, webkit_keyframes, selectors, unneeded_rules, .. } = visitor;
+	// Likewise rules with unneeded prefixed pseudos are matched by their selectors.
+	for (span, standard, renames) in unneeded_rules {
+		if selectors.contains(&standard) {
+			edits.push(Edit::new(removal(source, span), ""));
+		} else {
+			edits.extend(renames);
+		}
+	}
+	// Keyframes are matched by name, wherever they are in the stylesheet.
+	if let Some((keyword, prefixed)) = unprefixed_at_rule("@-webkit-keyframes") {
+		let standard = at_rule_support(keyword).unwrap_or(Support::NONE);
+		let needed = targets.iter().any(|target| prefixed.needed_by(standard, target));
+		for (span, name) in &keyframes {
+			if needed && !webkit_keyframes.iter().any(|(_, webkit)| webkit == name) {
+				copies.push((*span, prefix(*span, keyword), prefixed.name.into()));
+			}
+		}
+		for (span, name) in &webkit_keyframes {
+			if needed {
+				continue;
+			}
+			if keyframes.iter().any(|(_, standard)| standard == name) {
+				edits.push(Edit::new(removal(source, *span), ""));
+			} else {
+				edits.push(Edit::new(prefix(*span, prefixed.name), keyword));
+			}
+		}
+	}
+	// Copies are made after visiting, so that they include any prefixes added within the rule.
+	let copies: Vec<Edit> = copies
+		.into_iter()
+		.map(|(span, replace, with)| {
+			let relative = |edit_span: Span| {
+				let start = span.start().0;
+				Span::new(SourceOffset(edit_span.start().0 - start), SourceOffset(edit_span.end().0 - start))
+			};
+			let inner: Vec<Edit> = edits
+				.iter()
+				.filter(|edit| span.contains(edit.span) && !replace.contains(edit.span))
+				.map(|edit| Edit::new(relative(edit.span), edit.text.clone()))
+				.chain([Edit::new(relative(replace), with)])
+				.collect();
+			let copy = apply_edits(text(source, span), &inner);
+			let whitespace = leading_whitespace(source, span);
+			Edit::new(Span::new(span.start(), span.start()), format!("{copy}{whitespace}"))
+		})
+		.collect();
+	edits.extend(copies);
+	edits
+}
+
+#[derive(Default)]
+struct Autoprefix<'s> {
+	source: &'s str,
+	targets: &'s [BrowserTarget],
+	edits: Vec<Edit>,
+	// Rules to insert a copy of before themselves: the span of the rule, and a span within it to replace.
+	copies: Vec<(Span, Span, String)>,
+	keyframes: Vec<(Span, String)>,
+	webkit_keyframes: Vec<(Span, String)>,
+	// The selectors of each style rule, with their whitespace collapsed.
+	selectors: Vec<String>,
+	// Rules where every selector uses a prefixed pseudo which isn't needed: the span of the rule, its selectors with
+	// the standard pseudos, and the edits which rename each prefixed pseudo to the standard one.
+	unneeded_rules: Vec<(Span, String, Vec<Edit>)>,
+}
+
+impl Autoprefix<'_> {
+	fn needed(&self, standard: Support, prefixed: &Prefixed) -> bool {
+		self.targets.iter().any(|target| prefixed.needed_by(standard, target))
+	}
+}
+
+// The span of a keyword at the start of a node, such as the name of an at-rule.
+fn prefix(span: Span, keyword: &str) -> Span {
+	Span::new(span.start(), SourceOffset(span.start().0 + keyword.len() as u32))
+}
+
+impl Visit for Autoprefix<'_> {
+	fn visit_block<'a, D: DeclarationValue<'a>, R: Parse<'a> + ToCursors + ToSpan>(&mut self, block: &Block<'a, D, R>) {
+		let source = self.source;
+		let declarations: Vec<(String, String)> = block
+			.declarations
+			.iter()
+			.map(|declaration| {
+				let value = text(source, declaration.value.to_span()).to_ascii_lowercase();
+				let value = value.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+				(text(source, declaration.name.to_span()).to_ascii_lowercase(), value)
+			})
+			.collect();
+		let has_property = |name: &str| declarations.iter().any(|(property, _)| property == name);
+		let has_value =
+			|name: &str, value: &str| declarations.iter().any(|(property, v)| property == name && v == value);
+		for (declaration, (name, value)) in block.declarations.iter().zip(&declarations) {
+			let span = declaration.to_span();
+			let name_span = declaration.name.to_span();
+			let value_span = trimmed(source, declaration.value.to_span());
+			// The declaration without its semicolon, which is copied for each prefix.
+			let end = declaration.semicolon.map_or(span.end(), |semicolon| semicolon.to_span().start());
+			let declaration_text = text(source, Span::new(span.start(), end)).trim_end();
+			let after_name = &declaration_text[(name_span.end().0 - span.start().0) as usize..];
+			let whitespace = leading_whitespace(source, span);
+			let insert =
+				|text: String| Edit::new(Span::new(span.start(), span.start()), format!("{text};{whitespace}"));
+
+			if let Some(standard) = property_support(name) {
+				for prefixed in property_prefixes(name) {
+					if self.needed(standard, prefixed) && !has_property(prefixed.name) {
+						self.edits.push(insert(format!("{}{after_name}", prefixed.name)));
+					}
+				}
+			}
+			if let Some((standard_name, prefixed)) = unprefixed_property(name) {
+				let standard = property_support(standard_name).unwrap_or(Support::NONE);
+				if !self.needed(standard, &prefixed) {
+					if has_property(standard_name) {
+						self.edits.push(Edit::new(removal(source, span), ""));
+					} else {
+						self.edits.push(Edit::new(name_span, standard_name));
+					}
+					continue;
+				}
+			}
+			if let Some(standard) = value_support(name, value) {
+				let before_value = text(source, Span::new(span.start(), value_span.start()));
+				let after_value = &declaration_text[(value_span.end().0 - span.start().0) as usize..];
+				for prefixed in value_prefixes(name, value) {
+					if self.needed(standard, prefixed) && !has_value(name, prefixed.name) {
+						self.edits.push(insert(format!("{before_value}{}{after_value}", prefixed.name)));
+					}
+				}
+			}
+			if let Some((standard_value, prefixed)) = unprefixed_value(name, value) {
+				let standard = value_support(name, standard_value).unwrap_or(Support::NONE);
+				if !self.needed(standard, &prefixed) {
+					if has_value(name, standard_value) {
+						self.edits.push(Edit::new(removal(source, span), ""));
+					} else {
+						self.edits.push(Edit::new(value_span, standard_value));
+					}
+				}
+			}
+		}
+	}
+
+	fn visit_style_rule<'a>(&mut self, rule: &StyleRule<'a>) {
+		let span = rule.to_span();
+		let prelude = rule.0.prelude.to_span();
+		let selectors = &rule.0.prelude.0;
+		let mut unneeded = 0;
+		let mut renames = vec![];
+		for (compound, _) in selectors {
+			let mut has_unneeded = false;
+			for component in &compound.0 {
+				if !matches!(component, SelectorComponent::PseudoClass(_) | SelectorComponent::PseudoElement(_)) {
+					continue;
+				}
+				let pseudo = component.to_span();
+				let name = text(self.source, pseudo).to_ascii_lowercase();
+				if let Some(standard) = selector_support(&name) {
+					for prefixed in selector_prefixes(&name) {
+						if !self.needed(standard, prefixed) {
+							continue;
+						}
+						let selector = format!(
+							"{}{}{}",
+							text(self.source, Span::new(prelude.start(), pseudo.start())),
+							prefixed.name,
+							text(self.source, Span::new(pseudo.end(), prelude.end())),
+						);
+						// Unknown pseudos invalidate the whole selector list, so each prefix needs its own rule.
+						if !self.source[..span.start().0 as usize].contains(&selector) {
+							self.copies.push((span, pseudo, prefixed.name.into()));
+						}
+					}
+				}
+				if let Some((standard_name, prefixed)) = unprefixed_selector(&name) {
+					let standard = selector_support(standard_name).unwrap_or(Support::NONE);
+					if !self.needed(standard, &prefixed) {
+						has_unneeded = true;
+						renames.push(Edit::new(pseudo, standard_name));
+					}
+				}
+			}
+			unneeded += has_unneeded as usize;
+		}
+		let collapse = |selector: &str| selector.split_ascii_whitespace().collect::<Vec<_>>().join(" ");
+		self.selectors.push(collapse(text(self.source, prelude)));
+		if unneeded > 0 && unneeded == selectors.len() {
+			let relative: Vec<Edit> = renames
+				.iter()
+				.map(|rename| {
+					let start = rename.span.start().0 - prelude.start().0;
+					let end = rename.span.end().0 - prelude.start().0;
+					Edit::new(Span::new(SourceOffset(start), SourceOffset(end)), rename.text.clone())
+				})
+				.collect();
+			let standard = collapse(&apply_edits(text(self.source, prelude), &relative));
+			self.unneeded_rules.push((span, standard, renames));
+		}
+	}
+
+	fn visit_keyframes_rule<'a>(&mut self, rule: &KeyframesRule<'a>) {
+		let name = text(self.source, rule.0.prelude.to_span()).to_string();
+		self.keyframes.push((rule.to_span(), name));
+	}
+
+	fn visit_webkit_keyframes_rule<'a>(&mut self, rule: &WebkitKeyframesRule<'a>) {
+		let name = text(self.source, rule.0.prelude.to_span()).to_string();
+		self.webkit_keyframes.push((rule.to_span(), name));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn old(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 20", "firefox 60", "safari 8"].map(|target| target.parse().unwrap());
+		autoprefix(source, stylesheet, &targets)
+	}
+
+	fn new(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 120", "firefox 120", "safari 18"].map(|target| target.parse().unwrap());
+		autoprefix(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_add_property_prefixes() {
+		assert_transform!(
+			old,
+			"a {\n\ttransform: scale(2);\n\tuser-select: none !important;\n}",
+			"a {\n\t-webkit-transform: scale(2);\n\ttransform: scale(2);\n\t-webkit-user-select: none !important;\n\t-moz-user-select: none !important;\n\tuser-select: none !important;\n}"
+		);
+		assert_transform!(old, "a{color:red;hyphens:auto}", "a{color:red;-webkit-hyphens:auto;hyphens:auto}");
+		assert_transform!(new, "a{hyphens:auto}", "a{hyphens:auto}");
+	}
+
+	#[test]
+	fn test_add_value_prefixes() {
+		assert_transform!(
+			old,
+			"a { position: sticky; width: stretch }",
+			"a { position: -webkit-sticky; position: sticky; width: -webkit-fill-available; width: -moz-available; width: stretch }"
+		);
+	}
+
+	#[test]
+	fn test_add_selector_prefixes() {
+		assert_transform!(
+			old,
+			"a {}\ninput::placeholder {\n\tcolor: gray;\n}\n",
+			"a {}\ninput::-webkit-input-placeholder {\n\tcolor: gray;\n}\ninput::placeholder {\n\tcolor: gray;\n}\n"
+		);
+		assert_transform!(
+			old,
+			"::selection{user-select:none}",
+			"::-moz-selection{-webkit-user-select:none;-moz-user-select:none;user-select:none}::selection{-webkit-user-select:none;-moz-user-select:none;user-select:none}"
+		);
+	}
+
+	#[test]
+	fn test_add_keyframes_prefixes() {
+		assert_transform!(
+			old,
+			"a{}\n@keyframes spin { to { transform: rotate(1turn) } }",
+			"a{}\n@-webkit-keyframes spin { to { -webkit-transform: rotate(1turn); transform: rotate(1turn) } }\n@keyframes spin { to { -webkit-transform: rotate(1turn); transform: rotate(1turn) } }"
+		);
+	}
+
+	#[test]
+	fn test_rename_prefixed_selectors() {
+		assert_transform!(new, "input::-webkit-input-placeholder{color:gray}", "input::placeholder{color:gray}");
+		assert_transform!(new, "a:-webkit-autofill{color:gray}", "a:autofill{color:gray}");
+		assert_transform!(
+			new,
+			"input::placeholder { color: gray }\ninput::-webkit-input-placeholder { color: gray }",
+			"input::placeholder { color: gray }"
+		);
+	}
+
+	#[test]
+	fn test_remove_prefixes() {
+		assert_transform!(
+			new,
+			"a {\n\t-webkit-transform: none;\n\ttransform: none;\n\t-webkit-transition: none;\n\tposition: -webkit-sticky;\n}",
+			"a {\n\ttransform: none;\n\ttransition: none;\n\tposition: sticky;\n}"
+		);
+		assert_transform!(
+			new,
+			"a:-webkit-autofill, b {} a::-moz-selection {} a::selection {}",
+			"a:-webkit-autofill, b {} a::selection {}"
+		);
+		assert_transform!(
+			new,
+			"@keyframes a {} @-webkit-keyframes a {} @-webkit-keyframes b {}",
+			"@keyframes a {} @keyframes b {}"
+		);
+	}
+}
@@ -0,0 +1,107 @@
+use css_ast::{BrowserTarget, StyleSheet, SupportsRule, Visit, Visitable};
+use css_lexer::{SourceOffset, Span, ToSpan};
+
+use crate::Edit;
+
+/// Returns the [Edits][Edit] which resolve each `@supports` rule whose condition is known for every browser target
+/// (see [SupportsCondition::evaluate][css_ast::SupportsCondition::evaluate]): rules which are always true are unwrapped,
+/// leaving their contents in place, while rules which are always false are removed. Rules whose condition differs
+/// between the targets, or is unknown, are left as they are.
+pub fn resolve_supports(source: &str, stylesheet: &StyleSheet, targets: &[BrowserTarget]) -> Vec<Edit> {
+	let mut visitor = ResolveSupports { source, targets, edits: vec![] };
+	stylesheet.accept(&mut visitor);
+	visitor.edits
+}
+
+#[derive(Default)]
+struct ResolveSupports<'s> {
+	source: &'s str,
+	targets: &'s [BrowserTarget],
+	edits: Vec<Edit>,
+}
+
+impl Visit for ResolveSupports<'_> {
+	fn visit_supports_rule<'a>(&mut self, rule: &SupportsRule<'a>) {
+		let span = rule.to_span();
+		let rules = &rule.0.block.0.rules;
+		match (rule.evaluate(self.source, self.targets), rules.first(), rules.last()) {
+			(Some(true), Some(first), Some(last)) => {
+				self.edits.push(Edit::new(Span::new(span.start(), first.to_span().start()), ""));
+				self.edits.push(Edit::new(Span::new(last.to_span().end(), span.end()), ""));
+			}
+			(Some(_), _, _) => {
+				// Remove the whitespace before the rule too, without overlapping an edit to its parent rule, which
+				// would stop this edit from being applied.
+				let trimmed =
+					self.source[..span.start().0 as usize].trim_end_matches(|c: char| c.is_ascii_whitespace());
+				let previous = self.edits.iter().map(|edit| edit.span.end()).filter(|end| *end <= span.start()).max();
+				let start = SourceOffset(trimmed.len() as u32).max(previous.unwrap_or(SourceOffset(0)));
+				self.edits.push(Edit::new(Span::new(start, span.end()), ""));
+			}
+			(None, _, _) => {}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_helpers::assert_transform;
+
+	fn resolve(source: &str, stylesheet: &StyleSheet) -> Vec<Edit> {
+		let targets = ["chrome 100", "firefox 115", "safari 15.4"].map(|target| target.parse().unwrap());
+		resolve_supports(source, stylesheet, &targets)
+	}
+
+	#[test]
+	fn test_always_true() {
+		assert_transform!(
+			resolve,
+			"@supports (aspect-ratio: 1) { .a { aspect-ratio: 1 } } .b{}",
+			".a { aspect-ratio: 1 } .b{}"
+		);
+		assert_transform!(
+			resolve,
+			"@supports selector(:is(a)) and (gap: 1rem) {\n\t.a {}\n\t.b {}\n}\n",
+			".a {}\n\t.b {}\n"
+		);
+		assert_transform!(resolve, ".a{}@supports (aspect-ratio:1){}", ".a{}");
+	}
+
+	#[test]
+	fn test_always_false() {
+		assert_transform!(
+			resolve,
+			".a{color:red}\n@supports (text-wrap: pretty) {\n\t.a {\n\t\ttext-wrap: pretty;\n\t}\n}\n.b{}",
+			".a{color:red}\n.b{}"
+		);
+		assert_transform!(resolve, "@supports not (aspect-ratio: 1) {.a{float:left}}.b{}", ".b{}");
+	}
+
+	#[test]
+	fn test_unknown() {
+		assert_transform!(
+			resolve,
+			"@supports (container-name: card) {.a{}}",
+			"@supports (container-name: card) {.a{}}"
+		);
+		assert_transform!(resolve, "@supports (foo: bar) {.a{}}", "@supports (foo: bar) {.a{}}");
+		// Values which aren't valid for their property would make the condition false, so they're never resolved.
+		assert_transform!(
+			resolve,
+			"@supports (display: nonsense){a{color:red}}",
+			"@supports (display: nonsense){a{color:red}}"
+		);
+		assert_transform!(resolve, "@supports not (display: nonsense){d{}}", "@supports not (display: nonsense){d{}}");
+		assert_transform!(resolve, "@supports (width: 10furlongs){a{}}", "@supports (width: 10furlongs){a{}}");
+	}
+
+	#[test]
+	fn test_nested() {
+		assert_transform!(
+			resolve,
+			"@supports (aspect-ratio: 1) {\n\t@supports selector(:nth-col(1)) {\n\t\t.a {}\n\t}\n\t.b {}\n}",
+			"\n\t.b {}"
+		);
+	}
+}
@@ -1,4 +1,8 @@
 import { DOMParser } from "jsr:@b-fuze/deno-dom";
+// Compat data comes from pinned, offline snapshots so regenerating values is reproducible. Bump these versions to
+// pick up newer browser releases.
+import bcd from "npm:@mdn/browser-compat-data@6.0.13" with { type: "json" };
+import { features } from "npm:web-features@2.20.0";
 
 // TODO!!!
 // These properties have yet to be implemented because their parsing rules can be a little tricky.
@@ -719,6 +723,51 @@ async function getPopularity(propertyName) {
 	return (popularity.find(({ property_name }) => propertyName == property_name)?.day_percentage ?? 0) * 100;
 }
 
+// The browsers which `css_ast::Browser` models, by their browser-compat-data names. Others are ignored by the
+// `#[versions]` macro.
+const compatBrowsers = [
+	"chrome",
+	"chrome_android",
+	"edge",
+	"firefox",
+	"firefox_android",
+	"opera",
+	"safari",
+	"safari_ios",
+	"samsunginternet_android",
+];
+
+// Returns the first version of each browser to support a property without a prefix or flag, according to
+// browser-compat-data. Ranged versions such as `≤79` are taken as their upper bound.
+function getVersions(propertyName) {
+	const support = bcd.css.properties[propertyName]?.__compat?.support ?? {};
+	const versions = [];
+	for (const browser of compatBrowsers) {
+		const statements = [support[browser] ?? []].flat();
+		const statement = statements.find(
+			(s) => !s.prefix && !s.alternative_name && !s.flags && !s.partial_implementation && !s.version_removed,
+		);
+		const version = String(statement?.version_added ?? "").replace(/^≤/, "");
+		if (/^\d+(\.\d+)?$/.test(version)) {
+			versions.push(`${browser}:${version}`);
+		}
+	}
+	return versions;
+}
+
+// Returns the Baseline status and caniuse page of a property, from the web-features group which includes it.
+function getFeature(propertyName) {
+	const key = `css.properties.${propertyName}`;
+	const feature = Object.values(features).find((f) => f.compat_features?.includes(key));
+	if (!feature) {
+		return {};
+	}
+	const status = feature.status?.by_compat_key?.[key] ?? feature.status;
+	const baseline = { high: "widely", low: "newly", false: "limited" }[String(status?.baseline)];
+	const caniuse = [feature.caniuse ?? []].flat()[0];
+	return { baseline, caniuse };
+}
+
 async function getIndex() {
 	const json = await fetchCached("https://api.github.com/repos/w3c/csswg-drafts/git/trees/main", "index.json");
 	return json.tree.reduce((acc: Record<string, number>, { path, type }) => {
@@ -805,35 +854,23 @@ async function getSpec(name: string, index: Record<string, number[]>) {
 	}
 
 	for (const { name } of types.values()) {
-		compats.set(name, await fetchCached(`https://api.webstatus.dev/v1/features/${name}`, `${name}-compat.json`));
+		compats.set(name, getVersions(name));
 		popularities.set(name, await getPopularity(name));
-		metas.set(
-			name,
-			await fetchCached(
-				`https://api.webstatus.dev/v1/features/${name}/feature-metadata`,
-				`${name}-feature-metadata.json`,
-			),
-		);
+		metas.set(name, getFeature(name));
 	}
 
 	const typeDefs = [...types.values()].map((table) => {
 		const enums = enumOverrides.get(name);
 		const structs = structOverrides.get(name);
 		const valueExts = valueExtensions.get(name);
-		const compat = compats.get(table.name) ?? {};
+		const versions = compats.get(table.name) ?? [];
 		const meta = metas.get(table.name) ?? {};
-		let caniuse = meta.can_i_use?.items?.[0]?.id;
+		let caniuse = meta.caniuse;
 		if (caniuse) {
 			caniuse = `"https://caniuse.com/${caniuse}"`;
 		}
-		let baseline = compat.baseline?.status ?? "Unknown";
-		let versions = [];
-		if (compat.browser_implementations) {
-			for (const [browser, { version }] of Object.entries(compat.browser_implementations)) {
-				versions.push(`${browser}:${version}`);
-			}
-		}
-		let popularity = popularities.get(name);
+		const baseline = meta.baseline ?? "Unknown";
+		let popularity = popularities.get(table.name);
 		popularity = popularity ? popularity.toFixed(3) : "Unknown";
 		const justTopLevels = table.value
 			.replace(/<[^>]+>/g, "")